@@ -0,0 +1,56 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Parsing, lookup and evaluation on a compiled xterm-256color entry, expansion of a long title
+//!
+//! Run with `cargo bench --bench parse`
+
+#[macro_use]
+extern crate criterion;
+extern crate cxterminfo;
+
+use criterion::{black_box, Criterion};
+
+use cxterminfo::capabilities::StringCapability;
+use cxterminfo::param_string::{evaluate, Param};
+use cxterminfo::terminfo::TermInfo;
+
+const XTERM: &[u8] = include_bytes!("../tests/data/xterm-256color");
+
+fn from_data(c: &mut Criterion) {
+    c.bench_function("from_data xterm-256color", |b| {
+        b.iter(|| TermInfo::from_data(black_box(XTERM).to_vec()).unwrap())
+    });
+}
+
+fn get_string(c: &mut Criterion) {
+    let info = TermInfo::from_data(XTERM.to_vec()).unwrap();
+    c.bench_function("get_string cup", |b| {
+        b.iter(|| info.get_string(black_box(StringCapability::CursorAddress)))
+    });
+}
+
+fn evaluate_cup(c: &mut Criterion) {
+    let info = TermInfo::from_data(XTERM.to_vec()).unwrap();
+    let cup = info.get_string(StringCapability::CursorAddress).unwrap();
+    let params = [Param::Number(24), Param::Number(80)];
+    c.bench_function("evaluate cup", |b| b.iter(|| evaluate(black_box(&cup), black_box(&params))));
+}
+
+fn evaluate_title(c: &mut Criterion) {
+    // OSC 2 with a 4 KiB title, borrowed and owned
+    let osc = "\x1B]2;%p1%s\x07";
+    let title = "x".repeat(4096);
+    c.bench_function("evaluate osc 2 borrowed title", |b| {
+        b.iter(|| evaluate(black_box(osc), &[Param::from(black_box(title.as_str()))]))
+    });
+    c.bench_function("evaluate osc 2 owned title", |b| {
+        b.iter(|| evaluate(black_box(osc), &[Param::from(black_box(title.clone()))]))
+    });
+}
+
+criterion_group!(benches, from_data, get_string, evaluate_cup, evaluate_title);
+criterion_main!(benches);
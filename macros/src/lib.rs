@@ -0,0 +1,46 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Compile-time checked parameterized strings for cxterminfo
+//!
+//! ```
+//! use cxterminfo::param_string::{Param, StaticParamString};
+//! use cxterminfo_macros::param_str;
+//!
+//! const CUP: StaticParamString = param_str!("\x1B[%i%p1%d;%p2%dH");
+//! assert_eq!(CUP.eval(&[Param::Number(4), Param::Number(9)]).unwrap(), "\x1B[5;10H");
+//! ```
+
+extern crate cxterminfo;
+extern crate proc_macro;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use cxterminfo::param_string::ParamString;
+use proc_macro::TokenStream;
+use syn::LitStr;
+
+/// Validate a parameterized string literal at compile time
+///
+/// Expands to a `cxterminfo::param_string::StaticParamString` (usable in `const`), malformed
+/// strings are reported as compile error with the position of the invalid code.
+#[proc_macro]
+pub fn param_str(input: TokenStream) -> TokenStream {
+    let literal = match syn::parse::<LitStr>(input) {
+        Ok(literal) => literal,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let term = literal.value();
+    match ParamString::compile(&term) {
+        Ok(_) => quote!(::cxterminfo::param_string::StaticParamString::new_unchecked(#literal)).into(),
+        Err(err) => {
+            let message = format!("invalid parameterized string: {}", err);
+            syn::Error::new(literal.span(), message).to_compile_error().into()
+        }
+    }
+}
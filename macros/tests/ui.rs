@@ -0,0 +1,15 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+extern crate trybuild;
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/valid.rs");
+    t.compile_fail("tests/ui/invalid.rs");
+    t.compile_fail("tests/ui/not_a_string.rs");
+}
@@ -0,0 +1,11 @@
+extern crate cxterminfo;
+extern crate cxterminfo_macros;
+
+use cxterminfo::param_string::StaticParamString;
+use cxterminfo_macros::param_str;
+
+const CUP: StaticParamString = param_str!("\x1B[%i%p0%d;%p2%dH");
+const PRINTF: StaticParamString = param_str!("%p1%5.2q");
+const CONSTANT: StaticParamString = param_str!("%{12x}%d");
+
+fn main() {}
@@ -0,0 +1,9 @@
+extern crate cxterminfo;
+extern crate cxterminfo_macros;
+
+use cxterminfo::param_string::StaticParamString;
+use cxterminfo_macros::param_str;
+
+const CUP: StaticParamString = param_str!(42);
+
+fn main() {}
@@ -0,0 +1,15 @@
+extern crate cxterminfo;
+extern crate cxterminfo_macros;
+
+use cxterminfo::param_string::{Param, StaticParamString};
+use cxterminfo_macros::param_str;
+
+const CUP: StaticParamString = param_str!("\x1B[%i%p1%d;%p2%dH");
+const SGR0: StaticParamString = param_str!("\x1B(B\x1B[m");
+
+fn main() {
+    assert_eq!(CUP.eval(&[Param::Number(0), Param::Number(0)]).unwrap(), "\x1B[1;1H");
+    assert!(SGR0.compile().is_constant());
+    let setaf = param_str!("\x1B[%?%p1%{8}%<%t3%p1%d%e%p1%{16}%<%t9%p1%{8}%-%d%e38;5;%p1%d%;m");
+    assert_eq!(setaf.eval(&[Param::Number(1)]).unwrap(), "\x1B[31m");
+}
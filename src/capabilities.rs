@@ -4,7 +4,324 @@
 //  terms of the Do What The Fuck You Want To Public License, Version 2,
 //  as published by Sam Hocevar. See the COPYING file for more details.
 
+#[cfg(feature = "names")]
+use std::convert::TryFrom;
+#[cfg(feature = "names")]
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+#[cfg(feature = "names")]
+use std::str::FromStr;
+
+#[cfg(feature = "names")]
+use crate::ext::KNOWN_NAMES;
+#[cfg(feature = "parser")]
+use crate::terminfo::TermInfo;
+
+/// Error for unknown capability names, contains the name and up to three similar names of
+/// the same kind, closest first
+#[cfg(feature = "names")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCapabilityError(pub String, pub Vec<&'static str>);
+
+#[cfg(feature = "names")]
+impl Display for ParseCapabilityError {
+    /// Writes the name and the suggestions
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::{NumberCapability, StringCapability};
+    ///
+    /// let err = "max_colour".parse::<NumberCapability>().unwrap_err();
+    /// assert_eq!(err.to_string(), "unknown capability name \"max_colour\", did you mean \"max_colors\"?");
+    ///
+    /// let err = "smcpu".parse::<StringCapability>().unwrap_err();
+    /// assert_eq!(err.1, ["smcup", "rmcup", "scp"]);
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown capability name \"{}\"", self.0)?;
+        for (idx, name) in self.1.iter().enumerate() {
+            match idx {
+                0 => write!(f, ", did you mean \"{}\"", name)?,
+                _ if idx + 1 == self.1.len() => write!(f, " or \"{}\"", name)?,
+                _ => write!(f, ", \"{}\"", name)?,
+            }
+        }
+        match self.1.is_empty() {
+            true => Ok(()),
+            false => write!(f, "?"),
+        }
+    }
+}
+
+#[cfg(feature = "names")]
+impl Error for ParseCapabilityError {}
+
+/// Capability found by `complete`
+#[cfg(feature = "names")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CapMatch {
+    Bool(BoolCapability),
+    Number(NumberCapability),
+    String(StringCapability),
+    /// Well-known extended capability from `ext`
+    Extended(&'static str),
+}
+
+#[cfg(feature = "names")]
+impl CapMatch {
+    /// Returns the short name, e.g. `setaf` or `Tc`
+    pub fn name(&self) -> &'static str {
+        match self {
+            CapMatch::Bool(cap) => cap.name(),
+            CapMatch::Number(cap) => cap.name(),
+            CapMatch::String(cap) => cap.name(),
+            CapMatch::Extended(name) => name,
+        }
+    }
+
+    /// Returns the C variable name or Option::None for extended capabilities
+    pub fn long_name(&self) -> Option<&'static str> {
+        match self {
+            CapMatch::Bool(cap) => Some(cap.long_name()),
+            CapMatch::Number(cap) => Some(cap.long_name()),
+            CapMatch::String(cap) => Some(cap.long_name()),
+            CapMatch::Extended(_) => None,
+        }
+    }
+}
+
+/// Returns all capabilities whose short or variable name starts with prefix, sorted by short
+/// name
+///
+/// Standard capabilities of all kinds and the well-known extended capabilities of `ext` are
+/// searched. The match is case sensitive, an empty prefix returns every capability.
+///
+/// # Arguments
+/// * `prefix` - beginning of a name
+///
+/// # Example
+/// ```
+/// use cxterminfo::capabilities::{complete, CapMatch, NumberCapability};
+///
+/// let names = complete("setr").iter().map(|m| m.name()).collect::<Vec<_>>();
+/// assert_eq!(names, ["setrgbb", "setrgbf"]);
+///
+/// // variable names match too
+/// assert_eq!(complete("max_col"), [CapMatch::Number(NumberCapability::MaxColors)]);
+/// ```
+#[cfg(feature = "names")]
+pub fn complete(prefix: &str) -> Vec<CapMatch> {
+    let bools = BoolCapability::all().map(CapMatch::Bool);
+    let numbers = NumberCapability::all().map(CapMatch::Number);
+    let strings = StringCapability::all().map(CapMatch::String);
+    let extended = KNOWN_NAMES.iter().map(|name| CapMatch::Extended(name));
+
+    let mut found = bools.chain(numbers)
+                         .chain(strings)
+                         .chain(extended)
+                         .filter(|m| m.name().starts_with(prefix)
+                                     || m.long_name().is_some_and(|name| name.starts_with(prefix)))
+                         .collect::<Vec<CapMatch>>();
+    found.sort_by_key(CapMatch::name);
+    found
+}
+
+/// Capability kind, implemented by `BoolCapability`, `NumberCapability` and `StringCapability`
+///
+/// The trait is sealed, `Value` is the type returned by `TermInfo::get`: `bool`, `i32` or the
+/// raw bytes of a string.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "parser")] {
+/// use cxterminfo::terminfo::TermInfo;
+/// use cxterminfo::capabilities::{Capability, NumberCapability};
+///
+/// fn present<C: Capability>(info: &TermInfo, caps: &[C]) -> usize {
+///     caps.iter().filter(|cap| info.get(**cap).is_some()).count()
+/// }
+///
+/// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+/// assert_eq!(present(&info, &[NumberCapability::Columns, NumberCapability::BitImageType]), 1);
+/// # }
+/// ```
+pub trait Capability: Copy + sealed::Sealed {
+    /// Value of the capability in a terminfo entry
+    type Value<'a>;
+}
+
+impl Capability for BoolCapability {
+    type Value<'a> = bool;
+}
+
+impl Capability for NumberCapability {
+    type Value<'a> = i32;
+}
+
+impl Capability for StringCapability {
+    type Value<'a> = &'a [u8];
+}
+
+pub(crate) mod sealed {
+    use super::{BoolCapability, Category, NumberCapability, StringCapability};
+    #[cfg(feature = "parser")]
+    use super::{Capability, TermInfo};
+    use super::{BOOL_CAPABILITIES, NUMBER_CAPABILITIES, STRING_CAPABILITIES};
+    use super::{BOOL_CATEGORIES, NUMBER_CATEGORIES, STRING_CATEGORIES};
+
+    pub trait Sealed: Sized + 'static {
+        #[cfg(feature = "parser")]
+        fn lookup(self, info: &TermInfo) -> Option<<Self as Capability>::Value<'_>>
+            where Self: Capability;
+        /// All variants in file order
+        fn variants() -> &'static [Self];
+        /// Categories of all variants in file order
+        fn categories() -> &'static [Category];
+    }
+
+    impl Sealed for BoolCapability {
+        #[cfg(feature = "parser")]
+        fn lookup(self, info: &TermInfo) -> Option<bool> {
+            info.get_bool(self)
+        }
+
+        fn variants() -> &'static [Self] {
+            &BOOL_CAPABILITIES
+        }
+
+        fn categories() -> &'static [Category] {
+            &BOOL_CATEGORIES
+        }
+    }
+
+    impl Sealed for NumberCapability {
+        #[cfg(feature = "parser")]
+        fn lookup(self, info: &TermInfo) -> Option<i32> {
+            info.get_number(self)
+        }
+
+        fn variants() -> &'static [Self] {
+            &NUMBER_CAPABILITIES
+        }
+
+        fn categories() -> &'static [Category] {
+            &NUMBER_CATEGORIES
+        }
+    }
+
+    impl Sealed for StringCapability {
+        #[cfg(feature = "parser")]
+        fn lookup(self, info: &TermInfo) -> Option<&[u8]> {
+            info.string_at(self as usize)
+        }
+
+        fn variants() -> &'static [Self] {
+            &STRING_CAPABILITIES
+        }
+
+        fn categories() -> &'static [Category] {
+            &STRING_CATEGORIES
+        }
+    }
+}
+
+/// Group of capabilities, following the sections of terminfo(5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Cursor motion and addressing
+    CursorMotion,
+    /// Scrolling, scroll regions and retained memory
+    Scrolling,
+    /// Automatic and soft margins
+    Margins,
+    /// Area clears, insert and delete of lines and characters
+    Editing,
+    /// Video attributes and cursor visibility
+    Highlighting,
+    /// Audible and visible bell
+    Bell,
+    /// Color handling
+    Color,
+    /// Line graphics and character sets
+    CharacterSet,
+    /// Keypad and function keys
+    FunctionKeys,
+    /// Soft labels of function keys
+    Labels,
+    /// Mouse buttons and events
+    Mouse,
+    /// Status line
+    StatusLine,
+    /// Tab stops
+    Tabs,
+    /// Initialization, reset and fullscreen mode
+    Initialization,
+    /// Delays, padding and flow control
+    Padding,
+    /// Printer and hardcopy terminals
+    Printer,
+    /// Everything else, e.g. screen size, windows, dialing and user strings
+    Miscellaneous,
+}
+
+impl Category {
+    /// Returns an iterator over all categories
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::Category;
+    ///
+    /// assert_eq!(Category::all().next(), Some(Category::CursorMotion));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Category> {
+        CATEGORIES.iter().copied()
+    }
+
+    /// Returns an iterator over the capabilities of one kind in this category in file order
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::{Category, NumberCapability, StringCapability};
+    ///
+    /// let bells = Category::Bell.capabilities::<StringCapability>().collect::<Vec<_>>();
+    /// assert_eq!(bells, [StringCapability::Bell, StringCapability::FlashScreen]);
+    /// assert!(Category::Color.capabilities::<NumberCapability>().any(|cap| cap == NumberCapability::MaxColors));
+    /// ```
+    pub fn capabilities<C: Capability>(self) -> impl Iterator<Item = C> {
+        C::variants().iter()
+                     .zip(C::categories())
+                     .filter(move |(_, category)| **category == self)
+                     .map(|(cap, _)| *cap)
+    }
+}
+
+impl Display for Category {
+    /// Writes the name of the category in lowercase, e.g. `cursor motion`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Category::CursorMotion => "cursor motion",
+            Category::Scrolling => "scrolling",
+            Category::Margins => "margins",
+            Category::Editing => "editing",
+            Category::Highlighting => "highlighting",
+            Category::Bell => "bell",
+            Category::Color => "color",
+            Category::CharacterSet => "character set",
+            Category::FunctionKeys => "function keys",
+            Category::Labels => "labels",
+            Category::Mouse => "mouse",
+            Category::StatusLine => "status line",
+            Category::Tabs => "tabs",
+            Category::Initialization => "initialization",
+            Category::Padding => "padding",
+            Category::Printer => "printer",
+            Category::Miscellaneous => "miscellaneous",
+        })
+    }
+}
+
 /// Known bool capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BoolCapability
 {
     /// cub1 wraps from column 0 to last column
@@ -81,9 +398,200 @@ pub enum BoolCapability
     CpiChangesRes,
     /// Changing line pitch changes resolution
     LpiChangesRes,
+    /// Uses ^H to move left (obsolete)
+    BackspacesWithBs,
+    /// Crt cannot scroll (obsolete)
+    CrtNoScrolling,
+    /// No way to go to start of line (obsolete)
+    NoCorrectlyWorkingCr,
+    /// Has meta key (obsolete)
+    GnuHasMetaKey,
+    /// Move down with \\n (obsolete)
+    LinefeedIsNewline,
+    /// Has 8-char tabs invoked with ^I (obsolete)
+    HasHardwareTabs,
+    /// Return clears the line (obsolete)
+    ReturnDoesClrEol,
+}
+
+impl BoolCapability {
+    /// Number of bool capabilities, including the obsolete ncurses entries
+    pub const COUNT: usize = BOOL_CAPABILITIES.len();
+
+    /// Returns the capability with the index inside the terminfo file or Option::None if idx
+    /// is out of range
+    ///
+    /// # Arguments
+    /// * `idx` - index, `cap as usize` is the index of `cap`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::from_index(4), Some(BoolCapability::EatNewlineGlitch));
+    /// assert_eq!(BoolCapability::from_index(BoolCapability::COUNT), None);
+    /// ```
+    pub fn from_index(idx: usize) -> Option<BoolCapability> {
+        BOOL_CAPABILITIES.get(idx).copied()
+    }
+
+    /// Returns an iterator over all bool capabilities in file order, the position matches the
+    /// index inside the terminfo file
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::all().count(), 44);
+    /// assert!(BoolCapability::all().enumerate().all(|(idx, cap)| cap as usize == idx));
+    /// ```
+    pub fn all() -> impl Iterator<Item = BoolCapability> {
+        BOOL_CAPABILITIES.iter().copied()
+    }
+
+    /// Returns the short capability name, e.g. `am`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::AutoRightMargin.name(), "am");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> &'static str {
+        BOOL_NAMES[*self as usize]
+    }
+
+    /// Returns the C variable name of the capability, e.g. `auto_right_margin`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::AutoRightMargin.long_name(), "auto_right_margin");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn long_name(&self) -> &'static str {
+        BOOL_LONG_NAMES[*self as usize]
+    }
+
+    /// Returns the category of the capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::{BoolCapability, Category};
+    ///
+    /// assert_eq!(BoolCapability::BackColorErase.category(), Category::Color);
+    /// ```
+    pub fn category(&self) -> Category {
+        BOOL_CATEGORIES[*self as usize]
+    }
+
+    /// Returns the description of the capability from terminfo(5)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::AutoRightMargin.description(), "terminal has automatic margins");
+    /// ```
+    #[cfg(feature = "descriptions")]
+    pub fn description(&self) -> &'static str {
+        BOOL_DESCRIPTIONS[*self as usize]
+    }
+
+    /// Returns the termcap code, e.g. `xn` for `xenl`
+    ///
+    /// All standard capabilities have a termcap code, Option::None is reserved for capabilities
+    /// without one.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::EatNewlineGlitch.termcap_code(), Some("xn"));
+    /// ```
+    #[cfg(feature = "names")]
+    #[doc(alias = "termcap_name")]
+    pub fn termcap_code(&self) -> Option<&'static str> {
+        BOOL_TERMCAP_CODES.get(*self as usize).copied()
+    }
+
+    /// Returns the capability for a termcap code or Option::None if the code is unknown
+    ///
+    /// # Arguments
+    /// * `code` - two letter termcap code
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(BoolCapability::from_termcap_code("xn"), Some(BoolCapability::EatNewlineGlitch));
+    /// assert_eq!(BoolCapability::from_termcap_code("xenl"), None);
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn from_termcap_code(code: &str) -> Option<BoolCapability> {
+        BOOL_TERMCAP_CODES.iter().position(|c| *c == code).map(|idx| BOOL_CAPABILITIES[idx])
+    }
+}
+
+impl Display for BoolCapability {
+    /// Writes the short name, the variable name with `{:#}`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!(format!("{}", BoolCapability::AutoRightMargin), "am");
+    /// assert_eq!(format!("{:#}", BoolCapability::AutoRightMargin), "auto_right_margin");
+    /// ```
+    #[cfg(feature = "names")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate() {
+            true => f.pad(self.long_name()),
+            false => f.pad(self.name()),
+        }
+    }
+
+    /// Writes the variant name, the name tables are compiled out without feature `names`
+    #[cfg(not(feature = "names"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{:?}", self))
+    }
+}
+
+#[cfg(feature = "names")]
+impl FromStr for BoolCapability {
+    type Err = ParseCapabilityError;
+
+    /// Parse a short capability name (`am`) or a C variable name (`auto_right_margin`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// assert_eq!("am".parse(), Ok(BoolCapability::AutoRightMargin));
+    /// assert_eq!("auto_right_margin".parse(), Ok(BoolCapability::AutoRightMargin));
+    /// assert!("unknown".parse::<BoolCapability>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        find_name(name, &BOOL_NAMES, &BOOL_LONG_NAMES)
+            .map(|idx| BOOL_CAPABILITIES[idx])
+            .ok_or_else(|| unknown_name(name, &BOOL_NAMES, &BOOL_LONG_NAMES))
+    }
+}
+
+#[cfg(feature = "names")]
+impl<'a> TryFrom<&'a str> for BoolCapability {
+    type Error = ParseCapabilityError;
+
+    fn try_from(name: &'a str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
 }
 
 /// Known number capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NumberCapability
 {
     /// Number of columns in a line
@@ -152,9 +660,198 @@ pub enum NumberCapability
     BitImageEntwining,
     /// Type of bit image device
     BitImageType,
+    /// Number of blanks left by ul (obsolete)
+    MagicCookieGlitchUl,
+    /// Padding needed for CR (obsolete)
+    CarriageReturnDelay,
+    /// Padding needed for LF (obsolete)
+    NewLineDelay,
+    /// Padding required for ^H (obsolete)
+    BackspaceDelay,
+    /// Padding required for ^I (obsolete)
+    HorizontalTabDelay,
+    /// Count of function keys (obsolete)
+    NumberOfFunctionKeys,
+}
+
+impl NumberCapability {
+    /// Number of number capabilities, including the obsolete ncurses entries
+    pub const COUNT: usize = NUMBER_CAPABILITIES.len();
+
+    /// Returns the capability with the index inside the terminfo file or Option::None if idx
+    /// is out of range
+    ///
+    /// # Arguments
+    /// * `idx` - index, `cap as usize` is the index of `cap`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::from_index(13), Some(NumberCapability::MaxColors));
+    /// assert_eq!(NumberCapability::from_index(NumberCapability::COUNT), None);
+    /// ```
+    pub fn from_index(idx: usize) -> Option<NumberCapability> {
+        NUMBER_CAPABILITIES.get(idx).copied()
+    }
+
+    /// Returns an iterator over all number capabilities in file order, the position matches the
+    /// index inside the terminfo file
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::all().count(), 39);
+    /// assert!(NumberCapability::all().enumerate().all(|(idx, cap)| cap as usize == idx));
+    /// ```
+    pub fn all() -> impl Iterator<Item = NumberCapability> {
+        NUMBER_CAPABILITIES.iter().copied()
+    }
+
+    /// Returns the short capability name, e.g. `colors`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::MaxColors.name(), "colors");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> &'static str {
+        NUMBER_NAMES[*self as usize]
+    }
+
+    /// Returns the C variable name of the capability, e.g. `max_colors`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::MaxColors.long_name(), "max_colors");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn long_name(&self) -> &'static str {
+        NUMBER_LONG_NAMES[*self as usize]
+    }
+
+    /// Returns the category of the capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::{NumberCapability, Category};
+    ///
+    /// assert_eq!(NumberCapability::MaxColors.category(), Category::Color);
+    /// ```
+    pub fn category(&self) -> Category {
+        NUMBER_CATEGORIES[*self as usize]
+    }
+
+    /// Returns the description of the capability from terminfo(5)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::MaxColors.description(), "maximum number of colors on screen");
+    /// ```
+    #[cfg(feature = "descriptions")]
+    pub fn description(&self) -> &'static str {
+        NUMBER_DESCRIPTIONS[*self as usize]
+    }
+
+    /// Returns the termcap code, e.g. `Co` for `colors`
+    ///
+    /// All standard capabilities have a termcap code, Option::None is reserved for capabilities
+    /// without one.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::MaxColors.termcap_code(), Some("Co"));
+    /// ```
+    #[cfg(feature = "names")]
+    #[doc(alias = "termcap_name")]
+    pub fn termcap_code(&self) -> Option<&'static str> {
+        NUMBER_TERMCAP_CODES.get(*self as usize).copied()
+    }
+
+    /// Returns the capability for a termcap code or Option::None if the code is unknown
+    ///
+    /// # Arguments
+    /// * `code` - two letter termcap code
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(NumberCapability::from_termcap_code("Co"), Some(NumberCapability::MaxColors));
+    /// assert_eq!(NumberCapability::from_termcap_code("colors"), None);
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn from_termcap_code(code: &str) -> Option<NumberCapability> {
+        NUMBER_TERMCAP_CODES.iter().position(|c| *c == code).map(|idx| NUMBER_CAPABILITIES[idx])
+    }
+}
+
+impl Display for NumberCapability {
+    /// Writes the short name, the variable name with `{:#}`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!(format!("{}", NumberCapability::MaxColors), "colors");
+    /// assert_eq!(format!("{:#}", NumberCapability::MaxColors), "max_colors");
+    /// ```
+    #[cfg(feature = "names")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate() {
+            true => f.pad(self.long_name()),
+            false => f.pad(self.name()),
+        }
+    }
+
+    /// Writes the variant name, the name tables are compiled out without feature `names`
+    #[cfg(not(feature = "names"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{:?}", self))
+    }
+}
+
+#[cfg(feature = "names")]
+impl FromStr for NumberCapability {
+    type Err = ParseCapabilityError;
+
+    /// Parse a short capability name (`colors`) or a C variable name (`max_colors`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// assert_eq!("colors".parse(), Ok(NumberCapability::MaxColors));
+    /// assert_eq!("max_colors".parse(), Ok(NumberCapability::MaxColors));
+    /// assert!("unknown".parse::<NumberCapability>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        find_name(name, &NUMBER_NAMES, &NUMBER_LONG_NAMES)
+            .map(|idx| NUMBER_CAPABILITIES[idx])
+            .ok_or_else(|| unknown_name(name, &NUMBER_NAMES, &NUMBER_LONG_NAMES))
+    }
+}
+
+#[cfg(feature = "names")]
+impl<'a> TryFrom<&'a str> for NumberCapability {
+    type Error = ParseCapabilityError;
+
+    fn try_from(name: &'a str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
 }
 
 /// Known string capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StringCapability
 {
     /// Back tab
@@ -945,4 +1642,1393 @@ pub enum StringCapability
     SetAAttributes,
     /// Set page length to #1 hundredth of an inch
     SetPageLenInch,
+    /// Secondary initialization string (obsolete)
+    TermcapInit2,
+    /// Terminal reset string (obsolete)
+    TermcapReset,
+    /// Use to move down (obsolete)
+    LinefeedIfNotLf,
+    /// Move left, if not ^H (obsolete)
+    BackspaceIfNotBs,
+    /// List of self-mapped keycaps (obsolete)
+    OtherNonFunctionKeys,
+    /// Map motion-keys for vi version 2 (obsolete)
+    ArrowKeyMap,
+    /// Single upper left (obsolete)
+    AcsUlcorner,
+    /// Single lower left (obsolete)
+    AcsLlcorner,
+    /// Single upper right (obsolete)
+    AcsUrcorner,
+    /// Single lower right (obsolete)
+    AcsLrcorner,
+    /// Tee pointing right (obsolete)
+    AcsLtee,
+    /// Tee pointing left (obsolete)
+    AcsRtee,
+    /// Tee pointing up (obsolete)
+    AcsBtee,
+    /// Tee pointing down (obsolete)
+    AcsTtee,
+    /// Single horizontal line (obsolete)
+    AcsHline,
+    /// Single vertical line (obsolete)
+    AcsVline,
+    /// Single intersection (obsolete)
+    AcsPlus,
+    /// Lock memory above cursor
+    MemoryLock,
+    /// Unlock memory
+    MemoryUnlock,
+    /// Box characters primary set
+    BoxChars1,
+}
+
+impl StringCapability {
+    /// Number of string capabilities, including the obsolete ncurses entries
+    pub const COUNT: usize = STRING_CAPABILITIES.len();
+
+    /// Returns the capability with the index inside the terminfo file or Option::None if idx
+    /// is out of range
+    ///
+    /// # Arguments
+    /// * `idx` - index, `cap as usize` is the index of `cap`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::from_index(10), Some(StringCapability::CursorAddress));
+    /// assert_eq!(StringCapability::from_index(StringCapability::COUNT), None);
+    /// ```
+    pub fn from_index(idx: usize) -> Option<StringCapability> {
+        STRING_CAPABILITIES.get(idx).copied()
+    }
+
+    /// Returns an iterator over all string capabilities in file order, the position matches the
+    /// index inside the terminfo file
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::all().count(), 414);
+    /// assert!(StringCapability::all().enumerate().all(|(idx, cap)| cap as usize == idx));
+    /// ```
+    pub fn all() -> impl Iterator<Item = StringCapability> {
+        STRING_CAPABILITIES.iter().copied()
+    }
+
+    /// Returns the short capability name, e.g. `cup`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::CursorAddress.name(), "cup");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> &'static str {
+        STRING_NAMES[*self as usize]
+    }
+
+    /// Returns the C variable name of the capability, e.g. `cursor_address`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::CursorAddress.long_name(), "cursor_address");
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn long_name(&self) -> &'static str {
+        STRING_LONG_NAMES[*self as usize]
+    }
+
+    /// Returns the category of the capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::{StringCapability, Category};
+    ///
+    /// assert_eq!(StringCapability::CursorAddress.category(), Category::CursorMotion);
+    /// ```
+    pub fn category(&self) -> Category {
+        STRING_CATEGORIES[*self as usize]
+    }
+
+    /// Returns the description of the capability from terminfo(5)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::CursorAddress.description(), "move to row #1 columns #2");
+    /// ```
+    #[cfg(feature = "descriptions")]
+    pub fn description(&self) -> &'static str {
+        STRING_DESCRIPTIONS[*self as usize]
+    }
+
+    /// Returns the termcap code, e.g. `cm` for `cup`
+    ///
+    /// All standard capabilities have a termcap code, Option::None is reserved for capabilities
+    /// without one.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::CursorAddress.termcap_code(), Some("cm"));
+    /// ```
+    #[cfg(feature = "names")]
+    #[doc(alias = "termcap_name")]
+    pub fn termcap_code(&self) -> Option<&'static str> {
+        STRING_TERMCAP_CODES.get(*self as usize).copied()
+    }
+
+    /// Returns the capability for a termcap code or Option::None if the code is unknown
+    ///
+    /// # Arguments
+    /// * `code` - two letter termcap code
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(StringCapability::from_termcap_code("cm"), Some(StringCapability::CursorAddress));
+    /// assert_eq!(StringCapability::from_termcap_code("cup"), None);
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn from_termcap_code(code: &str) -> Option<StringCapability> {
+        STRING_TERMCAP_CODES.iter().position(|c| *c == code).map(|idx| STRING_CAPABILITIES[idx])
+    }
+}
+
+impl Display for StringCapability {
+    /// Writes the short name, the variable name with `{:#}`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!(format!("{}", StringCapability::CursorAddress), "cup");
+    /// assert_eq!(format!("{:#}", StringCapability::CursorAddress), "cursor_address");
+    /// ```
+    #[cfg(feature = "names")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate() {
+            true => f.pad(self.long_name()),
+            false => f.pad(self.name()),
+        }
+    }
+
+    /// Writes the variant name, the name tables are compiled out without feature `names`
+    #[cfg(not(feature = "names"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{:?}", self))
+    }
+}
+
+#[cfg(feature = "names")]
+impl FromStr for StringCapability {
+    type Err = ParseCapabilityError;
+
+    /// Parse a short capability name (`cup`) or a C variable name (`cursor_address`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// assert_eq!("cup".parse(), Ok(StringCapability::CursorAddress));
+    /// assert_eq!("cursor_address".parse(), Ok(StringCapability::CursorAddress));
+    /// assert!("unknown".parse::<StringCapability>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        find_name(name, &STRING_NAMES, &STRING_LONG_NAMES)
+            .map(|idx| STRING_CAPABILITIES[idx])
+            .ok_or_else(|| unknown_name(name, &STRING_NAMES, &STRING_LONG_NAMES))
+    }
 }
+
+#[cfg(feature = "names")]
+impl<'a> TryFrom<&'a str> for StringCapability {
+    type Error = ParseCapabilityError;
+
+    fn try_from(name: &'a str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+/// Short names of bool capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const BOOL_NAMES: [&str; 44] = [
+    "bw", "am", "xsb", "xhp", "xenl", "eo", "gn", "hc", "km", "hs", "in", "da", "db", "mir", "msgr",
+    "os", "eslok", "xt", "hz", "ul", "xon", "nxon", "mc5i", "chts", "nrrmc", "npc", "ndscr", "ccc",
+    "bce", "hls", "xhpa", "crxm", "daisy", "xvpa", "sam", "cpix", "lpix", "OTbs", "OTns", "OTnc",
+    "OTMT", "OTNL", "OTpt", "OTxr",
+];
+
+/// Short names of number capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const NUMBER_NAMES: [&str; 39] = [
+    "cols", "it", "lines", "lm", "xmc", "pb", "vt", "wsl", "nlab", "lh", "lw", "ma", "wnum",
+    "colors", "pairs", "ncv", "bufsz", "spinv", "spinh", "maddr", "mjump", "mcs", "mls", "npins",
+    "orc", "orl", "orhi", "orvi", "cps", "widcs", "btns", "bitwin", "bitype", "OTug", "OTdC",
+    "OTdN", "OTdB", "OTdT", "OTkn",
+];
+
+/// Short names of string capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const STRING_NAMES: [&str; 414] = [
+    "cbt", "bel", "cr", "csr", "tbc", "clear", "el", "ed", "hpa", "cmdch", "cup", "cud1", "home",
+    "civis", "cub1", "mrcup", "cnorm", "cuf1", "ll", "cuu1", "cvvis", "dch1", "dl1", "dsl", "hd",
+    "smacs", "blink", "bold", "smcup", "smdc", "dim", "smir", "invis", "prot", "rev", "smso",
+    "smul", "ech", "rmacs", "sgr0", "rmcup", "rmdc", "rmir", "rmso", "rmul", "flash", "ff", "fsl",
+    "is1", "is2", "is3", "if", "ich1", "il1", "ip", "kbs", "ktbc", "kclr", "kctab", "kdch1", "kdl1",
+    "kcud1", "krmir", "kel", "ked", "kf0", "kf1", "kf10", "kf2", "kf3", "kf4", "kf5", "kf6", "kf7",
+    "kf8", "kf9", "khome", "kich1", "kil1", "kcub1", "kll", "knp", "kpp", "kcuf1", "kind", "kri",
+    "khts", "kcuu1", "rmkx", "smkx", "lf0", "lf1", "lf10", "lf2", "lf3", "lf4", "lf5", "lf6", "lf7",
+    "lf8", "lf9", "rmm", "smm", "nel", "pad", "dch", "dl", "cud", "ich", "indn", "il", "cub", "cuf",
+    "rin", "cuu", "pfkey", "pfloc", "pfx", "mc0", "mc4", "mc5", "rep", "rs1", "rs2", "rs3", "rf",
+    "rc", "vpa", "sc", "ind", "ri", "sgr", "hts", "wind", "ht", "tsl", "uc", "hu", "iprog", "ka1",
+    "ka3", "kb2", "kc1", "kc3", "mc5p", "rmp", "acsc", "pln", "kcbt", "smxon", "rmxon", "smam",
+    "rmam", "xonc", "xoffc", "enacs", "smln", "rmln", "kbeg", "kcan", "kclo", "kcmd", "kcpy",
+    "kcrt", "kend", "kent", "kext", "kfnd", "khlp", "kmrk", "kmsg", "kmov", "knxt", "kopn", "kopt",
+    "kprv", "kprt", "krdo", "kref", "krfr", "krpl", "krst", "kres", "ksav", "kspd", "kund", "kBEG",
+    "kCAN", "kCMD", "kCPY", "kCRT", "kDC", "kDL", "kslt", "kEND", "kEOL", "kEXT", "kFND", "kHLP",
+    "kHOM", "kIC", "kLFT", "kMSG", "kMOV", "kNXT", "kOPT", "kPRV", "kPRT", "kRDO", "kRPL", "kRIT",
+    "kRES", "kSAV", "kSPD", "kUND", "rfi", "kf11", "kf12", "kf13", "kf14", "kf15", "kf16", "kf17",
+    "kf18", "kf19", "kf20", "kf21", "kf22", "kf23", "kf24", "kf25", "kf26", "kf27", "kf28", "kf29",
+    "kf30", "kf31", "kf32", "kf33", "kf34", "kf35", "kf36", "kf37", "kf38", "kf39", "kf40", "kf41",
+    "kf42", "kf43", "kf44", "kf45", "kf46", "kf47", "kf48", "kf49", "kf50", "kf51", "kf52", "kf53",
+    "kf54", "kf55", "kf56", "kf57", "kf58", "kf59", "kf60", "kf61", "kf62", "kf63", "el1", "mgc",
+    "smgl", "smgr", "fln", "sclk", "dclk", "rmclk", "cwin", "wingo", "hup", "dial", "qdial", "tone",
+    "pulse", "hook", "pause", "wait", "u0", "u1", "u2", "u3", "u4", "u5", "u6", "u7", "u8", "u9",
+    "op", "oc", "initc", "initp", "scp", "setf", "setb", "cpi", "lpi", "chr", "cvr", "defc",
+    "swidm", "sdrfq", "sitm", "slm", "smicm", "snlq", "snrmq", "sshm", "ssubm", "ssupm", "sum",
+    "rwidm", "ritm", "rlm", "rmicm", "rshm", "rsubm", "rsupm", "rum", "mhpa", "mcud1", "mcub1",
+    "mcuf1", "mvpa", "mcuu1", "porder", "mcud", "mcub", "mcuf", "mcuu", "scs", "smgb", "smgbp",
+    "smglp", "smgrp", "smgt", "smgtp", "sbim", "scsd", "rbim", "rcsd", "subcs", "supcs", "docr",
+    "zerom", "csnm", "kmous", "minfo", "reqmp", "getm", "setaf", "setab", "pfxl", "devt", "csin",
+    "s0ds", "s1ds", "s2ds", "s3ds", "smglr", "smgtb", "birep", "binel", "bicr", "colornm", "defbi",
+    "endbi", "setcolor", "slines", "dispc", "smpch", "rmpch", "smsc", "rmsc", "pctrm", "scesc",
+    "scesa", "ehhlm", "elhlm", "elohlm", "erhlm", "ethlm", "evhlm", "sgr1", "slength", "OTi2",
+    "OTrs", "OTnl", "OTbc", "OTko", "OTma", "OTG2", "OTG3", "OTG1", "OTG4", "OTGR", "OTGL", "OTGU",
+    "OTGD", "OTGH", "OTGV", "OTGC", "meml", "memu", "box1",
+];
+
+/// Variable names of bool capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const BOOL_LONG_NAMES: [&str; 44] = [
+    "auto_left_margin", "auto_right_margin", "no_esc_ctlc", "ceol_standout_glitch",
+    "eat_newline_glitch", "erase_overstrike", "generic_type", "hard_copy", "has_meta_key",
+    "has_status_line", "insert_null_glitch", "memory_above", "memory_below", "move_insert_mode",
+    "move_standout_mode", "over_strike", "status_line_esc_ok", "dest_tabs_magic_smso",
+    "tilde_glitch", "transparent_underline", "xon_xoff", "needs_xon_xoff", "prtr_silent",
+    "hard_cursor", "non_rev_rmcup", "no_pad_char", "non_dest_scroll_region", "can_change",
+    "back_color_erase", "hue_lightness_saturation", "col_addr_glitch", "cr_cancels_micro_mode",
+    "has_print_wheel", "row_addr_glitch", "semi_auto_right_margin", "cpi_changes_res",
+    "lpi_changes_res", "backspaces_with_bs", "crt_no_scrolling", "no_correctly_working_cr",
+    "gnu_has_meta_key", "linefeed_is_newline", "has_hardware_tabs", "return_does_clr_eol",
+];
+
+/// Variable names of number capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const NUMBER_LONG_NAMES: [&str; 39] = [
+    "columns", "init_tabs", "lines", "lines_of_memory", "magic_cookie_glitch", "padding_baud_rate",
+    "virtual_terminal", "width_status_line", "num_labels", "label_height", "label_width",
+    "max_attributes", "maximum_windows", "max_colors", "max_pairs", "no_color_video",
+    "buffer_capacity", "dot_vert_spacing", "dot_horz_spacing", "max_micro_address",
+    "max_micro_jump", "micro_col_size", "micro_line_size", "number_of_pins", "output_res_char",
+    "output_res_line", "output_res_horz_inch", "output_res_vert_inch", "print_rate",
+    "wide_char_size", "buttons", "bit_image_entwining", "bit_image_type", "magic_cookie_glitch_ul",
+    "carriage_return_delay", "new_line_delay", "backspace_delay", "horizontal_tab_delay",
+    "number_of_function_keys",
+];
+
+/// Variable names of string capabilities in file order, including the obsolete ncurses entries
+#[cfg(feature = "names")]
+pub(crate) const STRING_LONG_NAMES: [&str; 414] = [
+    "back_tab", "bell", "carriage_return", "change_scroll_region", "clear_all_tabs", "clear_screen",
+    "clr_eol", "clr_eos", "column_address", "command_character", "cursor_address", "cursor_down",
+    "cursor_home", "cursor_invisible", "cursor_left", "cursor_mem_address", "cursor_normal",
+    "cursor_right", "cursor_to_ll", "cursor_up", "cursor_visible", "delete_character",
+    "delete_line", "dis_status_line", "down_half_line", "enter_alt_charset_mode",
+    "enter_blink_mode", "enter_bold_mode", "enter_ca_mode", "enter_delete_mode", "enter_dim_mode",
+    "enter_insert_mode", "enter_secure_mode", "enter_protected_mode", "enter_reverse_mode",
+    "enter_standout_mode", "enter_underline_mode", "erase_chars", "exit_alt_charset_mode",
+    "exit_attribute_mode", "exit_ca_mode", "exit_delete_mode", "exit_insert_mode",
+    "exit_standout_mode", "exit_underline_mode", "flash_screen", "form_feed", "from_status_line",
+    "init_1string", "init_2string", "init_3string", "init_file", "insert_character", "insert_line",
+    "insert_padding", "key_backspace", "key_catab", "key_clear", "key_ctab", "key_dc", "key_dl",
+    "key_down", "key_eic", "key_eol", "key_eos", "key_f0", "key_f1", "key_f10", "key_f2", "key_f3",
+    "key_f4", "key_f5", "key_f6", "key_f7", "key_f8", "key_f9", "key_home", "key_ic", "key_il",
+    "key_left", "key_ll", "key_npage", "key_ppage", "key_right", "key_sf", "key_sr", "key_stab",
+    "key_up", "keypad_local", "keypad_xmit", "lab_f0", "lab_f1", "lab_f10", "lab_f2", "lab_f3",
+    "lab_f4", "lab_f5", "lab_f6", "lab_f7", "lab_f8", "lab_f9", "meta_off", "meta_on", "newline",
+    "pad_char", "parm_dch", "parm_delete_line", "parm_down_cursor", "parm_ich", "parm_index",
+    "parm_insert_line", "parm_left_cursor", "parm_right_cursor", "parm_rindex", "parm_up_cursor",
+    "pkey_key", "pkey_local", "pkey_xmit", "print_screen", "prtr_off", "prtr_on", "repeat_char",
+    "reset_1string", "reset_2string", "reset_3string", "reset_file", "restore_cursor",
+    "row_address", "save_cursor", "scroll_forward", "scroll_reverse", "set_attributes", "set_tab",
+    "set_window", "tab", "to_status_line", "underline_char", "up_half_line", "init_prog", "key_a1",
+    "key_a3", "key_b2", "key_c1", "key_c3", "prtr_non", "char_padding", "acs_chars", "plab_norm",
+    "key_btab", "enter_xon_mode", "exit_xon_mode", "enter_am_mode", "exit_am_mode", "xon_character",
+    "xoff_character", "ena_acs", "label_on", "label_off", "key_beg", "key_cancel", "key_close",
+    "key_command", "key_copy", "key_create", "key_end", "key_enter", "key_exit", "key_find",
+    "key_help", "key_mark", "key_message", "key_move", "key_next", "key_open", "key_options",
+    "key_previous", "key_print", "key_redo", "key_reference", "key_refresh", "key_replace",
+    "key_restart", "key_resume", "key_save", "key_suspend", "key_undo", "key_sbeg", "key_scancel",
+    "key_scommand", "key_scopy", "key_screate", "key_sdc", "key_sdl", "key_select", "key_send",
+    "key_seol", "key_sexit", "key_sfind", "key_shelp", "key_shome", "key_sic", "key_sleft",
+    "key_smessage", "key_smove", "key_snext", "key_soptions", "key_sprevious", "key_sprint",
+    "key_sredo", "key_sreplace", "key_sright", "key_srsume", "key_ssave", "key_ssuspend",
+    "key_sundo", "req_for_input", "key_f11", "key_f12", "key_f13", "key_f14", "key_f15", "key_f16",
+    "key_f17", "key_f18", "key_f19", "key_f20", "key_f21", "key_f22", "key_f23", "key_f24",
+    "key_f25", "key_f26", "key_f27", "key_f28", "key_f29", "key_f30", "key_f31", "key_f32",
+    "key_f33", "key_f34", "key_f35", "key_f36", "key_f37", "key_f38", "key_f39", "key_f40",
+    "key_f41", "key_f42", "key_f43", "key_f44", "key_f45", "key_f46", "key_f47", "key_f48",
+    "key_f49", "key_f50", "key_f51", "key_f52", "key_f53", "key_f54", "key_f55", "key_f56",
+    "key_f57", "key_f58", "key_f59", "key_f60", "key_f61", "key_f62", "key_f63", "clr_bol",
+    "clear_margins", "set_left_margin", "set_right_margin", "label_format", "set_clock",
+    "display_clock", "remove_clock", "create_window", "goto_window", "hangup", "dial_phone",
+    "quick_dial", "tone", "pulse", "flash_hook", "fixed_pause", "wait_tone", "user0", "user1",
+    "user2", "user3", "user4", "user5", "user6", "user7", "user8", "user9", "orig_pair",
+    "orig_colors", "initialize_color", "initialize_pair", "set_color_pair", "set_foreground",
+    "set_background", "change_char_pitch", "change_line_pitch", "change_res_horz",
+    "change_res_vert", "define_char", "enter_doublewide_mode", "enter_draft_quality",
+    "enter_italics_mode", "enter_leftward_mode", "enter_micro_mode", "enter_near_letter_quality",
+    "enter_normal_quality", "enter_shadow_mode", "enter_subscript_mode", "enter_superscript_mode",
+    "enter_upward_mode", "exit_doublewide_mode", "exit_italics_mode", "exit_leftward_mode",
+    "exit_micro_mode", "exit_shadow_mode", "exit_subscript_mode", "exit_superscript_mode",
+    "exit_upward_mode", "micro_column_address", "micro_down", "micro_left", "micro_right",
+    "micro_row_address", "micro_up", "order_of_pins", "parm_down_micro", "parm_left_micro",
+    "parm_right_micro", "parm_up_micro", "select_char_set", "set_bottom_margin",
+    "set_bottom_margin_parm", "set_left_margin_parm", "set_right_margin_parm", "set_top_margin",
+    "set_top_margin_parm", "start_bit_image", "start_char_set_def", "stop_bit_image",
+    "stop_char_set_def", "subscript_characters", "superscript_characters", "these_cause_cr",
+    "zero_motion", "char_set_names", "key_mouse", "mouse_info", "req_mouse_pos", "get_mouse",
+    "set_a_foreground", "set_a_background", "pkey_plab", "device_type", "code_set_init",
+    "set0_des_seq", "set1_des_seq", "set2_des_seq", "set3_des_seq", "set_lr_margin",
+    "set_tb_margin", "bit_image_repeat", "bit_image_newline", "bit_image_carriage_return",
+    "color_names", "define_bit_image_region", "end_bit_image_region", "set_color_band",
+    "set_page_length", "display_pc_char", "enter_pc_charset_mode", "exit_pc_charset_mode",
+    "enter_scancode_mode", "exit_scancode_mode", "pc_term_options", "scancode_escape",
+    "alt_scancode_esc", "enter_horizontal_hl_mode", "enter_left_hl_mode", "enter_low_hl_mode",
+    "enter_right_hl_mode", "enter_top_hl_mode", "enter_vertical_hl_mode", "set_a_attributes",
+    "set_pglen_inch", "termcap_init2", "termcap_reset", "linefeed_if_not_lf", "backspace_if_not_bs",
+    "other_non_function_keys", "arrow_key_map", "acs_ulcorner", "acs_llcorner", "acs_urcorner",
+    "acs_lrcorner", "acs_ltee", "acs_rtee", "acs_btee", "acs_ttee", "acs_hline", "acs_vline",
+    "acs_plus", "memory_lock", "memory_unlock", "box_chars_1",
+];
+
+/// Termcap codes of bool capabilities in file order
+#[cfg(feature = "names")]
+pub(crate) const BOOL_TERMCAP_CODES: [&str; 44] = [
+    "bw", "am", "xb", "xs", "xn", "eo", "gn", "hc", "km", "hs", "in", "da", "db", "mi", "ms", "os",
+    "es", "xt", "hz", "ul", "xo", "nx", "5i", "HC", "NR", "NP", "ND", "cc", "ut", "hl", "YA", "YB",
+    "YC", "YD", "YE", "YF", "YG", "bs", "ns", "nc", "MT", "NL", "pt", "xr",
+];
+
+/// Termcap codes of number capabilities in file order
+#[cfg(feature = "names")]
+pub(crate) const NUMBER_TERMCAP_CODES: [&str; 39] = [
+    "co", "it", "li", "lm", "sg", "pb", "vt", "ws", "Nl", "lh", "lw", "ma", "MW", "Co", "pa", "NC",
+    "Ya", "Yb", "Yc", "Yd", "Ye", "Yf", "Yg", "Yh", "Yi", "Yj", "Yk", "Yl", "Ym", "Yn", "BT", "Yo",
+    "Yp", "ug", "dC", "dN", "dB", "dT", "kn",
+];
+
+/// Termcap codes of string capabilities in file order
+#[cfg(feature = "names")]
+pub(crate) const STRING_TERMCAP_CODES: [&str; 414] = [
+    "bt", "bl", "cr", "cs", "ct", "cl", "ce", "cd", "ch", "CC", "cm", "do", "ho", "vi", "le", "CM",
+    "ve", "nd", "ll", "up", "vs", "dc", "dl", "ds", "hd", "as", "mb", "md", "ti", "dm", "mh", "im",
+    "mk", "mp", "mr", "so", "us", "ec", "ae", "me", "te", "ed", "ei", "se", "ue", "vb", "ff", "fs",
+    "i1", "is", "i3", "if", "ic", "al", "ip", "kb", "ka", "kC", "kt", "kD", "kL", "kd", "kM", "kE",
+    "kS", "k0", "k1", "k;", "k2", "k3", "k4", "k5", "k6", "k7", "k8", "k9", "kh", "kI", "kA", "kl",
+    "kH", "kN", "kP", "kr", "kF", "kR", "kT", "ku", "ke", "ks", "l0", "l1", "la", "l2", "l3", "l4",
+    "l5", "l6", "l7", "l8", "l9", "mo", "mm", "nw", "pc", "DC", "DL", "DO", "IC", "SF", "AL", "LE",
+    "RI", "SR", "UP", "pk", "pl", "px", "ps", "pf", "po", "rp", "r1", "r2", "r3", "rf", "rc", "cv",
+    "sc", "sf", "sr", "sa", "st", "wi", "ta", "ts", "uc", "hu", "iP", "K1", "K3", "K2", "K4", "K5",
+    "pO", "rP", "ac", "pn", "kB", "SX", "RX", "SA", "RA", "XN", "XF", "eA", "LO", "LF", "@1", "@2",
+    "@3", "@4", "@5", "@6", "@7", "@8", "@9", "@0", "%1", "%2", "%3", "%4", "%5", "%6", "%7", "%8",
+    "%9", "%0", "&1", "&2", "&3", "&4", "&5", "&6", "&7", "&8", "&9", "&0", "*1", "*2", "*3", "*4",
+    "*5", "*6", "*7", "*8", "*9", "*0", "#1", "#2", "#3", "#4", "%a", "%b", "%c", "%d", "%e", "%f",
+    "%g", "%h", "%i", "%j", "!1", "!2", "!3", "RF", "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8",
+    "F9", "FA", "FB", "FC", "FD", "FE", "FF", "FG", "FH", "FI", "FJ", "FK", "FL", "FM", "FN", "FO",
+    "FP", "FQ", "FR", "FS", "FT", "FU", "FV", "FW", "FX", "FY", "FZ", "Fa", "Fb", "Fc", "Fd", "Fe",
+    "Ff", "Fg", "Fh", "Fi", "Fj", "Fk", "Fl", "Fm", "Fn", "Fo", "Fp", "Fq", "Fr", "cb", "MC", "ML",
+    "MR", "Lf", "SC", "DK", "RC", "CW", "WG", "HU", "DI", "QD", "TO", "PU", "fh", "PA", "WA", "u0",
+    "u1", "u2", "u3", "u4", "u5", "u6", "u7", "u8", "u9", "op", "oc", "Ic", "Ip", "sp", "Sf", "Sb",
+    "ZA", "ZB", "ZC", "ZD", "ZE", "ZF", "ZG", "ZH", "ZI", "ZJ", "ZK", "ZL", "ZM", "ZN", "ZO", "ZP",
+    "ZQ", "ZR", "ZS", "ZT", "ZU", "ZV", "ZW", "ZX", "ZY", "ZZ", "Za", "Zb", "Zc", "Zd", "Ze", "Zf",
+    "Zg", "Zh", "Zi", "Zj", "Zk", "Zl", "Zm", "Zn", "Zo", "Zp", "Zq", "Zr", "Zs", "Zt", "Zu", "Zv",
+    "Zw", "Zx", "Zy", "Km", "Mi", "RQ", "Gm", "AF", "AB", "xl", "dv", "ci", "s0", "s1", "s2", "s3",
+    "ML", "MT", "Xy", "Zz", "Yv", "Yw", "Yx", "Yy", "Yz", "YZ", "S1", "S2", "S3", "S4", "S5", "S6",
+    "S7", "S8", "Xh", "Xl", "Xo", "Xr", "Xt", "Xv", "sA", "YI", "i2", "rs", "nl", "bc", "ko", "ma",
+    "G2", "G3", "G1", "G4", "GR", "GL", "GU", "GD", "GH", "GV", "GC", "ml", "mu", "bx",
+];
+
+/// Descriptions of bool capabilities from terminfo(5) in file order
+#[cfg(feature = "descriptions")]
+pub(crate) const BOOL_DESCRIPTIONS: [&str; 44] = [
+    "cub1 wraps from column 0 to last column",
+    "terminal has automatic margins",
+    "beehive (f1=escape, f2=ctrl C)",
+    "standout not erased by overwriting (hp)",
+    "newline ignored after 80 cols (concept)",
+    "can erase overstrikes with a blank",
+    "generic line type",
+    "hardcopy terminal",
+    "Has a meta key (i.e., sets 8th-bit)",
+    "has extra status line",
+    "insert mode distinguishes nulls",
+    "display may be retained above the screen",
+    "display may be retained below the screen",
+    "safe to move while in insert mode",
+    "safe to move while in standout mode",
+    "terminal can overstrike",
+    "escape can be used on the status line",
+    "tabs destructive, magic so char (t1061)",
+    "cannot print ~'s (Hazeltine)",
+    "underline character overstrikes",
+    "terminal uses xon/xoff handshaking",
+    "padding will not work, xon/xoff required",
+    "printer will not echo on screen",
+    "cursor is hard to see",
+    "smcup does not reverse rmcup",
+    "pad character does not exist",
+    "scrolling region is non-destructive",
+    "terminal can re-define existing colors",
+    "screen erased with background color",
+    "terminal uses only HLS color notation (Tektronix)",
+    "only positive motion for hpa/mhpa caps",
+    "using cr turns off micro mode",
+    "printer needs operator to change character set",
+    "only positive motion for vpa/mvpa caps",
+    "printing in last column causes cr",
+    "changing character pitch changes resolution",
+    "changing line pitch changes resolution",
+    "uses ^H to move left (obsolete)",
+    "crt cannot scroll (obsolete)",
+    "no way to go to start of line (obsolete)",
+    "has meta key (obsolete)",
+    "move down with \\n (obsolete)",
+    "has 8-char tabs invoked with ^I (obsolete)",
+    "return clears the line (obsolete)",
+];
+
+/// Descriptions of number capabilities from terminfo(5) in file order
+#[cfg(feature = "descriptions")]
+pub(crate) const NUMBER_DESCRIPTIONS: [&str; 39] = [
+    "number of columns in a line",
+    "tabs initially every # spaces",
+    "number of lines on screen or page",
+    "lines of memory if > line. 0 means varies",
+    "number of blank characters left by smso or rmso",
+    "lowest baud rate where padding needed",
+    "virtual terminal number (CB/unix)",
+    "number of columns in status line",
+    "number of labels on screen",
+    "rows in each label",
+    "columns in each label",
+    "maximum combined attributes terminal can handle",
+    "maximum number of definable windows",
+    "maximum number of colors on screen",
+    "maximum number of color-pairs on the screen",
+    "video attributes that cannot be used with colors",
+    "numbers of bytes buffered before printing",
+    "spacing of pins vertically in pins per inch",
+    "spacing of dots horizontally in dots per inch",
+    "maximum value in micro_..._address",
+    "maximum value in parm_..._micro",
+    "character step size when in micro mode",
+    "line step size when in micro mode",
+    "numbers of pins in print-head",
+    "horizontal resolution in units per line",
+    "vertical resolution in units per line",
+    "horizontal resolution in units per inch",
+    "vertical resolution in units per inch",
+    "print rate in characters per second",
+    "character step size when in double wide mode",
+    "number of buttons on mouse",
+    "number of passes for each bit-image row",
+    "type of bit-image device",
+    "number of blanks left by ul (obsolete)",
+    "padding needed for CR (obsolete)",
+    "padding needed for LF (obsolete)",
+    "padding required for ^H (obsolete)",
+    "padding required for ^I (obsolete)",
+    "count of function keys (obsolete)",
+];
+
+/// Descriptions of string capabilities from terminfo(5) in file order
+#[cfg(feature = "descriptions")]
+pub(crate) const STRING_DESCRIPTIONS: [&str; 414] = [
+    "back tab",
+    "audible signal (bell)",
+    "carriage return",
+    "change region to line #1 to line #2",
+    "clear all tab stops",
+    "clear screen and home cursor",
+    "clear to end of line",
+    "clear to end of screen",
+    "horizontal position #1, absolute",
+    "terminal settable cmd character in prototype !?",
+    "move to row #1 columns #2",
+    "down one line",
+    "home cursor (if no cup)",
+    "make cursor invisible",
+    "move left one space",
+    "memory relative cursor addressing, move to row #1 columns #2",
+    "make cursor appear normal (undo civis/cvvis)",
+    "non-destructive space (move right one space)",
+    "last line, first column (if no cup)",
+    "up one line",
+    "make cursor very visible",
+    "delete character",
+    "delete line",
+    "disable status line",
+    "half a line down",
+    "start alternate character set",
+    "turn on blinking",
+    "turn on bold (extra bright) mode",
+    "string to start programs using cup",
+    "enter delete mode",
+    "turn on half-bright mode",
+    "enter insert mode",
+    "turn on blank mode (characters invisible)",
+    "turn on protected mode",
+    "turn on reverse video mode",
+    "begin standout mode",
+    "begin underline mode",
+    "erase #1 characters",
+    "end alternate character set",
+    "turn off all attributes",
+    "strings to end programs using cup",
+    "end delete mode",
+    "exit insert mode",
+    "exit standout mode",
+    "exit underline mode",
+    "visible bell (may not move cursor)",
+    "hardcopy terminal page eject",
+    "return from status line",
+    "initialization string",
+    "initialization string",
+    "initialization string",
+    "name of initialization file",
+    "insert character",
+    "insert line",
+    "insert padding after inserted character",
+    "backspace key",
+    "clear-all-tabs key",
+    "clear-screen or erase key",
+    "clear-tab key",
+    "delete-character key",
+    "delete-line key",
+    "down-arrow key",
+    "sent by rmir or smir in insert mode",
+    "clear-to-end-of-line key",
+    "clear-to-end-of-screen key",
+    "F0 function key",
+    "F1 function key",
+    "F10 function key",
+    "F2 function key",
+    "F3 function key",
+    "F4 function key",
+    "F5 function key",
+    "F6 function key",
+    "F7 function key",
+    "F8 function key",
+    "F9 function key",
+    "home key",
+    "insert-character key",
+    "insert-line key",
+    "left-arrow key",
+    "lower-left key (home down)",
+    "next-page key",
+    "previous-page key",
+    "right-arrow key",
+    "scroll-forward key",
+    "scroll-backward key",
+    "set-tab key",
+    "up-arrow key",
+    "leave 'keyboard_transmit' mode",
+    "enter 'keyboard_transmit' mode",
+    "label on function key f0 if not f0",
+    "label on function key f1 if not f1",
+    "label on function key f10 if not f10",
+    "label on function key f2 if not f2",
+    "label on function key f3 if not f3",
+    "label on function key f4 if not f4",
+    "label on function key f5 if not f5",
+    "label on function key f6 if not f6",
+    "label on function key f7 if not f7",
+    "label on function key f8 if not f8",
+    "label on function key f9 if not f9",
+    "turn off meta mode",
+    "turn on meta mode (8th-bit on)",
+    "newline (behave like cr followed by lf)",
+    "padding char (instead of null)",
+    "delete #1 characters",
+    "delete #1 lines",
+    "down #1 lines",
+    "insert #1 characters",
+    "scroll forward #1 lines",
+    "insert #1 lines",
+    "move #1 characters to the left",
+    "move #1 characters to the right",
+    "scroll back #1 lines",
+    "up #1 lines",
+    "program function key #1 to type string #2",
+    "program function key #1 to execute string #2",
+    "program function key #1 to transmit string #2",
+    "print contents of screen",
+    "turn off printer",
+    "turn on printer",
+    "repeat char #1 #2 times",
+    "reset string",
+    "reset string",
+    "reset string",
+    "name of reset file",
+    "restore cursor to position of last save_cursor",
+    "vertical position #1 absolute",
+    "save current cursor position",
+    "scroll text up",
+    "scroll text down",
+    "define video attributes #1-#9 (PG9)",
+    "set a tab in every row, current columns",
+    "current window is lines #1-#2 cols #3-#4",
+    "tab to next 8-space hardware tab stop",
+    "move to status line, column #1",
+    "underline char and move past it",
+    "half a line up",
+    "path name of program for initialization",
+    "upper left of keypad",
+    "upper right of keypad",
+    "center of keypad",
+    "lower left of keypad",
+    "lower right of keypad",
+    "turn on printer for #1 bytes",
+    "like ip but when in insert mode",
+    "graphics charset pairs, based on vt100",
+    "program label #1 to show string #2",
+    "back-tab key",
+    "turn on xon/xoff handshaking",
+    "turn off xon/xoff handshaking",
+    "turn on automatic margins",
+    "turn off automatic margins",
+    "XON character",
+    "XOFF character",
+    "enable alternate char set",
+    "turn on soft labels",
+    "turn off soft labels",
+    "begin key",
+    "cancel key",
+    "close key",
+    "command key",
+    "copy key",
+    "create key",
+    "end key",
+    "enter/send key",
+    "exit key",
+    "find key",
+    "help key",
+    "mark key",
+    "message key",
+    "move key",
+    "next key",
+    "open key",
+    "options key",
+    "previous key",
+    "print key",
+    "redo key",
+    "reference key",
+    "refresh key",
+    "replace key",
+    "restart key",
+    "resume key",
+    "save key",
+    "suspend key",
+    "undo key",
+    "shifted begin key",
+    "shifted cancel key",
+    "shifted command key",
+    "shifted copy key",
+    "shifted create key",
+    "shifted delete-character key",
+    "shifted delete-line key",
+    "select key",
+    "shifted end key",
+    "shifted clear-to-end-of-line key",
+    "shifted exit key",
+    "shifted find key",
+    "shifted help key",
+    "shifted home key",
+    "shifted insert-character key",
+    "shifted left-arrow key",
+    "shifted message key",
+    "shifted move key",
+    "shifted next key",
+    "shifted options key",
+    "shifted previous key",
+    "shifted print key",
+    "shifted redo key",
+    "shifted replace key",
+    "shifted right-arrow key",
+    "shifted resume key",
+    "shifted save key",
+    "shifted suspend key",
+    "shifted undo key",
+    "send next input char (for ptys)",
+    "F11 function key",
+    "F12 function key",
+    "F13 function key",
+    "F14 function key",
+    "F15 function key",
+    "F16 function key",
+    "F17 function key",
+    "F18 function key",
+    "F19 function key",
+    "F20 function key",
+    "F21 function key",
+    "F22 function key",
+    "F23 function key",
+    "F24 function key",
+    "F25 function key",
+    "F26 function key",
+    "F27 function key",
+    "F28 function key",
+    "F29 function key",
+    "F30 function key",
+    "F31 function key",
+    "F32 function key",
+    "F33 function key",
+    "F34 function key",
+    "F35 function key",
+    "F36 function key",
+    "F37 function key",
+    "F38 function key",
+    "F39 function key",
+    "F40 function key",
+    "F41 function key",
+    "F42 function key",
+    "F43 function key",
+    "F44 function key",
+    "F45 function key",
+    "F46 function key",
+    "F47 function key",
+    "F48 function key",
+    "F49 function key",
+    "F50 function key",
+    "F51 function key",
+    "F52 function key",
+    "F53 function key",
+    "F54 function key",
+    "F55 function key",
+    "F56 function key",
+    "F57 function key",
+    "F58 function key",
+    "F59 function key",
+    "F60 function key",
+    "F61 function key",
+    "F62 function key",
+    "F63 function key",
+    "Clear to beginning of line",
+    "clear right and left soft margins",
+    "set left soft margin at current column.",
+    "set right soft margin at current column",
+    "label format",
+    "set clock, #1 hrs #2 mins #3 secs",
+    "display clock",
+    "remove clock",
+    "define a window #1 from #2,#3 to #4,#5",
+    "go to window #1",
+    "hang-up phone",
+    "dial number #1",
+    "dial number #1 without checking",
+    "select touch tone dialing",
+    "select pulse dialing",
+    "flash switch hook",
+    "pause for 2-3 seconds",
+    "wait for dial-tone",
+    "User string #0",
+    "User string #1",
+    "User string #2",
+    "User string #3",
+    "User string #4",
+    "User string #5",
+    "User string #6",
+    "User string #7",
+    "User string #8",
+    "User string #9",
+    "Set default pair to its original value",
+    "Set all color pairs to the original ones",
+    "initialize color #1 to (#2,#3,#4)",
+    "Initialize color pair #1 to fg=(#2,#3,#4), bg=(#5,#6,#7)",
+    "Set current color pair to #1",
+    "Set foreground color #1",
+    "Set background color #1",
+    "Change number of characters per inch to #1",
+    "Change number of lines per inch to #1",
+    "Change horizontal resolution to #1",
+    "Change vertical resolution to #1",
+    "Define a character #1, #2 dots wide, descender #3",
+    "Enter double-wide mode",
+    "Enter draft-quality mode",
+    "Enter italic mode",
+    "Start leftward carriage motion",
+    "Start micro-motion mode",
+    "Enter NLQ mode",
+    "Enter normal-quality mode",
+    "Enter shadow-print mode",
+    "Enter subscript mode",
+    "Enter superscript mode",
+    "Start upward carriage motion",
+    "End double-wide mode",
+    "End italic mode",
+    "End left-motion mode",
+    "End micro-motion mode",
+    "End shadow-print mode",
+    "End subscript mode",
+    "End superscript mode",
+    "End reverse character motion",
+    "Like column_address in micro mode",
+    "Like cursor_down in micro mode",
+    "Like cursor_left in micro mode",
+    "Like cursor_right in micro mode",
+    "Like row_address #1 in micro mode",
+    "Like cursor_up in micro mode",
+    "Match software bits to print-head pins",
+    "Like parm_down_cursor in micro mode",
+    "Like parm_left_cursor in micro mode",
+    "Like parm_right_cursor in micro mode",
+    "Like parm_up_cursor in micro mode",
+    "Select character set, #1",
+    "Set bottom margin at current line",
+    "Set bottom margin at line #1 or (if smgtp is not given) #2 lines from bottom",
+    "Set left (right) margin at column #1",
+    "Set right margin at column #1",
+    "Set top margin at current line",
+    "Set top (bottom) margin at row #1",
+    "Start printing bit image graphics",
+    "Start character set definition #1, with #2 characters in the set",
+    "Stop printing bit image graphics",
+    "End definition of character set #1",
+    "List of subscriptable characters",
+    "List of superscriptable characters",
+    "Printing any of these characters causes CR",
+    "No motion for subsequent character",
+    "Produce #1'th item from list of character set names",
+    "Mouse event has occurred",
+    "Mouse status information",
+    "Request mouse position",
+    "Curses should get button events, parameter #1 not documented.",
+    "Set foreground color to #1, using ANSI escape",
+    "Set background color to #1, using ANSI escape",
+    "Program function key #1 to type string #2 and show string #3",
+    "Indicate language/codeset support",
+    "Init sequence for multiple codesets",
+    "Shift to codeset 0 (EUC set 0, ASCII)",
+    "Shift to codeset 1",
+    "Shift to codeset 2",
+    "Shift to codeset 3",
+    "Set both left and right margins to #1, #2.  (ML is not in BSD termcap).",
+    "Sets both top and bottom margins to #1, #2",
+    "Repeat bit image cell #1 #2 times",
+    "Move to next row of the bit image",
+    "Move to beginning of same row",
+    "Give name for color #1",
+    "Define rectangular bit image region",
+    "End a bit-image region",
+    "Change to ribbon color #1",
+    "Set page length to #1 lines",
+    "Display PC character #1",
+    "Enter PC character display mode",
+    "Exit PC character display mode",
+    "Enter PC scancode mode",
+    "Exit PC scancode mode",
+    "PC terminal options",
+    "Escape for scancode emulation",
+    "Alternate escape for scancode emulation",
+    "Enter horizontal highlight mode",
+    "Enter left highlight mode",
+    "Enter low highlight mode",
+    "Enter right highlight mode",
+    "Enter top highlight mode",
+    "Enter vertical highlight mode",
+    "Define second set of video attributes #1-#6",
+    "Set page length to #1 hundredth of an inch (some implementations use sL for termcap).",
+    "secondary initialization string (obsolete)",
+    "terminal reset string (obsolete)",
+    "use to move down (obsolete)",
+    "move left, if not ^H (obsolete)",
+    "list of self-mapped keycaps (obsolete)",
+    "map motion-keys for vi version 2 (obsolete)",
+    "single upper left (obsolete)",
+    "single lower left (obsolete)",
+    "single upper right (obsolete)",
+    "single lower right (obsolete)",
+    "tee pointing right (obsolete)",
+    "tee pointing left (obsolete)",
+    "tee pointing up (obsolete)",
+    "tee pointing down (obsolete)",
+    "single horizontal line (obsolete)",
+    "single vertical line (obsolete)",
+    "single intersection (obsolete)",
+    "lock memory above cursor",
+    "unlock memory",
+    "box characters primary set",
+];
+
+/// All categories in the order of terminfo(5)
+const CATEGORIES: [Category; 17] = [
+    Category::CursorMotion, Category::Scrolling, Category::Margins, Category::Editing,
+    Category::Highlighting, Category::Bell, Category::Color, Category::CharacterSet,
+    Category::FunctionKeys, Category::Labels, Category::Mouse, Category::StatusLine, Category::Tabs,
+    Category::Initialization, Category::Padding, Category::Printer, Category::Miscellaneous,
+];
+
+/// Categories of bool capabilities in file order
+pub(crate) const BOOL_CATEGORIES: [Category; 44] = [
+    Category::Margins, Category::Margins, Category::Miscellaneous, Category::Highlighting,
+    Category::Margins, Category::Editing, Category::Miscellaneous, Category::Printer,
+    Category::FunctionKeys, Category::StatusLine, Category::Editing, Category::Scrolling,
+    Category::Scrolling, Category::Editing, Category::Highlighting, Category::Highlighting,
+    Category::StatusLine, Category::Tabs, Category::Highlighting, Category::Highlighting,
+    Category::Padding, Category::Padding, Category::Printer, Category::Highlighting,
+    Category::Initialization, Category::Padding, Category::Scrolling, Category::Color,
+    Category::Color, Category::Color, Category::CursorMotion, Category::Printer, Category::Printer,
+    Category::CursorMotion, Category::Margins, Category::Printer, Category::Printer,
+    Category::CursorMotion, Category::Scrolling, Category::CursorMotion, Category::FunctionKeys,
+    Category::CursorMotion, Category::Tabs, Category::Editing,
+];
+
+/// Categories of number capabilities in file order
+pub(crate) const NUMBER_CATEGORIES: [Category; 39] = [
+    Category::Miscellaneous, Category::Tabs, Category::Miscellaneous, Category::Scrolling,
+    Category::Highlighting, Category::Padding, Category::Miscellaneous, Category::StatusLine,
+    Category::Labels, Category::Labels, Category::Labels, Category::Highlighting,
+    Category::Miscellaneous, Category::Color, Category::Color, Category::Color, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Mouse, Category::Printer,
+    Category::Printer, Category::Highlighting, Category::Padding, Category::Padding,
+    Category::Padding, Category::Padding, Category::FunctionKeys,
+];
+
+/// Categories of string capabilities in file order
+pub(crate) const STRING_CATEGORIES: [Category; 414] = [
+    Category::Tabs, Category::Bell, Category::CursorMotion, Category::Scrolling, Category::Tabs,
+    Category::Editing, Category::Editing, Category::Editing, Category::CursorMotion,
+    Category::Initialization, Category::CursorMotion, Category::CursorMotion,
+    Category::CursorMotion, Category::Highlighting, Category::CursorMotion, Category::CursorMotion,
+    Category::Highlighting, Category::CursorMotion, Category::CursorMotion, Category::CursorMotion,
+    Category::Highlighting, Category::Editing, Category::Editing, Category::StatusLine,
+    Category::CursorMotion, Category::CharacterSet, Category::Highlighting, Category::Highlighting,
+    Category::Initialization, Category::Editing, Category::Highlighting, Category::Editing,
+    Category::Highlighting, Category::Highlighting, Category::Highlighting, Category::Highlighting,
+    Category::Highlighting, Category::Editing, Category::CharacterSet, Category::Highlighting,
+    Category::Initialization, Category::Editing, Category::Editing, Category::Highlighting,
+    Category::Highlighting, Category::Bell, Category::Printer, Category::StatusLine,
+    Category::Initialization, Category::Initialization, Category::Initialization,
+    Category::Initialization, Category::Editing, Category::Editing, Category::Padding,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::Labels,
+    Category::Labels, Category::Labels, Category::Labels, Category::Labels, Category::Labels,
+    Category::Labels, Category::Labels, Category::Labels, Category::Labels, Category::Labels,
+    Category::FunctionKeys, Category::FunctionKeys, Category::CursorMotion, Category::Padding,
+    Category::Editing, Category::Editing, Category::CursorMotion, Category::Editing,
+    Category::Scrolling, Category::Editing, Category::CursorMotion, Category::CursorMotion,
+    Category::Scrolling, Category::CursorMotion, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::Printer, Category::Printer, Category::Printer,
+    Category::Editing, Category::Initialization, Category::Initialization, Category::Initialization,
+    Category::Initialization, Category::CursorMotion, Category::CursorMotion,
+    Category::CursorMotion, Category::Scrolling, Category::Scrolling, Category::Highlighting,
+    Category::Tabs, Category::Scrolling, Category::Tabs, Category::StatusLine,
+    Category::Highlighting, Category::CursorMotion, Category::Initialization,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::Printer, Category::Padding, Category::CharacterSet,
+    Category::Labels, Category::FunctionKeys, Category::Padding, Category::Padding,
+    Category::Margins, Category::Margins, Category::Padding, Category::Padding,
+    Category::CharacterSet, Category::Labels, Category::Labels, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::Miscellaneous, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::Editing, Category::Margins,
+    Category::Margins, Category::Margins, Category::Labels, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Miscellaneous, Category::Miscellaneous,
+    Category::Miscellaneous, Category::Color, Category::Color, Category::Color, Category::Color,
+    Category::Color, Category::Color, Category::Color, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Highlighting,
+    Category::Printer, Category::Highlighting, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Highlighting, Category::Highlighting,
+    Category::Highlighting, Category::Printer, Category::Highlighting, Category::Highlighting,
+    Category::Printer, Category::Printer, Category::Highlighting, Category::Highlighting,
+    Category::Highlighting, Category::Printer, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Printer,
+    Category::CharacterSet, Category::Margins, Category::Margins, Category::Margins,
+    Category::Margins, Category::Margins, Category::Margins, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Printer, Category::Printer, Category::Printer,
+    Category::Printer, Category::Printer, Category::Mouse, Category::Mouse, Category::Mouse,
+    Category::Mouse, Category::Color, Category::Color, Category::FunctionKeys,
+    Category::CharacterSet, Category::CharacterSet, Category::CharacterSet, Category::CharacterSet,
+    Category::CharacterSet, Category::CharacterSet, Category::Margins, Category::Margins,
+    Category::Printer, Category::Printer, Category::Printer, Category::Color, Category::Printer,
+    Category::Printer, Category::Color, Category::Printer, Category::CharacterSet,
+    Category::CharacterSet, Category::CharacterSet, Category::FunctionKeys, Category::FunctionKeys,
+    Category::FunctionKeys, Category::FunctionKeys, Category::FunctionKeys, Category::Highlighting,
+    Category::Highlighting, Category::Highlighting, Category::Highlighting, Category::Highlighting,
+    Category::Highlighting, Category::Highlighting, Category::Printer, Category::Initialization,
+    Category::Initialization, Category::CursorMotion, Category::CursorMotion,
+    Category::FunctionKeys, Category::FunctionKeys, Category::CharacterSet, Category::CharacterSet,
+    Category::CharacterSet, Category::CharacterSet, Category::CharacterSet, Category::CharacterSet,
+    Category::CharacterSet, Category::CharacterSet, Category::CharacterSet, Category::CharacterSet,
+    Category::CharacterSet, Category::Scrolling, Category::Scrolling, Category::CharacterSet,
+];
+
+/// All bool capabilities in file order
+pub(crate) const BOOL_CAPABILITIES: [BoolCapability; 44] = [
+    BoolCapability::AutoLeftMargin, BoolCapability::AutoRightMargin, BoolCapability::NoEscCtlc,
+    BoolCapability::CeolStandoutGlitch, BoolCapability::EatNewlineGlitch,
+    BoolCapability::EraseOverstrike, BoolCapability::GenericType, BoolCapability::HardCopy,
+    BoolCapability::HasMetaKey, BoolCapability::HasStatusLine, BoolCapability::InsertNullGlitch,
+    BoolCapability::MemoryAbove, BoolCapability::MemoryBelow, BoolCapability::MoveInsertMode,
+    BoolCapability::MoveStandoutMode, BoolCapability::OverStrike, BoolCapability::StatusLineEscOk,
+    BoolCapability::DestTabsMagicSmso, BoolCapability::TildeGlitch,
+    BoolCapability::TransparentUnderline, BoolCapability::XonXoff, BoolCapability::NeedsXonXoff,
+    BoolCapability::PrtrSilent, BoolCapability::HardCursor, BoolCapability::NonRevRmcup,
+    BoolCapability::NoPadChar, BoolCapability::NonDestScrollRegion, BoolCapability::CanChange,
+    BoolCapability::BackColorErase, BoolCapability::HueLightnessSaturation,
+    BoolCapability::ColAddrGlitch, BoolCapability::CrCancelsMicroMode,
+    BoolCapability::HasPrintWheel, BoolCapability::RowAddrGlitch,
+    BoolCapability::SemiAutoRightMargin, BoolCapability::CpiChangesRes,
+    BoolCapability::LpiChangesRes, BoolCapability::BackspacesWithBs, BoolCapability::CrtNoScrolling,
+    BoolCapability::NoCorrectlyWorkingCr, BoolCapability::GnuHasMetaKey,
+    BoolCapability::LinefeedIsNewline, BoolCapability::HasHardwareTabs,
+    BoolCapability::ReturnDoesClrEol,
+];
+
+/// All number capabilities in file order
+pub(crate) const NUMBER_CAPABILITIES: [NumberCapability; 39] = [
+    NumberCapability::Columns, NumberCapability::InitTabs, NumberCapability::Lines,
+    NumberCapability::LinesOfMemory, NumberCapability::MagicCookieGlitch,
+    NumberCapability::PaddingBaudRate, NumberCapability::VirtualTerminal,
+    NumberCapability::WidthStatusLine, NumberCapability::NumLabels, NumberCapability::LabelHeight,
+    NumberCapability::LabelWidth, NumberCapability::MaxAttributes, NumberCapability::MaximumWindows,
+    NumberCapability::MaxColors, NumberCapability::MaxPairs, NumberCapability::NoColorVideo,
+    NumberCapability::BufferCapacity, NumberCapability::DotVertSpacing,
+    NumberCapability::DotHorzSpacing, NumberCapability::MaxMicroAddress,
+    NumberCapability::MaxMicroJump, NumberCapability::MicroColSize, NumberCapability::MicroLineSize,
+    NumberCapability::NumberOfPins, NumberCapability::OutputResChar,
+    NumberCapability::OutputResLine, NumberCapability::OutputResHorzInch,
+    NumberCapability::OutputResVertInch, NumberCapability::PrintRate,
+    NumberCapability::WideCharSize, NumberCapability::Buttons, NumberCapability::BitImageEntwining,
+    NumberCapability::BitImageType, NumberCapability::MagicCookieGlitchUl,
+    NumberCapability::CarriageReturnDelay, NumberCapability::NewLineDelay,
+    NumberCapability::BackspaceDelay, NumberCapability::HorizontalTabDelay,
+    NumberCapability::NumberOfFunctionKeys,
+];
+
+/// All string capabilities in file order
+pub(crate) const STRING_CAPABILITIES: [StringCapability; 414] = [
+    StringCapability::BackTab, StringCapability::Bell, StringCapability::CarriageReturn,
+    StringCapability::ChangeScrollRegion, StringCapability::ClearAllTabs,
+    StringCapability::ClearScreen, StringCapability::ClearEOL, StringCapability::ClearEOS,
+    StringCapability::ColumnAddress, StringCapability::CommandCharacter,
+    StringCapability::CursorAddress, StringCapability::CursorDown, StringCapability::CursorHome,
+    StringCapability::CursorInvisible, StringCapability::CursorLeft,
+    StringCapability::CursorMemAddress, StringCapability::CursorNormal,
+    StringCapability::CursorRight, StringCapability::CursorToLastLine, StringCapability::CursorUp,
+    StringCapability::CursorVisible, StringCapability::DeleteCharacter,
+    StringCapability::DeleteLine, StringCapability::DisStatusLine, StringCapability::DownHalfLine,
+    StringCapability::EnterAltCharsetMode, StringCapability::EnterBlinkMode,
+    StringCapability::EnterBoldMode, StringCapability::EnterAlternativeMode,
+    StringCapability::EnterDeleteMode, StringCapability::EnterDimMode,
+    StringCapability::EnterInsertMode, StringCapability::EnterSecureMode,
+    StringCapability::EnterProtectedMode, StringCapability::EnterReverseMode,
+    StringCapability::EnterStandoutMode, StringCapability::EnterUnderlineMode,
+    StringCapability::EraseChars, StringCapability::ExitAltCharsetMode,
+    StringCapability::ExitAttributeMode, StringCapability::ExitAlternativeMode,
+    StringCapability::ExitDeleteMode, StringCapability::ExitInsertMode,
+    StringCapability::ExitStandoutMode, StringCapability::ExitUnderlineMode,
+    StringCapability::FlashScreen, StringCapability::FormFeed, StringCapability::FromStatusLine,
+    StringCapability::Init1String, StringCapability::Init2String, StringCapability::Init3String,
+    StringCapability::InitFile, StringCapability::InsertCharacter, StringCapability::InsertLine,
+    StringCapability::InsertPadding, StringCapability::KeyBackspace,
+    StringCapability::KeyClearAllTabs, StringCapability::KeyClear, StringCapability::KeyClearTab,
+    StringCapability::KeyDeleteCharacter, StringCapability::KeyDeleteLine,
+    StringCapability::KeyDown, StringCapability::KeyEic, StringCapability::KeyClearEOL,
+    StringCapability::KeyClearEOS, StringCapability::KeyF0, StringCapability::KeyF1,
+    StringCapability::KeyF10, StringCapability::KeyF2, StringCapability::KeyF3,
+    StringCapability::KeyF4, StringCapability::KeyF5, StringCapability::KeyF6,
+    StringCapability::KeyF7, StringCapability::KeyF8, StringCapability::KeyF9,
+    StringCapability::KeyHome, StringCapability::KeyInsertCharacter,
+    StringCapability::KeyInsertLine, StringCapability::KeyLeft, StringCapability::KeyLastLine,
+    StringCapability::KeyNextPage, StringCapability::KeyPreviousPage, StringCapability::KeyRight,
+    StringCapability::KeyScrollForward, StringCapability::KeyScrollBackward,
+    StringCapability::KeySetTab, StringCapability::KeyUp, StringCapability::KeypadLocal,
+    StringCapability::KeypadXmit, StringCapability::LabF0, StringCapability::LabF1,
+    StringCapability::LabF10, StringCapability::LabF2, StringCapability::LabF3,
+    StringCapability::LabF4, StringCapability::LabF5, StringCapability::LabF6,
+    StringCapability::LabF7, StringCapability::LabF8, StringCapability::LabF9,
+    StringCapability::MetaOff, StringCapability::MetaOn, StringCapability::Newline,
+    StringCapability::PadChar, StringCapability::ParmDeleteCharacters,
+    StringCapability::ParmDeleteLine, StringCapability::ParmDownCursor,
+    StringCapability::ParmInsertCharacters, StringCapability::ParmIndex,
+    StringCapability::ParmInsertLine, StringCapability::ParmLeftCursor,
+    StringCapability::ParmRightCursor, StringCapability::ParmReverseIndex,
+    StringCapability::ParmUpCursor, StringCapability::PKeyKey, StringCapability::PKeyLocal,
+    StringCapability::PKeyXmit, StringCapability::PrintScreen, StringCapability::PrinterOff,
+    StringCapability::PrinterOn, StringCapability::RepeatChar, StringCapability::Reset1String,
+    StringCapability::Reset2String, StringCapability::Reset3String, StringCapability::ResetFile,
+    StringCapability::RestoreCursor, StringCapability::RowAddress, StringCapability::SaveCursor,
+    StringCapability::ScrollForward, StringCapability::ScrollReverse,
+    StringCapability::SetAttributes, StringCapability::SetTab, StringCapability::SetWindow,
+    StringCapability::Tab, StringCapability::ToStatusLine, StringCapability::UnderlineChar,
+    StringCapability::UpHalfLine, StringCapability::InitProg, StringCapability::KeyA1,
+    StringCapability::KeyA3, StringCapability::KeyB2, StringCapability::KeyC1,
+    StringCapability::KeyC3, StringCapability::PrinterOnForNBytes, StringCapability::CharPadding,
+    StringCapability::AcsChars, StringCapability::PlabNorm, StringCapability::KeyBackTab,
+    StringCapability::EnterXonMode, StringCapability::ExitXonMode,
+    StringCapability::EnterAutomaticMarginsMode, StringCapability::ExitAutomaticMarginsMode,
+    StringCapability::XOnCharacter, StringCapability::XOffCharacter,
+    StringCapability::EnableAlternateCharSet, StringCapability::LabelOn, StringCapability::LabelOff,
+    StringCapability::KeyBegin, StringCapability::KeyCancel, StringCapability::KeyClose,
+    StringCapability::KeyCommand, StringCapability::KeyCopy, StringCapability::KeyCreate,
+    StringCapability::KeyEnd, StringCapability::KeyEnter, StringCapability::KeyExit,
+    StringCapability::KeyFind, StringCapability::KeyHelp, StringCapability::KeyMark,
+    StringCapability::KeyMessage, StringCapability::KeyMove, StringCapability::KeyNext,
+    StringCapability::KeyOpen, StringCapability::KeyOptions, StringCapability::KeyPrevious,
+    StringCapability::KeyPrint, StringCapability::KeyRedo, StringCapability::KeyReference,
+    StringCapability::KeyRefresh, StringCapability::KeyReplace, StringCapability::KeyRestart,
+    StringCapability::KeyResume, StringCapability::KeySave, StringCapability::KeySuspend,
+    StringCapability::KeyUndo, StringCapability::KeyShiftBegin, StringCapability::KeyShiftCancel,
+    StringCapability::KeyShiftCommand, StringCapability::KeyShiftCopy,
+    StringCapability::KeyShiftCreate, StringCapability::KeyShiftDeleteChar,
+    StringCapability::KeyShiftDeleteLine, StringCapability::KeySelect,
+    StringCapability::KeyShiftEnd, StringCapability::KeyShiftEOL, StringCapability::KeyShiftExit,
+    StringCapability::KeyShiftFind, StringCapability::KeyShiftHelp, StringCapability::KeyShiftHome,
+    StringCapability::KeyShiftInputKey, StringCapability::KeyShiftLeft,
+    StringCapability::KeyShiftMessage, StringCapability::KeyShiftMove,
+    StringCapability::KeyShiftNext, StringCapability::KeyShiftOptions,
+    StringCapability::KeyShiftPrevious, StringCapability::KeyShiftPrint,
+    StringCapability::KeyShiftRedo, StringCapability::KeyShiftReplace,
+    StringCapability::KeyShiftRight, StringCapability::KeyShiftResume,
+    StringCapability::KeyShiftSave, StringCapability::KeyShiftSuspend,
+    StringCapability::KeyShiftUndo, StringCapability::ReqForInput, StringCapability::KeyF11,
+    StringCapability::KeyF12, StringCapability::KeyF13, StringCapability::KeyF14,
+    StringCapability::KeyF15, StringCapability::KeyF16, StringCapability::KeyF17,
+    StringCapability::KeyF18, StringCapability::KeyF19, StringCapability::KeyF20,
+    StringCapability::KeyF21, StringCapability::KeyF22, StringCapability::KeyF23,
+    StringCapability::KeyF24, StringCapability::KeyF25, StringCapability::KeyF26,
+    StringCapability::KeyF27, StringCapability::KeyF28, StringCapability::KeyF29,
+    StringCapability::KeyF30, StringCapability::KeyF31, StringCapability::KeyF32,
+    StringCapability::KeyF33, StringCapability::KeyF34, StringCapability::KeyF35,
+    StringCapability::KeyF36, StringCapability::KeyF37, StringCapability::KeyF38,
+    StringCapability::KeyF39, StringCapability::KeyF40, StringCapability::KeyF41,
+    StringCapability::KeyF42, StringCapability::KeyF43, StringCapability::KeyF44,
+    StringCapability::KeyF45, StringCapability::KeyF46, StringCapability::KeyF47,
+    StringCapability::KeyF48, StringCapability::KeyF49, StringCapability::KeyF50,
+    StringCapability::KeyF51, StringCapability::KeyF52, StringCapability::KeyF53,
+    StringCapability::KeyF54, StringCapability::KeyF55, StringCapability::KeyF56,
+    StringCapability::KeyF57, StringCapability::KeyF58, StringCapability::KeyF59,
+    StringCapability::KeyF60, StringCapability::KeyF61, StringCapability::KeyF62,
+    StringCapability::KeyF63, StringCapability::ClearBOL, StringCapability::ClearMargins,
+    StringCapability::SetLeftMargin, StringCapability::SetRightMargin,
+    StringCapability::LabelFormat, StringCapability::SetClock, StringCapability::DisplayClock,
+    StringCapability::RemoveClock, StringCapability::CreateWindow, StringCapability::GotoWindow,
+    StringCapability::Hangup, StringCapability::DialPhone, StringCapability::QuickDial,
+    StringCapability::Tone, StringCapability::Pulse, StringCapability::FlashHook,
+    StringCapability::FixedPause, StringCapability::WaitTone, StringCapability::User0,
+    StringCapability::User1, StringCapability::User2, StringCapability::User3,
+    StringCapability::User4, StringCapability::User5, StringCapability::User6,
+    StringCapability::User7, StringCapability::User8, StringCapability::User9,
+    StringCapability::OrigColorPair, StringCapability::OrigColors,
+    StringCapability::InitializeColor, StringCapability::InitializePair,
+    StringCapability::SetColorPair, StringCapability::SetForeground,
+    StringCapability::SetBackground, StringCapability::ChangeCharPitch,
+    StringCapability::ChangeLinePitch, StringCapability::ChangeResHorz,
+    StringCapability::ChangeResVert, StringCapability::DefineChar,
+    StringCapability::EnterDoublewideMode, StringCapability::EnterDraftQuality,
+    StringCapability::EnterItalicsMode, StringCapability::EnterLeftwardMode,
+    StringCapability::EnterMicroMode, StringCapability::EnterNearLetterQuality,
+    StringCapability::EnterNormalQuality, StringCapability::EnterShadowMode,
+    StringCapability::EnterSubscriptMode, StringCapability::EnterSuperscriptMode,
+    StringCapability::EnterUpwardMode, StringCapability::ExitDoublewideMode,
+    StringCapability::ExitItalicsMode, StringCapability::ExitLeftwardMode,
+    StringCapability::ExitMicroMode, StringCapability::ExitShadowMode,
+    StringCapability::ExitSubscriptMode, StringCapability::ExitSuperscriptMode,
+    StringCapability::ExitUpwardMode, StringCapability::MicroColumnAddress,
+    StringCapability::MicroDown, StringCapability::MicroLeft, StringCapability::MicroRight,
+    StringCapability::MicroRowAddress, StringCapability::MicroUp, StringCapability::OrderOfPins,
+    StringCapability::ParmDownMicro, StringCapability::ParmLeftMicro,
+    StringCapability::ParmRightMicro, StringCapability::ParmUpMicro,
+    StringCapability::SelectCharSet, StringCapability::SetBottomMargin,
+    StringCapability::SetBottomMarginParm, StringCapability::SetLeftMarginParm,
+    StringCapability::SetRightMarginParm, StringCapability::SetTopMargin,
+    StringCapability::SetTopMarginParm, StringCapability::StartBitImage,
+    StringCapability::StartCharSetDef, StringCapability::StopBitImage,
+    StringCapability::StopCharSetDef, StringCapability::SubscriptCharacters,
+    StringCapability::SuperscriptCharacters, StringCapability::TheseCauseCr,
+    StringCapability::ZeroMotion, StringCapability::CharSetNames, StringCapability::KeyMouse,
+    StringCapability::MouseInfo, StringCapability::ReqMousePos, StringCapability::GetMouse,
+    StringCapability::SetAnsiForeground, StringCapability::SetAnsiBackground,
+    StringCapability::PKeyPlab, StringCapability::DeviceType, StringCapability::CodeSetInit,
+    StringCapability::Set0DesSeq, StringCapability::Set1DesSeq, StringCapability::Set2DesSeq,
+    StringCapability::Set3DesSeq, StringCapability::SetLrMargin, StringCapability::SetTbMargin,
+    StringCapability::BitImageRepeat, StringCapability::BitImageNewline,
+    StringCapability::BitImageCarriageReturn, StringCapability::ColorNames,
+    StringCapability::DefineBitImageRegion, StringCapability::EndBitImageRegion,
+    StringCapability::SetColorBand, StringCapability::SetPageLength,
+    StringCapability::DisplayPcChar, StringCapability::EnterPcCharsetMode,
+    StringCapability::ExitPcCharsetMode, StringCapability::EnterScancodeMode,
+    StringCapability::ExitScancodeMode, StringCapability::PcTermOptions,
+    StringCapability::ScancodeEscape, StringCapability::AltScancodeEsc,
+    StringCapability::EnterHorizontalHlMode, StringCapability::EnterLeftHlMode,
+    StringCapability::EnterLowHlMode, StringCapability::EnterRightHlMode,
+    StringCapability::EnterTopHlMode, StringCapability::EnterVerticalHlMode,
+    StringCapability::SetAAttributes, StringCapability::SetPageLenInch,
+    StringCapability::TermcapInit2, StringCapability::TermcapReset,
+    StringCapability::LinefeedIfNotLf, StringCapability::BackspaceIfNotBs,
+    StringCapability::OtherNonFunctionKeys, StringCapability::ArrowKeyMap,
+    StringCapability::AcsUlcorner, StringCapability::AcsLlcorner, StringCapability::AcsUrcorner,
+    StringCapability::AcsLrcorner, StringCapability::AcsLtee, StringCapability::AcsRtee,
+    StringCapability::AcsBtee, StringCapability::AcsTtee, StringCapability::AcsHline,
+    StringCapability::AcsVline, StringCapability::AcsPlus, StringCapability::MemoryLock,
+    StringCapability::MemoryUnlock, StringCapability::BoxChars1,
+];
+
+/// Returns the index of name in the short or variable names
+#[cfg(feature = "names")]
+fn find_name(name: &str, names: &[&str], long_names: &[&str]) -> Option<usize> {
+    names.iter()
+         .position(|n| *n == name)
+         .or_else(|| long_names.iter().position(|n| *n == name))
+}
+
+/// Returns the error for an unknown name with up to three short or variable names within edit
+/// distance 2 as suggestions, closest first
+///
+/// Names shorter than three characters only get suggestions with distance 1.
+#[cfg(feature = "names")]
+fn unknown_name(name: &str,
+                names: &[&'static str],
+                long_names: &[&'static str]) -> ParseCapabilityError {
+    let limit = 2.min(name.chars().count().saturating_sub(1));
+    let mut candidates = names.iter()
+                              .chain(long_names)
+                              .map(|candidate| (edit_distance(name, candidate), *candidate))
+                              .filter(|(distance, _)| *distance <= limit)
+                              .collect::<Vec<(usize, &str)>>();
+    candidates.sort_by_key(|(distance, _)| *distance);
+
+    let mut found = Vec::new();
+    for (_, candidate) in candidates {
+        if found.len() < 3 && !found.contains(&candidate) {
+            found.push(candidate);
+        }
+    }
+    ParseCapabilityError(name.to_string(), found)
+}
+
+/// Returns the number of insertions, deletions, substitutions and transpositions of adjacent
+/// characters turning a into b
+#[cfg(feature = "names")]
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.chars().collect::<Vec<char>>(), b.chars().collect::<Vec<char>>());
+    // rows i - 2, i - 1 and i of the distance matrix
+    let mut rows = [Vec::new(), Vec::new(), (0..=b.len()).collect::<Vec<usize>>()];
+    for i in 1..=a.len() {
+        rows.rotate_left(1);
+        rows[2] = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[1][j] + 1).min(rows[2][j - 1] + 1).min(rows[1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[0][j - 2] + 1);
+            }
+            rows[2][j] = distance;
+        }
+    }
+    rows[2][b.len()]
+}
+
+// the variant tables must match the discriminants used as file index and every variant needs
+// a name and termcap code
+const _: () = {
+    #[cfg(feature = "names")]
+    assert!(BOOL_NAMES.len() == BOOL_CAPABILITIES.len()
+            && BOOL_LONG_NAMES.len() == BOOL_CAPABILITIES.len()
+            && BOOL_TERMCAP_CODES.len() == BOOL_CAPABILITIES.len());
+    #[cfg(feature = "names")]
+    assert!(NUMBER_NAMES.len() == NUMBER_CAPABILITIES.len()
+            && NUMBER_LONG_NAMES.len() == NUMBER_CAPABILITIES.len()
+            && NUMBER_TERMCAP_CODES.len() == NUMBER_CAPABILITIES.len());
+    #[cfg(feature = "names")]
+    assert!(STRING_NAMES.len() == STRING_CAPABILITIES.len()
+            && STRING_LONG_NAMES.len() == STRING_CAPABILITIES.len()
+            && STRING_TERMCAP_CODES.len() == STRING_CAPABILITIES.len());
+    assert!(BOOL_CATEGORIES.len() == BOOL_CAPABILITIES.len()
+            && NUMBER_CATEGORIES.len() == NUMBER_CAPABILITIES.len()
+            && STRING_CATEGORIES.len() == STRING_CAPABILITIES.len());
+    #[cfg(feature = "descriptions")]
+    assert!(BOOL_DESCRIPTIONS.len() == BOOL_CAPABILITIES.len()
+            && NUMBER_DESCRIPTIONS.len() == NUMBER_CAPABILITIES.len()
+            && STRING_DESCRIPTIONS.len() == STRING_CAPABILITIES.len());
+
+    let mut idx = 0;
+    while idx < BOOL_CAPABILITIES.len() {
+        assert!(BOOL_CAPABILITIES[idx] as usize == idx);
+        idx += 1;
+    }
+    let mut idx = 0;
+    while idx < NUMBER_CAPABILITIES.len() {
+        assert!(NUMBER_CAPABILITIES[idx] as usize == idx);
+        idx += 1;
+    }
+    let mut idx = 0;
+    while idx < STRING_CAPABILITIES.len() {
+        assert!(STRING_CAPABILITIES[idx] as usize == idx);
+        idx += 1;
+    }
+};
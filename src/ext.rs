@@ -0,0 +1,1160 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Names of well-known extended capabilities and typed accessors on `TermInfo`
+//!
+//! Extended capabilities aren't part of terminfo(5), the names follow ncurses
+//! (`user_caps(5)`), tmux, vim and kitty.
+
+use crate::capabilities::StringCapability;
+#[cfg(feature = "parser")]
+use crate::capabilities::NumberCapability;
+#[cfg(feature = "parser")]
+use crate::param_string::{evaluate, Param};
+#[cfg(feature = "parser")]
+use crate::terminfo::policy::{ColorPolicy, EnvProvider};
+#[cfg(feature = "parser")]
+use crate::terminfo::{FormatError, TermInfo};
+
+/// Bool: terminal supports 24-bit colors (tmux)
+pub const TRUECOLOR: &str = "Tc";
+/// Bool: `setaf`/`setab` take direct colors `0xRRGGBB` (ncurses `*-direct` entries)
+pub const RGB: &str = "RGB";
+/// Bool: SGR 39 and 49 set the default foreground and background color (ncurses)
+pub const DEFAULT_COLORS: &str = "AX";
+/// String: set foreground to r, g, b (user entries for kitty, tmux overrides)
+pub const SET_RGB_FOREGROUND: &str = "setrgbf";
+/// String: set background to r, g, b (user entries for kitty, tmux overrides)
+pub const SET_RGB_BACKGROUND: &str = "setrgbb";
+/// String: underline style #1, 0 none, 1 single, 2 double, 3 curly, 4 dotted, 5 dashed
+/// (tmux, kitty, vte)
+pub const STYLED_UNDERLINE: &str = "Smulx";
+/// String: underline color `0xRRGGBB` (kitty, tmux overrides)
+pub const SET_UNDERLINE_COLOR: &str = "Setulc";
+/// String: enter overline mode, SGR 53 (vte, mintty)
+pub const OVERLINE_ON: &str = "Smol";
+/// String: exit overline mode, SGR 55 (vte, mintty)
+pub const OVERLINE_OFF: &str = "Rmol";
+/// String: enter strikethrough mode, SGR 9 (ncurses `ecma+strikeout`)
+pub const STRIKETHROUGH_ON: &str = "smxx";
+/// String: exit strikethrough mode, SGR 29 (ncurses `ecma+strikeout`)
+pub const STRIKETHROUGH_OFF: &str = "rmxx";
+/// String: `smxx` as named by older entries
+pub const STRIKETHROUGH_ON_ALT: &str = "Smxx";
+/// String: `rmxx` as named by older entries
+pub const STRIKETHROUGH_OFF_ALT: &str = "Rmxx";
+/// String: set selection #1 to base64 data #2, OSC 52 (xterm, tmux)
+pub const SET_SELECTION: &str = "Ms";
+/// String: set cursor color to #1 (xterm, tmux)
+pub const SET_CURSOR_COLOR: &str = "Cs";
+/// String: reset cursor color (xterm, tmux)
+pub const RESET_CURSOR_COLOR: &str = "Cr";
+/// String: set cursor style #1, DECSCUSR (xterm, tmux)
+pub const SET_CURSOR_STYLE: &str = "Ss";
+/// String: reset cursor style (xterm, tmux)
+pub const RESET_CURSOR_STYLE: &str = "Se";
+/// String: begin (#1 = 1) or end (#1 = 2) synchronized output (tmux, kitty)
+pub const SYNC: &str = "Sync";
+/// String: enable bracketed paste (xterm, tmux)
+pub const BRACKETED_PASTE_ENABLE: &str = "BE";
+/// String: disable bracketed paste (xterm, tmux)
+pub const BRACKETED_PASTE_DISABLE: &str = "BD";
+/// String: sent by the terminal before pasted text (xterm, tmux)
+pub const PASTE_START: &str = "PS";
+/// String: sent by the terminal after pasted text (xterm, tmux)
+pub const PASTE_END: &str = "PE";
+/// String: shift + up arrow key (xterm, tmux)
+pub const KEY_SHIFT_UP: &str = "kUP";
+/// String: shift + down arrow key (xterm, tmux)
+pub const KEY_SHIFT_DOWN: &str = "kDN";
+/// String: shift + left arrow key (xterm, tmux)
+pub const KEY_SHIFT_LEFT: &str = "kLFT";
+/// String: shift + right arrow key (xterm, tmux)
+pub const KEY_SHIFT_RIGHT: &str = "kRIT";
+/// String: shift + home key (xterm, tmux)
+pub const KEY_SHIFT_HOME: &str = "kHOM";
+/// String: shift + end key (xterm, tmux)
+pub const KEY_SHIFT_END: &str = "kEND";
+/// String: shift + page up key (xterm, tmux)
+pub const KEY_SHIFT_PAGE_UP: &str = "kPRV";
+/// String: shift + page down key (xterm, tmux)
+pub const KEY_SHIFT_PAGE_DOWN: &str = "kNXT";
+/// String: shift + insert key (xterm, tmux)
+pub const KEY_SHIFT_INSERT: &str = "kIC";
+/// String: shift + delete key (xterm, tmux)
+pub const KEY_SHIFT_DELETE: &str = "kDC";
+/// String: enable (#1 = 1) or disable mouse reporting (xterm)
+pub const MOUSE_MODE: &str = "XM";
+/// String: format of mouse reports (xterm)
+pub const MOUSE_REPORT: &str = "xm";
+
+/// All names above, for completion of capability names
+#[cfg(feature = "names")]
+pub(crate) const KNOWN_NAMES: [&str; 35] = [
+    TRUECOLOR, RGB, DEFAULT_COLORS, SET_RGB_FOREGROUND, SET_RGB_BACKGROUND, STYLED_UNDERLINE,
+    SET_UNDERLINE_COLOR, OVERLINE_ON, OVERLINE_OFF, STRIKETHROUGH_ON, STRIKETHROUGH_OFF,
+    STRIKETHROUGH_ON_ALT, STRIKETHROUGH_OFF_ALT, SET_SELECTION, SET_CURSOR_COLOR,
+    RESET_CURSOR_COLOR, SET_CURSOR_STYLE, RESET_CURSOR_STYLE, SYNC, BRACKETED_PASTE_ENABLE,
+    BRACKETED_PASTE_DISABLE, PASTE_START, PASTE_END, KEY_SHIFT_UP, KEY_SHIFT_DOWN, KEY_SHIFT_LEFT,
+    KEY_SHIFT_RIGHT, KEY_SHIFT_HOME, KEY_SHIFT_END, KEY_SHIFT_PAGE_UP, KEY_SHIFT_PAGE_DOWN,
+    KEY_SHIFT_INSERT, KEY_SHIFT_DELETE, MOUSE_MODE, MOUSE_REPORT,
+];
+
+/// Cursor and editing keys with modified variants, see `TermInfo::modified_key`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrowOrEditKey {
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Insert,
+    Delete,
+}
+
+impl ArrowOrEditKey {
+    /// Returns the standard capability of the key without modifiers
+    pub fn capability(&self) -> StringCapability {
+        match self {
+            ArrowOrEditKey::Up => StringCapability::KeyUp,
+            ArrowOrEditKey::Down => StringCapability::KeyDown,
+            ArrowOrEditKey::Left => StringCapability::KeyLeft,
+            ArrowOrEditKey::Right => StringCapability::KeyRight,
+            ArrowOrEditKey::Home => StringCapability::KeyHome,
+            ArrowOrEditKey::End => StringCapability::KeyEnd,
+            ArrowOrEditKey::PageUp => StringCapability::KeyPreviousPage,
+            ArrowOrEditKey::PageDown => StringCapability::KeyNextPage,
+            ArrowOrEditKey::Insert => StringCapability::KeyInsertCharacter,
+            ArrowOrEditKey::Delete => StringCapability::KeyDeleteCharacter,
+        }
+    }
+
+    /// Returns the name of the extended capability with shift, e.g. `kUP`
+    pub fn ext_name(&self) -> &'static str {
+        match self {
+            ArrowOrEditKey::Up => KEY_SHIFT_UP,
+            ArrowOrEditKey::Down => KEY_SHIFT_DOWN,
+            ArrowOrEditKey::Left => KEY_SHIFT_LEFT,
+            ArrowOrEditKey::Right => KEY_SHIFT_RIGHT,
+            ArrowOrEditKey::Home => KEY_SHIFT_HOME,
+            ArrowOrEditKey::End => KEY_SHIFT_END,
+            ArrowOrEditKey::PageUp => KEY_SHIFT_PAGE_UP,
+            ArrowOrEditKey::PageDown => KEY_SHIFT_PAGE_DOWN,
+            ArrowOrEditKey::Insert => KEY_SHIFT_INSERT,
+            ArrowOrEditKey::Delete => KEY_SHIFT_DELETE,
+        }
+    }
+}
+
+/// Modifier keys held down with a key
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub alt: bool,
+    pub ctrl: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    /// No modifier
+    pub const NONE: Modifiers = Modifiers { shift: false, alt: false, ctrl: false, meta: false };
+    /// Shift only
+    pub const SHIFT: Modifiers = Modifiers { shift: true, ..Modifiers::NONE };
+    /// Alt only
+    pub const ALT: Modifiers = Modifiers { alt: true, ..Modifiers::NONE };
+    /// Ctrl only
+    pub const CTRL: Modifiers = Modifiers { ctrl: true, ..Modifiers::NONE };
+
+    /// Returns the xterm modifier code, 1 plus shift (1), alt (2), ctrl (4) and meta (8)
+    ///
+    /// The code is the suffix of extended key names, `kUP5` is ctrl + up. Shift alone (2) has
+    /// no suffix.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::ext::Modifiers;
+    ///
+    /// assert_eq!(Modifiers::NONE.code(), 1);
+    /// assert_eq!(Modifiers::SHIFT.code(), 2);
+    /// assert_eq!(Modifiers { shift: true, ctrl: true, ..Modifiers::NONE }.code(), 6);
+    /// ```
+    pub fn code(&self) -> u8 {
+        1 + self.shift as u8
+          + ((self.alt as u8) << 1)
+          + ((self.ctrl as u8) << 2)
+          + ((self.meta as u8) << 3)
+    }
+
+    /// Returns the modifiers of an xterm modifier code (2 to 16) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::ext::Modifiers;
+    ///
+    /// assert_eq!(Modifiers::from_code(5), Some(Modifiers::CTRL));
+    /// assert_eq!(Modifiers::from_code(17), None);
+    /// ```
+    pub fn from_code(code: u8) -> Option<Modifiers> {
+        let bits = code.checked_sub(1).filter(|bits| *bits < 16)?;
+        Some(Modifiers {
+            shift: bits & 1 != 0,
+            alt: bits & 2 != 0,
+            ctrl: bits & 4 != 0,
+            meta: bits & 8 != 0,
+        })
+    }
+}
+
+/// Color support of a terminal, see `TermInfo::color_support`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSupport {
+    /// No colors
+    Mono,
+    /// Number of indexed colors (`colors`)
+    Colors(u32),
+    /// 24-bit colors
+    Direct { via: DirectMethod },
+}
+
+/// How a terminal sets 24-bit colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirectMethod {
+    /// `setrgbf`/`setrgbb` with r, g, b
+    SetRgb,
+    /// `setaf`/`setab` with `0xRRGGBB` (`RGB` or `colors#0x1000000`, ncurses `*-direct` entries)
+    Setaf,
+    /// SGR 38;2 and 48;2, the entry has `Tc`
+    Truecolor,
+    /// SGR 38;2 and 48;2, `COLORTERM` is `truecolor` or `24bit`
+    ColorTerm,
+}
+
+/// Capability the environment reveals beyond the terminfo entry, see
+/// `TermInfo::probe_env_upgrades`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnvUpgrade {
+    /// 24-bit colors, `COLORTERM` is `truecolor` or `24bit`. Applied as `Tc`.
+    Truecolor,
+    /// Italics, the terminal named by `TERM_PROGRAM` supports them but the entry has no
+    /// `sitm`. Only a hint, standard capabilities can't be added to an entry.
+    Italics,
+    /// Curly underlines, the terminal named by `TERM_PROGRAM` supports them. Applied as
+    /// `Smulx`.
+    Undercurl,
+}
+
+/// Underline style, the parameter of `Smulx`, see `TermInfo::set_underline`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnderlineStyle {
+    /// Not underlined
+    None = 0,
+    /// Single line
+    Single = 1,
+    /// Double line
+    Double = 2,
+    /// Curly line (undercurl)
+    Curly = 3,
+    /// Dotted line
+    Dotted = 4,
+    /// Dashed line
+    Dashed = 5,
+}
+
+/// `TERM_PROGRAM` of terminals with italics and of terminals with curly underlines
+#[cfg(feature = "parser")]
+const ITALICS_PROGRAMS: [&str; 5] = ["iTerm.app", "WezTerm", "ghostty", "vscode", "Apple_Terminal"];
+#[cfg(feature = "parser")]
+const UNDERCURL_PROGRAMS: [&str; 4] = ["iTerm.app", "WezTerm", "ghostty", "vscode"];
+
+/// `TERM_PROGRAM` and the terminfo entry of the terminal, see `TermInfo::from_env_with_hints`
+#[cfg(feature = "parser")]
+pub(crate) const PROGRAM_ENTRIES: [(&str, &str); 4] = [
+    ("iTerm.app", "iterm2"),
+    ("WezTerm", "wezterm"),
+    ("vscode", "vscode"),
+    ("Apple_Terminal", "nsterm-256color"),
+];
+
+/// `Smulx` of the terminals in `UNDERCURL_PROGRAMS`
+#[cfg(feature = "parser")]
+const STYLED_UNDERLINE_SGR: &str = "\x1B[4:%p1%dm";
+
+/// Default colors 0 to 15 of xterm, other terminals use their own palette
+const XTERM_16: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00), (0xCD, 0x00, 0x00), (0x00, 0xCD, 0x00), (0xCD, 0xCD, 0x00),
+    (0x00, 0x00, 0xEE), (0xCD, 0x00, 0xCD), (0x00, 0xCD, 0xCD), (0xE5, 0xE5, 0xE5),
+    (0x7F, 0x7F, 0x7F), (0xFF, 0x00, 0x00), (0x00, 0xFF, 0x00), (0xFF, 0xFF, 0x00),
+    (0x5C, 0x5C, 0xFF), (0xFF, 0x00, 0xFF), (0x00, 0xFF, 0xFF), (0xFF, 0xFF, 0xFF),
+];
+
+/// Returns the RGB value of a color of the xterm 256-color palette
+///
+/// * `0..=15` - system colors, terminal-specific, the xterm defaults are returned
+/// * `16..=231` - 6x6x6 color cube, levels 0, 95, 135, 175, 215 and 255
+/// * `232..=255` - grayscale ramp from 8 to 238 in steps of 10
+///
+/// # Arguments
+/// * `index` - color number as used with `setaf` and `setab`
+///
+/// # Example
+/// ```
+/// use cxterminfo::ext::xterm_256_to_rgb;
+///
+/// assert_eq!(xterm_256_to_rgb(1), (205, 0, 0));
+/// assert_eq!(xterm_256_to_rgb(16), (0, 0, 0));
+/// assert_eq!(xterm_256_to_rgb(196), (255, 0, 0));
+/// assert_eq!(xterm_256_to_rgb(110), (135, 175, 215));
+/// assert_eq!(xterm_256_to_rgb(231), (255, 255, 255));
+/// assert_eq!(xterm_256_to_rgb(232), (8, 8, 8));
+/// assert_eq!(xterm_256_to_rgb(255), (238, 238, 238));
+/// ```
+pub fn xterm_256_to_rgb(index: u8) -> (u8, u8, u8) {
+    let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+    match index {
+        0..=15 => XTERM_16[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Returns the closest color of the xterm 256-color palette for an RGB value
+///
+/// Only the color cube and the grayscale ramp (16 to 255) are considered, colors 0 to 15 differ
+/// between terminals. The distance is the "redmean" weighted Euclidean distance, which is
+/// closer to perception than the plain distance in RGB space.
+///
+/// # Arguments
+/// * `r` - red
+/// * `g` - green
+/// * `b` - blue
+///
+/// # Example
+/// ```
+/// use cxterminfo::ext::{rgb_to_xterm_256, xterm_256_to_rgb};
+///
+/// assert_eq!(rgb_to_xterm_256(255, 0, 0), 196);
+/// assert_eq!(rgb_to_xterm_256(250, 10, 5), 196);
+/// assert_eq!(rgb_to_xterm_256(0x80, 0x80, 0x80), 244);
+/// assert_eq!(rgb_to_xterm_256(0, 0, 0), 16);
+///
+/// for index in 16..=255 {
+///     let (r, g, b) = xterm_256_to_rgb(index);
+///     assert_eq!(rgb_to_xterm_256(r, g, b), index);
+/// }
+/// ```
+pub fn rgb_to_xterm_256(r: u8, g: u8, b: u8) -> u8 {
+    (16..=255).min_by_key(|index| distance((r, g, b), xterm_256_to_rgb(*index))).unwrap_or(16)
+}
+
+/// Returns the RGB value of a color of the xterm 88-color palette, a 4x4x4 cube with levels 0,
+/// 139, 205 and 255 and 8 grays
+#[cfg(feature = "parser")]
+fn xterm_88_to_rgb(index: u8) -> (u8, u8, u8) {
+    const LEVELS: [u8; 4] = [0x00, 0x8B, 0xCD, 0xFF];
+    const GRAYS: [u8; 8] = [0x2E, 0x5C, 0x73, 0x8B, 0xA2, 0xB9, 0xD0, 0xE7];
+    match index {
+        0..=15 => XTERM_16[index as usize],
+        16..=79 => {
+            let cube = (index - 16) as usize;
+            (LEVELS[cube / 16], LEVELS[cube / 4 % 4], LEVELS[cube % 4])
+        }
+        _ => {
+            let gray = GRAYS[(index - 80) as usize % 8];
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// "Redmean" weighted Euclidean distance, squared
+fn distance((r, g, b): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> i32 {
+    let mean = (r as i32 + r2 as i32) / 2;
+    let (dr, dg, db) = (r as i32 - r2 as i32, g as i32 - g2 as i32, b as i32 - b2 as i32);
+    (((512 + mean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - mean) * db * db) >> 8)
+}
+
+#[cfg(feature = "parser")]
+impl TermInfo {
+    /// Returns the sequence sent by a cursor or editing key with modifiers or Option::None
+    ///
+    /// Modified keys are extended capabilities named like xterm: the shift name (`kUP`, `kDN`,
+    /// `kLFT`, `kRIT`, `kHOM`, `kEND`, `kPRV`, `kNXT`, `kIC`, `kDC`) followed by the modifier
+    /// code (`kUP3` alt, `kUP5` ctrl, ...), see `Modifiers::code`. Without modifiers the standard
+    /// capability (`kcuu1`, ...) is returned.
+    ///
+    /// # Arguments
+    /// * `base`      - key
+    /// * `modifiers` - modifiers held down
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::{ArrowOrEditKey, Modifiers};
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.modified_key(ArrowOrEditKey::Up, Modifiers::SHIFT), Some("\x1B[1;2A".to_string()));
+    /// assert_eq!(info.modified_key(ArrowOrEditKey::Right, Modifiers::CTRL), Some("\x1B[1;5C".to_string()));
+    /// assert_eq!(info.modified_key(ArrowOrEditKey::Delete, Modifiers::ALT), Some("\x1B[3;3~".to_string()));
+    /// assert_eq!(info.modified_key(ArrowOrEditKey::Up, Modifiers::NONE), Some("\x1BOA".to_string()));
+    ///
+    /// let meta = Modifiers { meta: true, ..Modifiers::NONE };
+    /// assert_eq!(info.modified_key(ArrowOrEditKey::Up, meta), None);
+    /// ```
+    pub fn modified_key(&self, base: ArrowOrEditKey, modifiers: Modifiers) -> Option<String> {
+        match modifiers.code() {
+            1 => self.get_string(base.capability()),
+            2 => self.get_ext_string(base.ext_name()).cloned(),
+            code => self.get_ext_string(&format!("{}{}", base.ext_name(), code)).cloned(),
+        }
+    }
+
+    /// Returns true if the terminal supports 24-bit colors (`Tc` or `RGB`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/xterm-direct").unwrap().truecolor_bool());
+    /// assert!(TermInfo::from_file("tests/data/cx-ext").unwrap().truecolor_bool());
+    /// assert!(!TermInfo::from_file("tests/data/tmux-256color").unwrap().truecolor_bool());
+    /// ```
+    pub fn truecolor_bool(&self) -> bool {
+        self.get_ext_bool(TRUECOLOR) == Some(&true) || self.get_ext_bool(RGB) == Some(&true)
+    }
+
+    /// Returns true if SGR 39 and 49 set the default foreground and background color (`AX`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/xterm-256color").unwrap().supports_default_colors());
+    /// assert!(!TermInfo::from_file("tests/data/cx-ext").unwrap().supports_default_colors());
+    /// ```
+    pub fn supports_default_colors(&self) -> bool {
+        self.get_ext_bool(DEFAULT_COLORS) == Some(&true)
+    }
+
+    /// Returns how many colors the terminal supports and how it sets 24-bit colors
+    ///
+    /// Direct colors are detected in this order: `setrgbf`, `RGB` or `colors#0x1000000` (`setaf`
+    /// takes `0xRRGGBB`), `Tc` and last `COLORTERM=truecolor` (or `24bit`) for entries with at
+    /// least 8 colors. `COLORTERM` is read from the environment of the process unless set with
+    /// `TermInfo::set_colorterm_from_provider`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::{ColorSupport, DirectMethod};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.color_support(), ColorSupport::Direct { via: DirectMethod::Setaf });
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-tc").unwrap();
+    /// assert_eq!(info.color_support(), ColorSupport::Direct { via: DirectMethod::Truecolor });
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.color_support(), ColorSupport::Colors(256));
+    /// ```
+    pub fn color_support(&self) -> ColorSupport {
+        self.color_support_for(self.colorterm_truecolor())
+    }
+
+    /// Returns how many colors the terminal supports and how it sets 24-bit colors, like
+    /// `color_support` with `COLORTERM` read from env
+    ///
+    /// # Arguments
+    /// * `env` - environment variables, e.g. `ProcessEnv`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::{ColorSupport, DirectMethod};
+    ///
+    /// let mut env = HashMap::new();
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.color_support_from_provider(&env), ColorSupport::Colors(256));
+    ///
+    /// env.insert("COLORTERM".to_string(), "truecolor".to_string());
+    /// assert_eq!(info.color_support_from_provider(&env),
+    ///            ColorSupport::Direct { via: DirectMethod::ColorTerm });
+    /// ```
+    pub fn color_support_from_provider<E: EnvProvider>(&self, env: &E) -> ColorSupport {
+        self.color_support_for(announces_truecolor(env))
+    }
+
+    /// `color_support` with the result of the `COLORTERM` check
+    fn color_support_for(&self, colorterm_truecolor: bool) -> ColorSupport {
+        let colors = self.get_number(NumberCapability::MaxColors).unwrap_or(0);
+
+        let via = if self.get_ext_string(SET_RGB_FOREGROUND).is_some() {
+            DirectMethod::SetRgb
+        } else if self.get_ext_bool(RGB) == Some(&true) || colors >= 0x1000000 {
+            DirectMethod::Setaf
+        } else if self.get_ext_bool(TRUECOLOR) == Some(&true) {
+            DirectMethod::Truecolor
+        } else if colors >= 8 && colorterm_truecolor {
+            DirectMethod::ColorTerm
+        } else if colors > 1 {
+            return ColorSupport::Colors(colors as u32);
+        } else {
+            return ColorSupport::Mono;
+        };
+
+        ColorSupport::Direct { via }
+    }
+
+    /// Returns the color support of the terminal with a color policy applied
+    ///
+    /// `ColorPolicy::Never` is `Mono`, `ColorPolicy::Always` is at least the 8 ANSI colors,
+    /// `ColorPolicy::Auto` is `color_support`.
+    ///
+    /// # Arguments
+    /// * `policy` - color policy, e.g. `ColorPolicy::from_env()`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::terminfo::policy::ColorPolicy;
+    /// use cxterminfo::ext::ColorSupport;
+    ///
+    /// let info = TermInfo::from_file("tests/data/vt100").unwrap();
+    /// assert_eq!(info.color_support_with_policy(&ColorPolicy::Auto), ColorSupport::Mono);
+    /// assert_eq!(info.color_support_with_policy(&ColorPolicy::Always), ColorSupport::Colors(8));
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.color_support_with_policy(&ColorPolicy::Never), ColorSupport::Mono);
+    /// ```
+    pub fn color_support_with_policy(&self, policy: &ColorPolicy) -> ColorSupport {
+        match (policy, self.color_support()) {
+            (ColorPolicy::Never, _) => ColorSupport::Mono,
+            (ColorPolicy::Always, ColorSupport::Direct { via }) => ColorSupport::Direct { via },
+            (ColorPolicy::Always, _) if !self.has_colors() => ColorSupport::Colors(8),
+            (_, support) => support,
+        }
+    }
+
+    /// Returns the capabilities the environment reveals that the entry lacks
+    ///
+    /// Terminals often keep `TERM` at a common entry like `xterm-256color` and announce more
+    /// with `COLORTERM` (`truecolor` or `24bit`) and `TERM_PROGRAM` (iTerm.app, WezTerm,
+    /// ghostty, vscode, Apple_Terminal). Only capabilities the entry doesn't have are returned,
+    /// truecolor only for entries with at least 8 colors. Nothing is changed, see
+    /// `apply_env_upgrades`.
+    ///
+    /// # Arguments
+    /// * `env` - environment variables, e.g. `ProcessEnv`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::EnvUpgrade;
+    ///
+    /// let mut env = HashMap::new();
+    /// env.insert("COLORTERM".to_string(), "truecolor".to_string());
+    /// env.insert("TERM_PROGRAM".to_string(), "WezTerm".to_string());
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let upgrades = info.probe_env_upgrades(&env);
+    /// assert_eq!(upgrades, vec![EnvUpgrade::Truecolor, EnvUpgrade::Undercurl]);
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.probe_env_upgrades(&env), vec![EnvUpgrade::Undercurl]);
+    /// ```
+    pub fn probe_env_upgrades<E: EnvProvider>(&self, env: &E) -> Vec<EnvUpgrade> {
+        let program = env.var("TERM_PROGRAM").unwrap_or_default();
+        let colors = self.colors();
+
+        let truecolor = announces_truecolor(env)
+            && (8..0x1000000).contains(&colors)
+            && !self.truecolor_bool()
+            && self.get_ext_string(SET_RGB_FOREGROUND).is_none();
+        let italics = ITALICS_PROGRAMS.contains(&program.as_str())
+            && self.get_string(StringCapability::EnterItalicsMode).is_none();
+        let undercurl = UNDERCURL_PROGRAMS.contains(&program.as_str())
+            && self.get_ext_string(STYLED_UNDERLINE).is_none();
+
+        [(truecolor, EnvUpgrade::Truecolor),
+         (italics, EnvUpgrade::Italics),
+         (undercurl, EnvUpgrade::Undercurl)].iter()
+                                             .filter(|(found, _)| *found)
+                                             .map(|(_, upgrade)| *upgrade)
+                                             .collect()
+    }
+
+    /// Adds the capabilities of `probe_env_upgrades` to the entry and returns the ones added
+    ///
+    /// Truecolor sets `Tc`, undercurl sets `Smulx` to SGR 4 with a sub-parameter. Italics can't
+    /// be added and is never returned. Present capabilities are never changed.
+    ///
+    /// # Arguments
+    /// * `env` - environment variables, e.g. `ProcessEnv`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::EnvUpgrade;
+    ///
+    /// let mut env = HashMap::new();
+    /// env.insert("TERM_PROGRAM".to_string(), "vscode".to_string());
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.undercurl(), None);
+    /// assert_eq!(info.apply_env_upgrades(&env), vec![EnvUpgrade::Undercurl]);
+    /// assert_eq!(info.undercurl(), Some("\x1B[4:3m".to_string()));
+    /// assert!(info.apply_env_upgrades(&env).is_empty());
+    /// ```
+    pub fn apply_env_upgrades<E: EnvProvider>(&mut self, env: &E) -> Vec<EnvUpgrade> {
+        let mut applied = self.probe_env_upgrades(env);
+        applied.retain(|upgrade| *upgrade != EnvUpgrade::Italics);
+        for upgrade in &applied {
+            match upgrade {
+                EnvUpgrade::Truecolor => self.set_ext_bool(TRUECOLOR, true),
+                EnvUpgrade::Undercurl => {
+                    self.set_ext_string(STYLED_UNDERLINE, STYLED_UNDERLINE_SGR)
+                }
+                EnvUpgrade::Italics => {}
+            }
+        }
+        applied
+    }
+
+    /// Returns the sequence to set a 24-bit foreground color or Option::None
+    ///
+    /// Uses the method of `color_support`: `setrgbf`, `setaf` with a direct color or SGR 38;2.
+    /// Direct color entries treat values below 8 as ANSI colors, these colors are written as
+    /// SGR 38:2 instead of using `setaf`. The sequence is empty if colors are turned off by the
+    /// color policy (`TermInfo::set_color_policy`).
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.set_rgb_foreground(0x10, 0x20, 0x30), Some("\x1B[38:2::16:32:48m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.set_rgb_foreground(0x10, 0x20, 0x30), Some("\x1B[38:2:16:32:48m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-tc").unwrap();
+    /// assert_eq!(info.set_rgb_foreground(0x10, 0x20, 0x30), Some("\x1B[38;2;16;32;48m".to_string()));
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.set_rgb_foreground(0x10, 0x20, 0x30), None);
+    /// ```
+    pub fn set_rgb_foreground(&self, r: u8, g: u8, b: u8) -> Option<String> {
+        self.rgb_sequence(SET_RGB_FOREGROUND, StringCapability::SetAnsiForeground, 38, r, g, b)
+    }
+
+    /// Returns the sequence to set a 24-bit background color or Option::None, see
+    /// `set_rgb_foreground`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.set_rgb_background(255, 0, 0), Some("\x1B[48:2::255:0:0m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.set_rgb_background(255, 0, 0), Some("\x1B[48:2:255:0:0m".to_string()));
+    /// ```
+    pub fn set_rgb_background(&self, r: u8, g: u8, b: u8) -> Option<String> {
+        self.rgb_sequence(SET_RGB_BACKGROUND, StringCapability::SetAnsiBackground, 48, r, g, b)
+    }
+
+    /// Returns the sequence for a curly underline (`Smulx` style 3) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.undercurl(), Some("\x1B[4:3m".to_string()));
+    /// assert_eq!(TermInfo::from_file("tests/data/xterm-direct").unwrap().undercurl(), None);
+    /// ```
+    pub fn undercurl(&self) -> Option<String> {
+        self.ext_sequence(STYLED_UNDERLINE, &[Param::Number(3)])
+    }
+
+    /// Returns the sequence to set the underline color (`Setulc`) or Option::None
+    ///
+    /// The sequence is empty if `Setulc` is defined and colors are turned off by the color
+    /// policy (`TermInfo::set_color_policy`).
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.set_underline_color(255, 128, 0), Some("\x1B[58:2::255:128:0m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.set_underline_color(255, 0, 0), None);
+    /// ```
+    pub fn set_underline_color(&self, r: u8, g: u8, b: u8) -> Option<String> {
+        let term = self.get_ext_string(SET_UNDERLINE_COLOR)?;
+        if self.colors_suppressed() {
+            return Some(String::new());
+        }
+
+        evaluate(term, &[Param::Number(direct_color(r, g, b))]).ok()
+    }
+
+    /// Returns true if the terminal has underline styles, curly underlines among them (`Smulx`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/tmux-256color").unwrap().supports_undercurl());
+    /// assert!(!TermInfo::from_file("tests/data/xterm-256color").unwrap().supports_undercurl());
+    /// ```
+    pub fn supports_undercurl(&self) -> bool {
+        self.get_ext_string(STYLED_UNDERLINE).is_some()
+    }
+
+    /// Returns true if the terminal can color underlines (`Setulc`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert!(info.supports_underline_color());
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert!(!info.supports_underline_color());
+    /// ```
+    pub fn supports_underline_color(&self) -> bool {
+        self.get_ext_string(SET_UNDERLINE_COLOR).is_some()
+    }
+
+    /// Returns the sequence to switch to an underline style or Option::None
+    ///
+    /// Uses `Smulx` if the terminal has it, otherwise every style but `UnderlineStyle::None`
+    /// falls back to a single underline (`smul`) and `UnderlineStyle::None` ends underlining
+    /// (`rmul`).
+    ///
+    /// # Arguments
+    /// * `style` - underline style
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::UnderlineStyle;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.set_underline(UnderlineStyle::Dotted), Some("\x1B[4:4m".to_string()));
+    /// assert_eq!(info.set_underline(UnderlineStyle::None), Some("\x1B[4:0m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.set_underline(UnderlineStyle::Curly), Some("\x1B[4m".to_string()));
+    /// assert_eq!(info.set_underline(UnderlineStyle::None), Some("\x1B[24m".to_string()));
+    /// ```
+    pub fn set_underline(&self, style: UnderlineStyle) -> Option<String> {
+        if self.supports_undercurl() {
+            return self.ext_sequence(STYLED_UNDERLINE, &[Param::Number(style as i32)]);
+        }
+
+        match style {
+            UnderlineStyle::None => self.get_string(StringCapability::ExitUnderlineMode),
+            _ => self.get_string(StringCapability::EnterUnderlineMode),
+        }
+    }
+
+    /// Returns the sequence to restore the default underline color, SGR 59, or Option::None if
+    /// the terminal has no `Setulc`
+    ///
+    /// terminfo has no capability for it, terminals with `Setulc` (kitty, vte, tmux) know
+    /// SGR 59. The sequence is empty if colors are turned off by the color policy
+    /// (`TermInfo::set_color_policy`).
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.reset_underline_color(), Some("\x1B[59m".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.reset_underline_color(), None);
+    /// ```
+    pub fn reset_underline_color(&self) -> Option<String> {
+        if !self.supports_underline_color() {
+            return None;
+        }
+
+        match self.colors_suppressed() {
+            true => Some(String::new()),
+            false => Some("\x1B[59m".to_string()),
+        }
+    }
+
+    /// Returns true if the terminal can overline text (`Smol` and `Rmol`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/cx-ext").unwrap().supports_overline());
+    /// assert!(!TermInfo::from_file("tests/data/xterm-256color").unwrap().supports_overline());
+    /// ```
+    pub fn supports_overline(&self) -> bool {
+        self.get_ext_string(OVERLINE_ON).is_some() && self.get_ext_string(OVERLINE_OFF).is_some()
+    }
+
+    /// Returns the sequence to start overlined text (`Smol`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.overline_on(), Some("\x1B[53m".to_string()));
+    /// assert_eq!(TermInfo::from_file("tests/data/tmux-256color").unwrap().overline_on(), None);
+    /// ```
+    pub fn overline_on(&self) -> Option<String> {
+        self.ext_sequence(OVERLINE_ON, &[])
+    }
+
+    /// Returns the sequence to end overlined text (`Rmol`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.overline_off(), Some("\x1B[55m".to_string()));
+    /// ```
+    pub fn overline_off(&self) -> Option<String> {
+        self.ext_sequence(OVERLINE_OFF, &[])
+    }
+
+    /// Returns true if the terminal can strike through text (`smxx` and `rmxx`, or `Smxx` and
+    /// `Rmxx`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/xterm-256color").unwrap().supports_strikethrough());
+    /// assert!(TermInfo::from_file("tests/data/cx-ext-alt").unwrap().supports_strikethrough());
+    /// assert!(!TermInfo::from_file("tests/data/cx-ext").unwrap().supports_strikethrough());
+    /// ```
+    pub fn supports_strikethrough(&self) -> bool {
+        self.strikethrough_on().is_some() && self.strikethrough_off().is_some()
+    }
+
+    /// Returns the sequence to start struck through text (`smxx` or `Smxx`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.strikethrough_on(), Some("\x1B[9m".to_string()));
+    /// let info = TermInfo::from_file("tests/data/cx-ext-alt").unwrap();
+    /// assert_eq!(info.strikethrough_on(), Some("\x1B[9m".to_string()));
+    /// ```
+    pub fn strikethrough_on(&self) -> Option<String> {
+        self.ext_sequence(STRIKETHROUGH_ON, &[])
+            .or_else(|| self.ext_sequence(STRIKETHROUGH_ON_ALT, &[]))
+    }
+
+    /// Returns the sequence to end struck through text (`rmxx` or `Rmxx`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext-alt").unwrap();
+    /// assert_eq!(info.strikethrough_off(), Some("\x1B[29m".to_string()));
+    /// assert_eq!(TermInfo::from_file("tests/data/cx-ext").unwrap().strikethrough_off(), None);
+    /// ```
+    pub fn strikethrough_off(&self) -> Option<String> {
+        self.ext_sequence(STRIKETHROUGH_OFF, &[])
+            .or_else(|| self.ext_sequence(STRIKETHROUGH_OFF_ALT, &[]))
+    }
+
+    /// Returns the sequence to copy base64 encoded data into a selection (`Ms`, OSC 52) or
+    /// Option::None
+    ///
+    /// # Arguments
+    /// * `selection` - `c` clipboard, `p` primary, ...
+    /// * `base64`    - base64 encoded data
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.osc52("c", "aGk="), Some("\x1B]52;c;aGk=\x07".to_string()));
+    /// ```
+    pub fn osc52(&self, selection: &str, base64: &str) -> Option<String> {
+        self.ext_sequence(SET_SELECTION, &[Param::from(selection), Param::from(base64)])
+    }
+
+    /// Returns the sequence to set the cursor style (`Ss`, 0-6 DECSCUSR) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.set_cursor_style(5), Some("\x1B[5 q".to_string()));
+    /// ```
+    pub fn set_cursor_style(&self, style: u8) -> Option<String> {
+        self.ext_sequence(SET_CURSOR_STYLE, &[Param::Number(style as i32)])
+    }
+
+    /// Returns the sequence to enable or disable bracketed paste (`BE`/`BD`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.bracketed_paste(true), Some("\x1B[?2004h".to_string()));
+    /// assert_eq!(info.bracketed_paste(false), Some("\x1B[?2004l".to_string()));
+    /// ```
+    pub fn bracketed_paste(&self, enable: bool) -> Option<String> {
+        match enable {
+            true => self.ext_sequence(BRACKETED_PASTE_ENABLE, &[]),
+            false => self.ext_sequence(BRACKETED_PASTE_DISABLE, &[]),
+        }
+    }
+
+    /// Returns the sequence to begin or end synchronized output (`Sync`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.synchronized_output(true), Some("\x1BP=1s\x1B\\".to_string()));
+    /// assert_eq!(info.synchronized_output(false), Some("\x1BP=2s\x1B\\".to_string()));
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.synchronized_output(true), None);
+    /// ```
+    pub fn synchronized_output(&self, begin: bool) -> Option<String> {
+        let mode = match begin {
+            true => 1,
+            false => 2,
+        };
+        self.ext_sequence(SYNC, &[Param::Number(mode)])
+    }
+
+    fn ext_sequence(&self, name: &str, params: &[Param]) -> Option<String> {
+        self.get_ext_string(name).and_then(|term| evaluate(term, params).ok())
+    }
+
+    /// Returns the best available sequence to set the foreground color to r, g, b or
+    /// Option::None if the terminal has less than 256 colors
+    ///
+    /// Uses `set_rgb_foreground` if the terminal supports 24-bit colors, otherwise `setaf` with
+    /// the closest color of the 256-color palette, see `rgb_to_xterm_256`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.set_foreground_rgb(250, 10, 5), Some("\x1B[38:2::250:10:5m".to_string()));
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.set_foreground_rgb(250, 10, 5), Some("\x1B[38;5;196m".to_string()));
+    /// ```
+    pub fn set_foreground_rgb(&self, r: u8, g: u8, b: u8) -> Option<String> {
+        self.set_rgb_foreground(r, g, b)
+            .or_else(|| self.color_fallback(StringCapability::SetAnsiForeground, r, g, b))
+    }
+
+    /// Returns the best available sequence to set the background color to r, g, b or
+    /// Option::None if the terminal has less than 256 colors, see `set_foreground_rgb`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.set_background_rgb(0, 0, 128), Some("\x1B[48:2:0:0:128m".to_string()));
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.set_background_rgb(0, 0, 128), Some("\x1B[48;5;18m".to_string()));
+    /// ```
+    pub fn set_background_rgb(&self, r: u8, g: u8, b: u8) -> Option<String> {
+        self.set_rgb_background(r, g, b)
+            .or_else(|| self.color_fallback(StringCapability::SetAnsiBackground, r, g, b))
+    }
+
+    /// Returns the closest color of the terminal's palette for an RGB value
+    ///
+    /// The palette depends on `colors`: the xterm 256-color palette without the system colors
+    /// (see `rgb_to_xterm_256`), the xterm 88-color palette, the 16 or 8 ANSI colors of xterm.
+    /// Terminals without colors return 0, unless the color policy forces the ANSI colors.
+    ///
+    /// # Arguments
+    /// * `r` - red
+    /// * `g` - green
+    /// * `b` - blue
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.quantize_color(255, 0, 0), 196);
+    /// assert_eq!(info.quantize_color(0x80, 0x80, 0x80), 244);
+    /// ```
+    pub fn quantize_color(&self, r: u8, g: u8, b: u8) -> u16 {
+        let colors = match self.forces_ansi_colors() {
+            true => 8,
+            false => self.colors(),
+        };
+        let closest = |palette: std::ops::Range<u8>, rgb: fn(u8) -> (u8, u8, u8)| {
+            palette.min_by_key(|index| distance((r, g, b), rgb(*index))).unwrap_or(0) as u16
+        };
+
+        match colors {
+            256..=u32::MAX => rgb_to_xterm_256(r, g, b) as u16,
+            88..=255 => closest(16..88, xterm_88_to_rgb),
+            16..=87 => closest(0..16, xterm_256_to_rgb),
+            8..=15 => closest(0..8, xterm_256_to_rgb),
+            _ => 0,
+        }
+    }
+
+    /// Returns the sequence to set the foreground color to r, g, b, or to the closest color of
+    /// the palette if the terminal doesn't support 24-bit colors
+    ///
+    /// Uses `set_rgb_foreground`, otherwise `set_foreground_color` with `quantize_color`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    /// assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5).unwrap(), b"\x1B[38:2::250:10:5m");
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5).unwrap(), b"\x1B[38;5;196m");
+    /// ```
+    pub fn set_foreground_rgb_or_nearest(&self,
+                                         r: u8,
+                                         g: u8,
+                                         b: u8) -> Result<Vec<u8>, FormatError> {
+        match self.set_rgb_foreground(r, g, b) {
+            Some(seq) => Ok(seq.chars().map(|c| c as u8).collect()),
+            None => self.set_foreground_color(self.quantize_color(r, g, b)),
+        }
+    }
+
+    /// Returns the sequence to set the background color to r, g, b, or to the closest color of
+    /// the palette, see `set_foreground_rgb_or_nearest`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.set_background_rgb_or_nearest(0, 0, 128).unwrap(), b"\x1B[48;5;18m");
+    /// ```
+    pub fn set_background_rgb_or_nearest(&self,
+                                         r: u8,
+                                         g: u8,
+                                         b: u8) -> Result<Vec<u8>, FormatError> {
+        match self.set_rgb_background(r, g, b) {
+            Some(seq) => Ok(seq.chars().map(|c| c as u8).collect()),
+            None => self.set_background_color(self.quantize_color(r, g, b)),
+        }
+    }
+
+    /// The indexed capability with the closest 256-color
+    fn color_fallback(&self, indexed: StringCapability, r: u8, g: u8, b: u8) -> Option<String> {
+        if self.get_number(NumberCapability::MaxColors).unwrap_or(0) < 256 {
+            return None;
+        }
+
+        let term = self.get_string(indexed)?;
+        evaluate(&term, &[Param::Number(rgb_to_xterm_256(r, g, b) as i32)]).ok()
+    }
+
+    fn rgb_sequence(&self,
+                    name: &str,
+                    direct: StringCapability,
+                    sgr: u8,
+                    r: u8,
+                    g: u8,
+                    b: u8) -> Option<String> {
+        if self.colors_suppressed() {
+            return Some(String::new());
+        }
+
+        let via = match self.color_support() {
+            ColorSupport::Direct { via } => via,
+            _ => return None,
+        };
+
+        match via {
+            DirectMethod::SetRgb => {
+                let term = self.get_ext_string(name)?;
+                let params = [Param::Number(r as i32), Param::Number(g as i32), Param::Number(b as i32)];
+                evaluate(term, &params).ok()
+            }
+            DirectMethod::Setaf => {
+                let term = self.get_string(direct)?;
+                // setaf reads values below 8 as ANSI colors
+                match direct_color(r, g, b) {
+                    0..=7 => Some(format!("\x1B[{}:2::{}:{}:{}m", sgr, r, g, b)),
+                    color => evaluate(&term, &[Param::Number(color)]).ok(),
+                }
+            }
+            DirectMethod::Truecolor | DirectMethod::ColorTerm => {
+                Some(format!("\x1B[{};2;{};{};{}m", sgr, r, g, b))
+            }
+        }
+    }
+}
+
+/// Returns the color as `0xRRGGBB`
+#[cfg(feature = "parser")]
+fn direct_color(r: u8, g: u8, b: u8) -> i32 {
+    ((r as i32) << 16) | ((g as i32) << 8) | b as i32
+}
+
+/// Returns true if `COLORTERM` announces 24-bit colors
+#[cfg(feature = "parser")]
+pub(crate) fn announces_truecolor<E: EnvProvider>(env: &E) -> bool {
+    match env.var("COLORTERM") {
+        Some(value) => value == "truecolor" || value == "24bit",
+        None => false,
+    }
+}
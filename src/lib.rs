@@ -4,11 +4,22 @@
 //  terms of the Do What The Fuck You Want To Public License, Version 2,
 //  as published by Sam Hocevar. See the COPYING file for more details.
 
+#[cfg(feature = "parser")]
 #[macro_use]
 pub mod terminfo;
 pub mod capabilities;
+pub mod ext;
+#[cfg(feature = "methods")]
+pub mod methods;
+#[cfg(feature = "param-string")]
+pub mod padding;
+#[cfg(feature = "param-string")]
 pub mod param_string;
+#[cfg(feature = "parser")]
+pub mod profile;
+pub mod response;
 
+#[cfg(feature = "param-string")]
 #[macro_export]
 macro_rules! sprintf {
     ($f:expr, $($a:expr),*)
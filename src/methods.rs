@@ -0,0 +1,614 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Typed accessors for every capability (feature `methods`, default)
+//!
+//! The methods are named after the variable names of terminfo(5) and return the same values
+//! as `get_bool`, `get_number` and `get_string`. String capabilities have a second method
+//! with the suffix `_raw` returning the bytes like `TermInfo::get`. Method and short names
+//! are checked against the name tables of the capability enums at compile time.
+//!
+//! # Example
+//! ```
+//! use cxterminfo::terminfo::TermInfo;
+//!
+//! let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+//! assert_eq!(info.auto_left_margin(), Some(false));
+//! assert_eq!(info.max_colors(), Some(256));
+//! assert_eq!(info.cursor_address_raw(), Some(&b"\x1B[%i%p1%d;%p2%dH"[..]));
+//! ```
+
+use crate::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use crate::capabilities::{BOOL_LONG_NAMES, NUMBER_LONG_NAMES, STRING_LONG_NAMES};
+use crate::capabilities::{BOOL_NAMES, NUMBER_NAMES, STRING_NAMES};
+use crate::terminfo::TermInfo;
+
+/// Implements an accessor per capability and asserts that the method and short names match
+/// the name tables
+macro_rules! capability_methods {
+    ($cap:ident, $names:ident, $long_names:ident, $getter:ident -> $value:ty,
+     $($method:ident => $variant:ident, $name:literal;)*) => {
+        impl TermInfo {
+            $(
+            #[doc = concat!("Returns the value of `", $name, "` or Option::None, see [`",
+                            stringify!($cap), "::", stringify!($variant), "`]")]
+            pub fn $method(&self) -> Option<$value> {
+                self.$getter($cap::$variant)
+            }
+            )*
+        }
+
+        const _: () = {
+            $(assert!(str_eq($names[$cap::$variant as usize], $name)
+                      && str_eq($long_names[$cap::$variant as usize], stringify!($method)));)*
+        };
+    };
+}
+
+/// Implements the accessors of string capabilities, readable and as bytes
+macro_rules! string_methods {
+    ($($method:ident, $raw:ident => $variant:ident, $name:literal;)*) => {
+        capability_methods!(StringCapability, STRING_NAMES, STRING_LONG_NAMES,
+                            get_string -> String, $($method => $variant, $name;)*);
+
+        impl TermInfo {
+            $(
+            #[doc = concat!("Returns the bytes of `", $name, "` or Option::None, see [`",
+                            "StringCapability::", stringify!($variant), "`]")]
+            pub fn $raw(&self) -> Option<&[u8]> {
+                self.get(StringCapability::$variant)
+            }
+            )*
+        }
+
+        const _: () = {
+            $(assert!(is_raw_name(stringify!($raw), stringify!($method)));)*
+        };
+    };
+}
+
+/// `a == b` for constant evaluation
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < a.len() {
+        if a[idx] != b[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// `raw == format!("{}_raw", name)` for constant evaluation
+const fn is_raw_name(raw: &str, name: &str) -> bool {
+    let (raw, name) = (raw.as_bytes(), name.as_bytes());
+    if raw.len() != name.len() + 4 {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < raw.len() {
+        let expected = if idx < name.len() { name[idx] } else { b"_raw"[idx - name.len()] };
+        if raw[idx] != expected {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+capability_methods! {
+    BoolCapability, BOOL_NAMES, BOOL_LONG_NAMES, get_bool -> bool,
+    auto_left_margin => AutoLeftMargin, "bw";
+    auto_right_margin => AutoRightMargin, "am";
+    no_esc_ctlc => NoEscCtlc, "xsb";
+    ceol_standout_glitch => CeolStandoutGlitch, "xhp";
+    eat_newline_glitch => EatNewlineGlitch, "xenl";
+    erase_overstrike => EraseOverstrike, "eo";
+    generic_type => GenericType, "gn";
+    hard_copy => HardCopy, "hc";
+    has_meta_key => HasMetaKey, "km";
+    has_status_line => HasStatusLine, "hs";
+    insert_null_glitch => InsertNullGlitch, "in";
+    memory_above => MemoryAbove, "da";
+    memory_below => MemoryBelow, "db";
+    move_insert_mode => MoveInsertMode, "mir";
+    move_standout_mode => MoveStandoutMode, "msgr";
+    over_strike => OverStrike, "os";
+    status_line_esc_ok => StatusLineEscOk, "eslok";
+    dest_tabs_magic_smso => DestTabsMagicSmso, "xt";
+    tilde_glitch => TildeGlitch, "hz";
+    transparent_underline => TransparentUnderline, "ul";
+    xon_xoff => XonXoff, "xon";
+    needs_xon_xoff => NeedsXonXoff, "nxon";
+    prtr_silent => PrtrSilent, "mc5i";
+    hard_cursor => HardCursor, "chts";
+    non_rev_rmcup => NonRevRmcup, "nrrmc";
+    no_pad_char => NoPadChar, "npc";
+    non_dest_scroll_region => NonDestScrollRegion, "ndscr";
+    can_change => CanChange, "ccc";
+    back_color_erase => BackColorErase, "bce";
+    hue_lightness_saturation => HueLightnessSaturation, "hls";
+    col_addr_glitch => ColAddrGlitch, "xhpa";
+    cr_cancels_micro_mode => CrCancelsMicroMode, "crxm";
+    has_print_wheel => HasPrintWheel, "daisy";
+    row_addr_glitch => RowAddrGlitch, "xvpa";
+    semi_auto_right_margin => SemiAutoRightMargin, "sam";
+    cpi_changes_res => CpiChangesRes, "cpix";
+    lpi_changes_res => LpiChangesRes, "lpix";
+    backspaces_with_bs => BackspacesWithBs, "OTbs";
+    crt_no_scrolling => CrtNoScrolling, "OTns";
+    no_correctly_working_cr => NoCorrectlyWorkingCr, "OTnc";
+    gnu_has_meta_key => GnuHasMetaKey, "OTMT";
+    linefeed_is_newline => LinefeedIsNewline, "OTNL";
+    has_hardware_tabs => HasHardwareTabs, "OTpt";
+    return_does_clr_eol => ReturnDoesClrEol, "OTxr";
+}
+
+capability_methods! {
+    NumberCapability, NUMBER_NAMES, NUMBER_LONG_NAMES, get_number -> i32,
+    columns => Columns, "cols";
+    init_tabs => InitTabs, "it";
+    lines => Lines, "lines";
+    lines_of_memory => LinesOfMemory, "lm";
+    magic_cookie_glitch => MagicCookieGlitch, "xmc";
+    padding_baud_rate => PaddingBaudRate, "pb";
+    virtual_terminal => VirtualTerminal, "vt";
+    width_status_line => WidthStatusLine, "wsl";
+    num_labels => NumLabels, "nlab";
+    label_height => LabelHeight, "lh";
+    label_width => LabelWidth, "lw";
+    max_attributes => MaxAttributes, "ma";
+    maximum_windows => MaximumWindows, "wnum";
+    max_colors => MaxColors, "colors";
+    max_pairs => MaxPairs, "pairs";
+    no_color_video => NoColorVideo, "ncv";
+    buffer_capacity => BufferCapacity, "bufsz";
+    dot_vert_spacing => DotVertSpacing, "spinv";
+    dot_horz_spacing => DotHorzSpacing, "spinh";
+    max_micro_address => MaxMicroAddress, "maddr";
+    max_micro_jump => MaxMicroJump, "mjump";
+    micro_col_size => MicroColSize, "mcs";
+    micro_line_size => MicroLineSize, "mls";
+    number_of_pins => NumberOfPins, "npins";
+    output_res_char => OutputResChar, "orc";
+    output_res_line => OutputResLine, "orl";
+    output_res_horz_inch => OutputResHorzInch, "orhi";
+    output_res_vert_inch => OutputResVertInch, "orvi";
+    print_rate => PrintRate, "cps";
+    wide_char_size => WideCharSize, "widcs";
+    buttons => Buttons, "btns";
+    bit_image_entwining => BitImageEntwining, "bitwin";
+    bit_image_type => BitImageType, "bitype";
+    magic_cookie_glitch_ul => MagicCookieGlitchUl, "OTug";
+    carriage_return_delay => CarriageReturnDelay, "OTdC";
+    new_line_delay => NewLineDelay, "OTdN";
+    backspace_delay => BackspaceDelay, "OTdB";
+    horizontal_tab_delay => HorizontalTabDelay, "OTdT";
+    number_of_function_keys => NumberOfFunctionKeys, "OTkn";
+}
+
+string_methods! {
+    back_tab, back_tab_raw => BackTab, "cbt";
+    bell, bell_raw => Bell, "bel";
+    carriage_return, carriage_return_raw => CarriageReturn, "cr";
+    change_scroll_region, change_scroll_region_raw => ChangeScrollRegion, "csr";
+    clear_all_tabs, clear_all_tabs_raw => ClearAllTabs, "tbc";
+    clear_screen, clear_screen_raw => ClearScreen, "clear";
+    clr_eol, clr_eol_raw => ClearEOL, "el";
+    clr_eos, clr_eos_raw => ClearEOS, "ed";
+    column_address, column_address_raw => ColumnAddress, "hpa";
+    command_character, command_character_raw => CommandCharacter, "cmdch";
+    cursor_address, cursor_address_raw => CursorAddress, "cup";
+    cursor_down, cursor_down_raw => CursorDown, "cud1";
+    cursor_home, cursor_home_raw => CursorHome, "home";
+    cursor_invisible, cursor_invisible_raw => CursorInvisible, "civis";
+    cursor_left, cursor_left_raw => CursorLeft, "cub1";
+    cursor_mem_address, cursor_mem_address_raw => CursorMemAddress, "mrcup";
+    cursor_normal, cursor_normal_raw => CursorNormal, "cnorm";
+    cursor_right, cursor_right_raw => CursorRight, "cuf1";
+    cursor_to_ll, cursor_to_ll_raw => CursorToLastLine, "ll";
+    cursor_up, cursor_up_raw => CursorUp, "cuu1";
+    cursor_visible, cursor_visible_raw => CursorVisible, "cvvis";
+    delete_character, delete_character_raw => DeleteCharacter, "dch1";
+    delete_line, delete_line_raw => DeleteLine, "dl1";
+    dis_status_line, dis_status_line_raw => DisStatusLine, "dsl";
+    down_half_line, down_half_line_raw => DownHalfLine, "hd";
+    enter_alt_charset_mode, enter_alt_charset_mode_raw => EnterAltCharsetMode, "smacs";
+    enter_blink_mode, enter_blink_mode_raw => EnterBlinkMode, "blink";
+    enter_bold_mode, enter_bold_mode_raw => EnterBoldMode, "bold";
+    enter_ca_mode, enter_ca_mode_raw => EnterAlternativeMode, "smcup";
+    enter_delete_mode, enter_delete_mode_raw => EnterDeleteMode, "smdc";
+    enter_dim_mode, enter_dim_mode_raw => EnterDimMode, "dim";
+    enter_insert_mode, enter_insert_mode_raw => EnterInsertMode, "smir";
+    enter_secure_mode, enter_secure_mode_raw => EnterSecureMode, "invis";
+    enter_protected_mode, enter_protected_mode_raw => EnterProtectedMode, "prot";
+    enter_reverse_mode, enter_reverse_mode_raw => EnterReverseMode, "rev";
+    enter_standout_mode, enter_standout_mode_raw => EnterStandoutMode, "smso";
+    enter_underline_mode, enter_underline_mode_raw => EnterUnderlineMode, "smul";
+    erase_chars, erase_chars_raw => EraseChars, "ech";
+    exit_alt_charset_mode, exit_alt_charset_mode_raw => ExitAltCharsetMode, "rmacs";
+    exit_attribute_mode, exit_attribute_mode_raw => ExitAttributeMode, "sgr0";
+    exit_ca_mode, exit_ca_mode_raw => ExitAlternativeMode, "rmcup";
+    exit_delete_mode, exit_delete_mode_raw => ExitDeleteMode, "rmdc";
+    exit_insert_mode, exit_insert_mode_raw => ExitInsertMode, "rmir";
+    exit_standout_mode, exit_standout_mode_raw => ExitStandoutMode, "rmso";
+    exit_underline_mode, exit_underline_mode_raw => ExitUnderlineMode, "rmul";
+    flash_screen, flash_screen_raw => FlashScreen, "flash";
+    form_feed, form_feed_raw => FormFeed, "ff";
+    from_status_line, from_status_line_raw => FromStatusLine, "fsl";
+    init_1string, init_1string_raw => Init1String, "is1";
+    init_2string, init_2string_raw => Init2String, "is2";
+    init_3string, init_3string_raw => Init3String, "is3";
+    init_file, init_file_raw => InitFile, "if";
+    insert_character, insert_character_raw => InsertCharacter, "ich1";
+    insert_line, insert_line_raw => InsertLine, "il1";
+    insert_padding, insert_padding_raw => InsertPadding, "ip";
+    key_backspace, key_backspace_raw => KeyBackspace, "kbs";
+    key_catab, key_catab_raw => KeyClearAllTabs, "ktbc";
+    key_clear, key_clear_raw => KeyClear, "kclr";
+    key_ctab, key_ctab_raw => KeyClearTab, "kctab";
+    key_dc, key_dc_raw => KeyDeleteCharacter, "kdch1";
+    key_dl, key_dl_raw => KeyDeleteLine, "kdl1";
+    key_down, key_down_raw => KeyDown, "kcud1";
+    key_eic, key_eic_raw => KeyEic, "krmir";
+    key_eol, key_eol_raw => KeyClearEOL, "kel";
+    key_eos, key_eos_raw => KeyClearEOS, "ked";
+    key_f0, key_f0_raw => KeyF0, "kf0";
+    key_f1, key_f1_raw => KeyF1, "kf1";
+    key_f10, key_f10_raw => KeyF10, "kf10";
+    key_f2, key_f2_raw => KeyF2, "kf2";
+    key_f3, key_f3_raw => KeyF3, "kf3";
+    key_f4, key_f4_raw => KeyF4, "kf4";
+    key_f5, key_f5_raw => KeyF5, "kf5";
+    key_f6, key_f6_raw => KeyF6, "kf6";
+    key_f7, key_f7_raw => KeyF7, "kf7";
+    key_f8, key_f8_raw => KeyF8, "kf8";
+    key_f9, key_f9_raw => KeyF9, "kf9";
+    key_home, key_home_raw => KeyHome, "khome";
+    key_ic, key_ic_raw => KeyInsertCharacter, "kich1";
+    key_il, key_il_raw => KeyInsertLine, "kil1";
+    key_left, key_left_raw => KeyLeft, "kcub1";
+    key_ll, key_ll_raw => KeyLastLine, "kll";
+    key_npage, key_npage_raw => KeyNextPage, "knp";
+    key_ppage, key_ppage_raw => KeyPreviousPage, "kpp";
+    key_right, key_right_raw => KeyRight, "kcuf1";
+    key_sf, key_sf_raw => KeyScrollForward, "kind";
+    key_sr, key_sr_raw => KeyScrollBackward, "kri";
+    key_stab, key_stab_raw => KeySetTab, "khts";
+    key_up, key_up_raw => KeyUp, "kcuu1";
+    keypad_local, keypad_local_raw => KeypadLocal, "rmkx";
+    keypad_xmit, keypad_xmit_raw => KeypadXmit, "smkx";
+    lab_f0, lab_f0_raw => LabF0, "lf0";
+    lab_f1, lab_f1_raw => LabF1, "lf1";
+    lab_f10, lab_f10_raw => LabF10, "lf10";
+    lab_f2, lab_f2_raw => LabF2, "lf2";
+    lab_f3, lab_f3_raw => LabF3, "lf3";
+    lab_f4, lab_f4_raw => LabF4, "lf4";
+    lab_f5, lab_f5_raw => LabF5, "lf5";
+    lab_f6, lab_f6_raw => LabF6, "lf6";
+    lab_f7, lab_f7_raw => LabF7, "lf7";
+    lab_f8, lab_f8_raw => LabF8, "lf8";
+    lab_f9, lab_f9_raw => LabF9, "lf9";
+    meta_off, meta_off_raw => MetaOff, "rmm";
+    meta_on, meta_on_raw => MetaOn, "smm";
+    newline, newline_raw => Newline, "nel";
+    pad_char, pad_char_raw => PadChar, "pad";
+    parm_dch, parm_dch_raw => ParmDeleteCharacters, "dch";
+    parm_delete_line, parm_delete_line_raw => ParmDeleteLine, "dl";
+    parm_down_cursor, parm_down_cursor_raw => ParmDownCursor, "cud";
+    parm_ich, parm_ich_raw => ParmInsertCharacters, "ich";
+    parm_index, parm_index_raw => ParmIndex, "indn";
+    parm_insert_line, parm_insert_line_raw => ParmInsertLine, "il";
+    parm_left_cursor, parm_left_cursor_raw => ParmLeftCursor, "cub";
+    parm_right_cursor, parm_right_cursor_raw => ParmRightCursor, "cuf";
+    parm_rindex, parm_rindex_raw => ParmReverseIndex, "rin";
+    parm_up_cursor, parm_up_cursor_raw => ParmUpCursor, "cuu";
+    pkey_key, pkey_key_raw => PKeyKey, "pfkey";
+    pkey_local, pkey_local_raw => PKeyLocal, "pfloc";
+    pkey_xmit, pkey_xmit_raw => PKeyXmit, "pfx";
+    print_screen, print_screen_raw => PrintScreen, "mc0";
+    prtr_off, prtr_off_raw => PrinterOff, "mc4";
+    prtr_on, prtr_on_raw => PrinterOn, "mc5";
+    repeat_char, repeat_char_raw => RepeatChar, "rep";
+    reset_1string, reset_1string_raw => Reset1String, "rs1";
+    reset_2string, reset_2string_raw => Reset2String, "rs2";
+    reset_3string, reset_3string_raw => Reset3String, "rs3";
+    reset_file, reset_file_raw => ResetFile, "rf";
+    restore_cursor, restore_cursor_raw => RestoreCursor, "rc";
+    row_address, row_address_raw => RowAddress, "vpa";
+    save_cursor, save_cursor_raw => SaveCursor, "sc";
+    scroll_forward, scroll_forward_raw => ScrollForward, "ind";
+    scroll_reverse, scroll_reverse_raw => ScrollReverse, "ri";
+    set_attributes, set_attributes_raw => SetAttributes, "sgr";
+    set_tab, set_tab_raw => SetTab, "hts";
+    set_window, set_window_raw => SetWindow, "wind";
+    tab, tab_raw => Tab, "ht";
+    to_status_line, to_status_line_raw => ToStatusLine, "tsl";
+    underline_char, underline_char_raw => UnderlineChar, "uc";
+    up_half_line, up_half_line_raw => UpHalfLine, "hu";
+    init_prog, init_prog_raw => InitProg, "iprog";
+    key_a1, key_a1_raw => KeyA1, "ka1";
+    key_a3, key_a3_raw => KeyA3, "ka3";
+    key_b2, key_b2_raw => KeyB2, "kb2";
+    key_c1, key_c1_raw => KeyC1, "kc1";
+    key_c3, key_c3_raw => KeyC3, "kc3";
+    prtr_non, prtr_non_raw => PrinterOnForNBytes, "mc5p";
+    char_padding, char_padding_raw => CharPadding, "rmp";
+    acs_chars, acs_chars_raw => AcsChars, "acsc";
+    plab_norm, plab_norm_raw => PlabNorm, "pln";
+    key_btab, key_btab_raw => KeyBackTab, "kcbt";
+    enter_xon_mode, enter_xon_mode_raw => EnterXonMode, "smxon";
+    exit_xon_mode, exit_xon_mode_raw => ExitXonMode, "rmxon";
+    enter_am_mode, enter_am_mode_raw => EnterAutomaticMarginsMode, "smam";
+    exit_am_mode, exit_am_mode_raw => ExitAutomaticMarginsMode, "rmam";
+    xon_character, xon_character_raw => XOnCharacter, "xonc";
+    xoff_character, xoff_character_raw => XOffCharacter, "xoffc";
+    ena_acs, ena_acs_raw => EnableAlternateCharSet, "enacs";
+    label_on, label_on_raw => LabelOn, "smln";
+    label_off, label_off_raw => LabelOff, "rmln";
+    key_beg, key_beg_raw => KeyBegin, "kbeg";
+    key_cancel, key_cancel_raw => KeyCancel, "kcan";
+    key_close, key_close_raw => KeyClose, "kclo";
+    key_command, key_command_raw => KeyCommand, "kcmd";
+    key_copy, key_copy_raw => KeyCopy, "kcpy";
+    key_create, key_create_raw => KeyCreate, "kcrt";
+    key_end, key_end_raw => KeyEnd, "kend";
+    key_enter, key_enter_raw => KeyEnter, "kent";
+    key_exit, key_exit_raw => KeyExit, "kext";
+    key_find, key_find_raw => KeyFind, "kfnd";
+    key_help, key_help_raw => KeyHelp, "khlp";
+    key_mark, key_mark_raw => KeyMark, "kmrk";
+    key_message, key_message_raw => KeyMessage, "kmsg";
+    key_move, key_move_raw => KeyMove, "kmov";
+    key_next, key_next_raw => KeyNext, "knxt";
+    key_open, key_open_raw => KeyOpen, "kopn";
+    key_options, key_options_raw => KeyOptions, "kopt";
+    key_previous, key_previous_raw => KeyPrevious, "kprv";
+    key_print, key_print_raw => KeyPrint, "kprt";
+    key_redo, key_redo_raw => KeyRedo, "krdo";
+    key_reference, key_reference_raw => KeyReference, "kref";
+    key_refresh, key_refresh_raw => KeyRefresh, "krfr";
+    key_replace, key_replace_raw => KeyReplace, "krpl";
+    key_restart, key_restart_raw => KeyRestart, "krst";
+    key_resume, key_resume_raw => KeyResume, "kres";
+    key_save, key_save_raw => KeySave, "ksav";
+    key_suspend, key_suspend_raw => KeySuspend, "kspd";
+    key_undo, key_undo_raw => KeyUndo, "kund";
+    key_sbeg, key_sbeg_raw => KeyShiftBegin, "kBEG";
+    key_scancel, key_scancel_raw => KeyShiftCancel, "kCAN";
+    key_scommand, key_scommand_raw => KeyShiftCommand, "kCMD";
+    key_scopy, key_scopy_raw => KeyShiftCopy, "kCPY";
+    key_screate, key_screate_raw => KeyShiftCreate, "kCRT";
+    key_sdc, key_sdc_raw => KeyShiftDeleteChar, "kDC";
+    key_sdl, key_sdl_raw => KeyShiftDeleteLine, "kDL";
+    key_select, key_select_raw => KeySelect, "kslt";
+    key_send, key_send_raw => KeyShiftEnd, "kEND";
+    key_seol, key_seol_raw => KeyShiftEOL, "kEOL";
+    key_sexit, key_sexit_raw => KeyShiftExit, "kEXT";
+    key_sfind, key_sfind_raw => KeyShiftFind, "kFND";
+    key_shelp, key_shelp_raw => KeyShiftHelp, "kHLP";
+    key_shome, key_shome_raw => KeyShiftHome, "kHOM";
+    key_sic, key_sic_raw => KeyShiftInputKey, "kIC";
+    key_sleft, key_sleft_raw => KeyShiftLeft, "kLFT";
+    key_smessage, key_smessage_raw => KeyShiftMessage, "kMSG";
+    key_smove, key_smove_raw => KeyShiftMove, "kMOV";
+    key_snext, key_snext_raw => KeyShiftNext, "kNXT";
+    key_soptions, key_soptions_raw => KeyShiftOptions, "kOPT";
+    key_sprevious, key_sprevious_raw => KeyShiftPrevious, "kPRV";
+    key_sprint, key_sprint_raw => KeyShiftPrint, "kPRT";
+    key_sredo, key_sredo_raw => KeyShiftRedo, "kRDO";
+    key_sreplace, key_sreplace_raw => KeyShiftReplace, "kRPL";
+    key_sright, key_sright_raw => KeyShiftRight, "kRIT";
+    key_srsume, key_srsume_raw => KeyShiftResume, "kRES";
+    key_ssave, key_ssave_raw => KeyShiftSave, "kSAV";
+    key_ssuspend, key_ssuspend_raw => KeyShiftSuspend, "kSPD";
+    key_sundo, key_sundo_raw => KeyShiftUndo, "kUND";
+    req_for_input, req_for_input_raw => ReqForInput, "rfi";
+    key_f11, key_f11_raw => KeyF11, "kf11";
+    key_f12, key_f12_raw => KeyF12, "kf12";
+    key_f13, key_f13_raw => KeyF13, "kf13";
+    key_f14, key_f14_raw => KeyF14, "kf14";
+    key_f15, key_f15_raw => KeyF15, "kf15";
+    key_f16, key_f16_raw => KeyF16, "kf16";
+    key_f17, key_f17_raw => KeyF17, "kf17";
+    key_f18, key_f18_raw => KeyF18, "kf18";
+    key_f19, key_f19_raw => KeyF19, "kf19";
+    key_f20, key_f20_raw => KeyF20, "kf20";
+    key_f21, key_f21_raw => KeyF21, "kf21";
+    key_f22, key_f22_raw => KeyF22, "kf22";
+    key_f23, key_f23_raw => KeyF23, "kf23";
+    key_f24, key_f24_raw => KeyF24, "kf24";
+    key_f25, key_f25_raw => KeyF25, "kf25";
+    key_f26, key_f26_raw => KeyF26, "kf26";
+    key_f27, key_f27_raw => KeyF27, "kf27";
+    key_f28, key_f28_raw => KeyF28, "kf28";
+    key_f29, key_f29_raw => KeyF29, "kf29";
+    key_f30, key_f30_raw => KeyF30, "kf30";
+    key_f31, key_f31_raw => KeyF31, "kf31";
+    key_f32, key_f32_raw => KeyF32, "kf32";
+    key_f33, key_f33_raw => KeyF33, "kf33";
+    key_f34, key_f34_raw => KeyF34, "kf34";
+    key_f35, key_f35_raw => KeyF35, "kf35";
+    key_f36, key_f36_raw => KeyF36, "kf36";
+    key_f37, key_f37_raw => KeyF37, "kf37";
+    key_f38, key_f38_raw => KeyF38, "kf38";
+    key_f39, key_f39_raw => KeyF39, "kf39";
+    key_f40, key_f40_raw => KeyF40, "kf40";
+    key_f41, key_f41_raw => KeyF41, "kf41";
+    key_f42, key_f42_raw => KeyF42, "kf42";
+    key_f43, key_f43_raw => KeyF43, "kf43";
+    key_f44, key_f44_raw => KeyF44, "kf44";
+    key_f45, key_f45_raw => KeyF45, "kf45";
+    key_f46, key_f46_raw => KeyF46, "kf46";
+    key_f47, key_f47_raw => KeyF47, "kf47";
+    key_f48, key_f48_raw => KeyF48, "kf48";
+    key_f49, key_f49_raw => KeyF49, "kf49";
+    key_f50, key_f50_raw => KeyF50, "kf50";
+    key_f51, key_f51_raw => KeyF51, "kf51";
+    key_f52, key_f52_raw => KeyF52, "kf52";
+    key_f53, key_f53_raw => KeyF53, "kf53";
+    key_f54, key_f54_raw => KeyF54, "kf54";
+    key_f55, key_f55_raw => KeyF55, "kf55";
+    key_f56, key_f56_raw => KeyF56, "kf56";
+    key_f57, key_f57_raw => KeyF57, "kf57";
+    key_f58, key_f58_raw => KeyF58, "kf58";
+    key_f59, key_f59_raw => KeyF59, "kf59";
+    key_f60, key_f60_raw => KeyF60, "kf60";
+    key_f61, key_f61_raw => KeyF61, "kf61";
+    key_f62, key_f62_raw => KeyF62, "kf62";
+    key_f63, key_f63_raw => KeyF63, "kf63";
+    clr_bol, clr_bol_raw => ClearBOL, "el1";
+    clear_margins, clear_margins_raw => ClearMargins, "mgc";
+    set_left_margin, set_left_margin_raw => SetLeftMargin, "smgl";
+    set_right_margin, set_right_margin_raw => SetRightMargin, "smgr";
+    label_format, label_format_raw => LabelFormat, "fln";
+    set_clock, set_clock_raw => SetClock, "sclk";
+    display_clock, display_clock_raw => DisplayClock, "dclk";
+    remove_clock, remove_clock_raw => RemoveClock, "rmclk";
+    create_window, create_window_raw => CreateWindow, "cwin";
+    goto_window, goto_window_raw => GotoWindow, "wingo";
+    hangup, hangup_raw => Hangup, "hup";
+    dial_phone, dial_phone_raw => DialPhone, "dial";
+    quick_dial, quick_dial_raw => QuickDial, "qdial";
+    tone, tone_raw => Tone, "tone";
+    pulse, pulse_raw => Pulse, "pulse";
+    flash_hook, flash_hook_raw => FlashHook, "hook";
+    fixed_pause, fixed_pause_raw => FixedPause, "pause";
+    wait_tone, wait_tone_raw => WaitTone, "wait";
+    user0, user0_raw => User0, "u0";
+    user1, user1_raw => User1, "u1";
+    user2, user2_raw => User2, "u2";
+    user3, user3_raw => User3, "u3";
+    user4, user4_raw => User4, "u4";
+    user5, user5_raw => User5, "u5";
+    user6, user6_raw => User6, "u6";
+    user7, user7_raw => User7, "u7";
+    user8, user8_raw => User8, "u8";
+    user9, user9_raw => User9, "u9";
+    orig_pair, orig_pair_raw => OrigColorPair, "op";
+    orig_colors, orig_colors_raw => OrigColors, "oc";
+    initialize_color, initialize_color_raw => InitializeColor, "initc";
+    initialize_pair, initialize_pair_raw => InitializePair, "initp";
+    set_color_pair, set_color_pair_raw => SetColorPair, "scp";
+    set_foreground, set_foreground_raw => SetForeground, "setf";
+    set_background, set_background_raw => SetBackground, "setb";
+    change_char_pitch, change_char_pitch_raw => ChangeCharPitch, "cpi";
+    change_line_pitch, change_line_pitch_raw => ChangeLinePitch, "lpi";
+    change_res_horz, change_res_horz_raw => ChangeResHorz, "chr";
+    change_res_vert, change_res_vert_raw => ChangeResVert, "cvr";
+    define_char, define_char_raw => DefineChar, "defc";
+    enter_doublewide_mode, enter_doublewide_mode_raw => EnterDoublewideMode, "swidm";
+    enter_draft_quality, enter_draft_quality_raw => EnterDraftQuality, "sdrfq";
+    enter_italics_mode, enter_italics_mode_raw => EnterItalicsMode, "sitm";
+    enter_leftward_mode, enter_leftward_mode_raw => EnterLeftwardMode, "slm";
+    enter_micro_mode, enter_micro_mode_raw => EnterMicroMode, "smicm";
+    enter_near_letter_quality, enter_near_letter_quality_raw => EnterNearLetterQuality, "snlq";
+    enter_normal_quality, enter_normal_quality_raw => EnterNormalQuality, "snrmq";
+    enter_shadow_mode, enter_shadow_mode_raw => EnterShadowMode, "sshm";
+    enter_subscript_mode, enter_subscript_mode_raw => EnterSubscriptMode, "ssubm";
+    enter_superscript_mode, enter_superscript_mode_raw => EnterSuperscriptMode, "ssupm";
+    enter_upward_mode, enter_upward_mode_raw => EnterUpwardMode, "sum";
+    exit_doublewide_mode, exit_doublewide_mode_raw => ExitDoublewideMode, "rwidm";
+    exit_italics_mode, exit_italics_mode_raw => ExitItalicsMode, "ritm";
+    exit_leftward_mode, exit_leftward_mode_raw => ExitLeftwardMode, "rlm";
+    exit_micro_mode, exit_micro_mode_raw => ExitMicroMode, "rmicm";
+    exit_shadow_mode, exit_shadow_mode_raw => ExitShadowMode, "rshm";
+    exit_subscript_mode, exit_subscript_mode_raw => ExitSubscriptMode, "rsubm";
+    exit_superscript_mode, exit_superscript_mode_raw => ExitSuperscriptMode, "rsupm";
+    exit_upward_mode, exit_upward_mode_raw => ExitUpwardMode, "rum";
+    micro_column_address, micro_column_address_raw => MicroColumnAddress, "mhpa";
+    micro_down, micro_down_raw => MicroDown, "mcud1";
+    micro_left, micro_left_raw => MicroLeft, "mcub1";
+    micro_right, micro_right_raw => MicroRight, "mcuf1";
+    micro_row_address, micro_row_address_raw => MicroRowAddress, "mvpa";
+    micro_up, micro_up_raw => MicroUp, "mcuu1";
+    order_of_pins, order_of_pins_raw => OrderOfPins, "porder";
+    parm_down_micro, parm_down_micro_raw => ParmDownMicro, "mcud";
+    parm_left_micro, parm_left_micro_raw => ParmLeftMicro, "mcub";
+    parm_right_micro, parm_right_micro_raw => ParmRightMicro, "mcuf";
+    parm_up_micro, parm_up_micro_raw => ParmUpMicro, "mcuu";
+    select_char_set, select_char_set_raw => SelectCharSet, "scs";
+    set_bottom_margin, set_bottom_margin_raw => SetBottomMargin, "smgb";
+    set_bottom_margin_parm, set_bottom_margin_parm_raw => SetBottomMarginParm, "smgbp";
+    set_left_margin_parm, set_left_margin_parm_raw => SetLeftMarginParm, "smglp";
+    set_right_margin_parm, set_right_margin_parm_raw => SetRightMarginParm, "smgrp";
+    set_top_margin, set_top_margin_raw => SetTopMargin, "smgt";
+    set_top_margin_parm, set_top_margin_parm_raw => SetTopMarginParm, "smgtp";
+    start_bit_image, start_bit_image_raw => StartBitImage, "sbim";
+    start_char_set_def, start_char_set_def_raw => StartCharSetDef, "scsd";
+    stop_bit_image, stop_bit_image_raw => StopBitImage, "rbim";
+    stop_char_set_def, stop_char_set_def_raw => StopCharSetDef, "rcsd";
+    subscript_characters, subscript_characters_raw => SubscriptCharacters, "subcs";
+    superscript_characters, superscript_characters_raw => SuperscriptCharacters, "supcs";
+    these_cause_cr, these_cause_cr_raw => TheseCauseCr, "docr";
+    zero_motion, zero_motion_raw => ZeroMotion, "zerom";
+    char_set_names, char_set_names_raw => CharSetNames, "csnm";
+    key_mouse, key_mouse_raw => KeyMouse, "kmous";
+    mouse_info, mouse_info_raw => MouseInfo, "minfo";
+    req_mouse_pos, req_mouse_pos_raw => ReqMousePos, "reqmp";
+    get_mouse, get_mouse_raw => GetMouse, "getm";
+    set_a_foreground, set_a_foreground_raw => SetAnsiForeground, "setaf";
+    set_a_background, set_a_background_raw => SetAnsiBackground, "setab";
+    pkey_plab, pkey_plab_raw => PKeyPlab, "pfxl";
+    device_type, device_type_raw => DeviceType, "devt";
+    code_set_init, code_set_init_raw => CodeSetInit, "csin";
+    set0_des_seq, set0_des_seq_raw => Set0DesSeq, "s0ds";
+    set1_des_seq, set1_des_seq_raw => Set1DesSeq, "s1ds";
+    set2_des_seq, set2_des_seq_raw => Set2DesSeq, "s2ds";
+    set3_des_seq, set3_des_seq_raw => Set3DesSeq, "s3ds";
+    set_lr_margin, set_lr_margin_raw => SetLrMargin, "smglr";
+    set_tb_margin, set_tb_margin_raw => SetTbMargin, "smgtb";
+    bit_image_repeat, bit_image_repeat_raw => BitImageRepeat, "birep";
+    bit_image_newline, bit_image_newline_raw => BitImageNewline, "binel";
+    bit_image_carriage_return, bit_image_carriage_return_raw => BitImageCarriageReturn, "bicr";
+    color_names, color_names_raw => ColorNames, "colornm";
+    define_bit_image_region, define_bit_image_region_raw => DefineBitImageRegion, "defbi";
+    end_bit_image_region, end_bit_image_region_raw => EndBitImageRegion, "endbi";
+    set_color_band, set_color_band_raw => SetColorBand, "setcolor";
+    set_page_length, set_page_length_raw => SetPageLength, "slines";
+    display_pc_char, display_pc_char_raw => DisplayPcChar, "dispc";
+    enter_pc_charset_mode, enter_pc_charset_mode_raw => EnterPcCharsetMode, "smpch";
+    exit_pc_charset_mode, exit_pc_charset_mode_raw => ExitPcCharsetMode, "rmpch";
+    enter_scancode_mode, enter_scancode_mode_raw => EnterScancodeMode, "smsc";
+    exit_scancode_mode, exit_scancode_mode_raw => ExitScancodeMode, "rmsc";
+    pc_term_options, pc_term_options_raw => PcTermOptions, "pctrm";
+    scancode_escape, scancode_escape_raw => ScancodeEscape, "scesc";
+    alt_scancode_esc, alt_scancode_esc_raw => AltScancodeEsc, "scesa";
+    enter_horizontal_hl_mode, enter_horizontal_hl_mode_raw => EnterHorizontalHlMode, "ehhlm";
+    enter_left_hl_mode, enter_left_hl_mode_raw => EnterLeftHlMode, "elhlm";
+    enter_low_hl_mode, enter_low_hl_mode_raw => EnterLowHlMode, "elohlm";
+    enter_right_hl_mode, enter_right_hl_mode_raw => EnterRightHlMode, "erhlm";
+    enter_top_hl_mode, enter_top_hl_mode_raw => EnterTopHlMode, "ethlm";
+    enter_vertical_hl_mode, enter_vertical_hl_mode_raw => EnterVerticalHlMode, "evhlm";
+    set_a_attributes, set_a_attributes_raw => SetAAttributes, "sgr1";
+    set_pglen_inch, set_pglen_inch_raw => SetPageLenInch, "slength";
+    termcap_init2, termcap_init2_raw => TermcapInit2, "OTi2";
+    termcap_reset, termcap_reset_raw => TermcapReset, "OTrs";
+    linefeed_if_not_lf, linefeed_if_not_lf_raw => LinefeedIfNotLf, "OTnl";
+    backspace_if_not_bs, backspace_if_not_bs_raw => BackspaceIfNotBs, "OTbc";
+    other_non_function_keys, other_non_function_keys_raw => OtherNonFunctionKeys, "OTko";
+    arrow_key_map, arrow_key_map_raw => ArrowKeyMap, "OTma";
+    acs_ulcorner, acs_ulcorner_raw => AcsUlcorner, "OTG2";
+    acs_llcorner, acs_llcorner_raw => AcsLlcorner, "OTG3";
+    acs_urcorner, acs_urcorner_raw => AcsUrcorner, "OTG1";
+    acs_lrcorner, acs_lrcorner_raw => AcsLrcorner, "OTG4";
+    acs_ltee, acs_ltee_raw => AcsLtee, "OTGR";
+    acs_rtee, acs_rtee_raw => AcsRtee, "OTGL";
+    acs_btee, acs_btee_raw => AcsBtee, "OTGU";
+    acs_ttee, acs_ttee_raw => AcsTtee, "OTGD";
+    acs_hline, acs_hline_raw => AcsHline, "OTGH";
+    acs_vline, acs_vline_raw => AcsVline, "OTGV";
+    acs_plus, acs_plus_raw => AcsPlus, "OTGC";
+    memory_lock, memory_lock_raw => MemoryLock, "meml";
+    memory_unlock, memory_unlock_raw => MemoryUnlock, "memu";
+    box_chars_1, box_chars_1_raw => BoxChars1, "box1";
+}
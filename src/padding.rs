@@ -0,0 +1,217 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Bits per pad character, ncurses assumes 7 data bits, parity and a stop bit
+const BITS_PER_CHAR: u64 = 9;
+
+/// Padding delay (`$<time*/>`) inside a capability string
+///
+/// See also [terminfo(4) - Delays and Padding](https://man.cx/terminfo(4)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    /// Delay in tenths of a millisecond
+    pub delay_tenths_ms: u32,
+    /// `*` - delay is proportional to the number of affected lines
+    pub proportional: bool,
+    /// `/` - delay is mandatory, even if the terminal uses xon/xoff
+    pub mandatory: bool,
+}
+
+impl Padding {
+    /// Returns the delay as Duration
+    ///
+    /// # Arguments
+    /// * `affected_lines` - multiplier for proportional delays
+    pub fn duration(&self, affected_lines: u32) -> Duration {
+        let tenths = match self.proportional {
+            true => self.delay_tenths_ms.saturating_mul(affected_lines.max(1)),
+            false => self.delay_tenths_ms,
+        };
+        Duration::from_micros(tenths as u64 * 100)
+    }
+
+    /// Returns the number of pad characters filling the delay at a baud rate, like ncurses
+    /// `tputs`
+    ///
+    /// # Arguments
+    /// * `baudrate`       - output speed in bits per second
+    /// * `affected_lines` - multiplier for proportional delays
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::padding::parse_padding;
+    ///
+    /// let (padding, _) = parse_padding(b"$<50>").unwrap();
+    /// assert_eq!(padding.pad_chars(9600, 1), 53);
+    /// assert_eq!(padding.pad_chars(300, 1), 1);
+    /// ```
+    pub fn pad_chars(&self, baudrate: u32, affected_lines: u32) -> u64 {
+        let tenths = self.duration(affected_lines).as_micros() as u64 / 100;
+        tenths * baudrate as u64 / (BITS_PER_CHAR * 10_000)
+    }
+
+    /// Returns the delay at a baud rate, the time to send the pad characters filling it
+    ///
+    /// Slow terminals only wait whole characters, the delay is shorter than `duration` by up to
+    /// one character time.
+    ///
+    /// # Arguments
+    /// * `baudrate`       - output speed in bits per second
+    /// * `affected_lines` - multiplier for proportional delays
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use cxterminfo::padding::parse_padding;
+    ///
+    /// let (padding, _) = parse_padding(b"$<50>").unwrap();
+    /// assert_eq!(padding.duration_at(9600, 1), Duration::from_micros(49_687));
+    /// assert_eq!(padding.duration_at(300, 1), Duration::from_millis(30));
+    ///
+    /// let (padding, _) = parse_padding(b"$<2*>").unwrap();
+    /// assert_eq!(padding.duration_at(1200, 24), Duration::from_millis(45));
+    /// ```
+    pub fn duration_at(&self, baudrate: u32, affected_lines: u32) -> Duration {
+        match baudrate {
+            0 => Duration::from_secs(0),
+            _ => {
+                let bits = self.pad_chars(baudrate, affected_lines) * BITS_PER_CHAR;
+                Duration::from_micros(bits * 1_000_000 / baudrate as u64)
+            }
+        }
+    }
+}
+
+impl Display for Padding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$<{}", self.delay_tenths_ms / 10)?;
+        if !self.delay_tenths_ms.is_multiple_of(10) {
+            write!(f, ".{}", self.delay_tenths_ms % 10)?;
+        }
+        if self.proportional {
+            write!(f, "*")?;
+        }
+        if self.mandatory {
+            write!(f, "/")?;
+        }
+        write!(f, ">")
+    }
+}
+
+/// Part of a capability string, split at padding delays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Bytes to send to the terminal
+    Output(&'a [u8]),
+    /// Padding delay
+    Delay(Padding),
+}
+
+/// Parse a padding delay at the start of data
+///
+/// Returns the padding and the number of bytes used or Option::None if data doesn't start with
+/// a valid `$<time*/>` sequence.
+///
+/// # Example
+/// ```
+/// use cxterminfo::padding::parse_padding;
+///
+/// let (padding, len) = parse_padding(b"$<2.5*/>rest").unwrap();
+/// assert_eq!(padding.delay_tenths_ms, 25);
+/// assert!(padding.proportional && padding.mandatory);
+/// assert_eq!(len, 8);
+///
+/// assert_eq!(parse_padding(b"$<>"), None);
+/// ```
+pub fn parse_padding(data: &[u8]) -> Option<(Padding, usize)> {
+    if data.len() < 4 || data[0] != b'$' || data[1] != b'<' {
+        return None;
+    }
+
+    let mut pos = 2;
+    let mut delay: u32 = 0;
+    let mut digits = 0;
+    while pos < data.len() && data[pos].is_ascii_digit() {
+        delay = delay.saturating_mul(10).saturating_add((data[pos] - b'0') as u32);
+        digits += 1;
+        pos += 1;
+    }
+
+    // one decimal place is significant, further digits are ignored
+    delay = delay.saturating_mul(10);
+    if pos < data.len() && data[pos] == b'.' {
+        pos += 1;
+        if pos < data.len() && data[pos].is_ascii_digit() {
+            delay = delay.saturating_add((data[pos] - b'0') as u32);
+            digits += 1;
+        }
+        while pos < data.len() && data[pos].is_ascii_digit() {
+            pos += 1;
+        }
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    let mut padding = Padding {
+        delay_tenths_ms: delay,
+        proportional: false,
+        mandatory: false,
+    };
+
+    while pos < data.len() {
+        match data[pos] {
+            b'*' if !padding.proportional => padding.proportional = true,
+            b'/' if !padding.mandatory => padding.mandatory = true,
+            b'>' => return Some((padding, pos + 1)),
+            _ => return None,
+        }
+        pos += 1;
+    }
+
+    None
+}
+
+/// Split a capability string into output and padding delays
+///
+/// # Example
+/// ```
+/// use cxterminfo::padding::{split_padding, Segment};
+///
+/// let segments = split_padding(b"\x1B[H\x1B[2J$<50>");
+/// assert_eq!(segments.len(), 2);
+/// assert_eq!(segments[0], Segment::Output(b"\x1B[H\x1B[2J"));
+/// ```
+pub fn split_padding(data: &[u8]) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut pos = 0;
+
+    while pos < data.len() {
+        if data[pos] == b'$' {
+            if let Some((padding, len)) = parse_padding(&data[pos..]) {
+                if start < pos {
+                    segments.push(Segment::Output(&data[start..pos]));
+                }
+                segments.push(Segment::Delay(padding));
+                pos += len;
+                start = pos;
+                continue;
+            }
+        }
+        pos += 1;
+    }
+
+    if start < data.len() {
+        segments.push(Segment::Output(&data[start..]));
+    }
+
+    segments
+}
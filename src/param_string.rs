@@ -4,40 +4,100 @@
 //  terms of the Do What The Fuck You Want To Public License, Version 2,
 //  as published by Sam Hocevar. See the COPYING file for more details.
 
+use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::error::Error;
-use std::ffi::CString;
-use std::fmt::{Debug, Display, Formatter};
+use std::fmt::{Debug, Display, Formatter, Write};
 
-use super::sprintf;
+use crate::padding::{parse_padding, Padding};
 
-#[derive(Clone)]
-pub enum Param {
+/// Parameter of a parameterized string
+///
+/// Strings can be borrowed, expanding a capability with a `&str` parameter doesn't copy it.
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, Param};
+///
+/// let title = "cx-terminfo";
+/// assert_eq!(evaluate("\x1B]2;%p1%s\x07", &[Param::from(title)]).unwrap(), "\x1B]2;cx-terminfo\x07");
+/// assert_eq!(evaluate("%p1%d;%p2%s", &[Param::from(5), Param::from(String::from("x"))]).unwrap(), "5;x");
+/// ```
+#[derive(Debug, Clone)]
+pub enum Param<'a> {
     /// Bool parameter, can be used as bool or int
     Bool(bool),
     /// Number parameter, can be used for int or bool values
     Number(i32),
-    /// Word parameter, only usable for strings
-    Word(String),
+    /// Word parameter, only usable for strings (0 in numeric and logical operations)
+    Word(Cow<'a, str>),
 }
 
-impl Param {
+impl<'a> Param<'a> {
+    /// Read a parameter from an environment variable, Option::None if the variable isn't set or
+    /// not unicode
+    ///
+    /// Integers are read as `Number`, `true` and `false` as `Bool`, everything else as `Word`.
+    ///
+    /// # Arguments
+    /// * `var` - name of the environment variable
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::param_string::{evaluate, Param};
+    ///
+    /// std::env::set_var("CXTERMINFO_ROW", "-12");
+    /// let row = Param::try_from_env("CXTERMINFO_ROW").unwrap();
+    /// assert_eq!(evaluate("%p1%d", &[row]).unwrap(), "-12");
+    ///
+    /// std::env::set_var("CXTERMINFO_TITLE", "vim");
+    /// let title = Param::try_from_env("CXTERMINFO_TITLE").unwrap();
+    /// assert_eq!(evaluate("%p1%s", &[title]).unwrap(), "vim");
+    ///
+    /// assert!(Param::try_from_env("CXTERMINFO_UNSET").is_none());
+    /// ```
+    pub fn try_from_env(var: &str) -> Option<Param<'a>> {
+        let value = std::env::var(var).ok()?;
+        Some(match value.as_str() {
+            "true" => Param::Bool(true),
+            "false" => Param::Bool(false),
+            number => match number.parse::<i32>() {
+                Ok(n) => Param::Number(n),
+                Err(_) => Param::from(value),
+            },
+        })
+    }
+
     fn as_str(&self) -> &str {
         match self {
-            Param::Word(s) => s.as_str(),
+            Param::Word(s) => s,
             _ => "",
         }
     }
 
+    /// Returns the parameter with an owned string
+    fn into_owned(self) -> Param<'static> {
+        match self {
+            Param::Bool(b) => Param::Bool(b),
+            Param::Number(n) => Param::Number(n),
+            Param::Word(s) => Param::Word(Cow::Owned(s.into_owned())),
+        }
+    }
+
+    /// Returns a copy which borrows the string of a `Word` parameter
+    fn borrowed(&self) -> Param<'_> {
+        match self {
+            Param::Bool(b) => Param::Bool(*b),
+            Param::Number(n) => Param::Number(*n),
+            Param::Word(s) => Param::Word(Cow::Borrowed(s)),
+        }
+    }
+
     fn as_int(&self) -> i32 {
         match self {
             Param::Number(n) => *n,
-            Param::Bool(b) => {
-                if *b {
-                    1
-                } else {
-                    0
-                }
-            }
+            Param::Bool(b) => i32::from(*b),
             _ => 0,
         }
     }
@@ -59,29 +119,80 @@ impl Param {
     }
 }
 
-impl Default for Param {
+impl<'a> Default for Param<'a> {
     fn default() -> Self {
         Param::Number(0)
     }
 }
 
-#[derive(Default)]
-struct Variables {
-    static_vars: [Param; 26],
-    dynamic_vars: [Param; 26],
+impl<'a> From<bool> for Param<'a> {
+    fn from(value: bool) -> Self {
+        Param::Bool(value)
+    }
+}
+
+impl<'a> From<i32> for Param<'a> {
+    fn from(value: i32) -> Self {
+        Param::Number(value)
+    }
+}
+
+impl<'a> From<&'a str> for Param<'a> {
+    fn from(value: &'a str) -> Self {
+        Param::Word(Cow::Borrowed(value))
+    }
 }
 
-impl Variables {
+impl<'a> From<String> for Param<'a> {
+    fn from(value: String) -> Self {
+        Param::Word(Cow::Owned(value))
+    }
+}
+
+/// Variables of parameterized strings, static `A` to `Z` and dynamic `a` to `z`
+///
+/// Used to start an `Evaluator` with a state, see `Evaluator::with_variables`.
+#[derive(Debug, Clone, Default)]
+pub struct Variables<'a> {
+    static_vars: [Param<'a>; 26],
+    dynamic_vars: [Param<'a>; 26],
+}
+
+impl<'a> Variables<'a> {
+    /// Create variables, all are 0
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Create variables with initial values of the static variables `A` to `Z`, the dynamic
+    /// variables are 0
+    ///
+    /// # Arguments
+    ///
+    /// * `static_vars` - values of `A` to `Z`
+    pub fn new_with_state(static_vars: [Param<'a>; 26]) -> Variables<'a> {
+        Variables {
+            static_vars,
+            dynamic_vars: Default::default(),
+        }
+    }
+
+    /// Returns the variable for a..z (dynamic) or A..Z (static)
+    fn get_mut(&mut self, name: char) -> &mut Param<'a> {
+        match name {
+            'A'..='Z' => &mut self.static_vars[(name as u8 - b'A') as usize],
+            _ => &mut self.dynamic_vars[(name as u8 - b'a') as usize],
+        }
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EvalError {
     StackEmpty(usize),
     Invalid(usize),
     InvalidPrintf(usize),
+    Overflow(usize),
+    ObsoleteCode(usize, char),
 }
 
 impl Display for EvalError {
@@ -90,12 +201,339 @@ impl Display for EvalError {
             EvalError::StackEmpty(pos) => write!(f, "Stack is empty ({})", pos),
             EvalError::Invalid(pos) => write!(f, "Invalid terminfo ({})", pos),
             EvalError::InvalidPrintf(pos) => write!(f, "Invalid printf format pattern ({})", pos),
+            EvalError::Overflow(pos) => write!(f, "Arithmetic overflow or division by zero ({})", pos),
+            EvalError::ObsoleteCode(pos, c) => write!(f, "Obsolete termcap code %{} ({})", c, pos),
         }
     }
 }
 
 impl Error for EvalError {}
 
+/// Arithmetic, bit and logical operators of parameterized strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `%+`
+    Add,
+    /// `%-`
+    Sub,
+    /// `%*`
+    Mul,
+    /// `%/`
+    Div,
+    /// `%m` - modulo
+    Mod,
+    /// `%&` - bit and
+    BitAnd,
+    /// `%|` - bit or
+    BitOr,
+    /// `%^` - bit exclusive or
+    BitXor,
+    /// `%=`
+    Eq,
+    /// `%>`
+    Gt,
+    /// `%<`
+    Lt,
+    /// `%A` - logical and
+    And,
+    /// `%O` - logical or
+    Or,
+    /// `%!` - logical not (unary)
+    Not,
+    /// `%~` - bit complement (unary)
+    BitNot,
+    /// `%B` - BCD encoding `(n / 10) * 16 + n % 10` (unary)
+    ///
+    /// Compatibility feature for legacy hardware terminals (e.g. old HP terminals).
+    Bcd,
+    /// `%D` - reverse coding `n - 2 * (n % 16)` (unary)
+    ///
+    /// Compatibility feature for legacy hardware terminals (e.g. Concept Delta Data).
+    ReverseCoding,
+}
+
+impl Operator {
+    /// Returns the character following `%`
+    pub fn as_char(&self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Sub => '-',
+            Operator::Mul => '*',
+            Operator::Div => '/',
+            Operator::Mod => 'm',
+            Operator::BitAnd => '&',
+            Operator::BitOr => '|',
+            Operator::BitXor => '^',
+            Operator::Eq => '=',
+            Operator::Gt => '>',
+            Operator::Lt => '<',
+            Operator::And => 'A',
+            Operator::Or => 'O',
+            Operator::Not => '!',
+            Operator::BitNot => '~',
+            Operator::Bcd => 'B',
+            Operator::ReverseCoding => 'D',
+        }
+    }
+
+    /// Returns true for operators with a single operand
+    pub fn is_unary(&self) -> bool {
+        matches!(self, Operator::Not | Operator::BitNot | Operator::Bcd | Operator::ReverseCoding)
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '+' => Operator::Add,
+            '-' => Operator::Sub,
+            '*' => Operator::Mul,
+            '/' => Operator::Div,
+            'm' => Operator::Mod,
+            '&' => Operator::BitAnd,
+            '|' => Operator::BitOr,
+            '^' => Operator::BitXor,
+            '=' => Operator::Eq,
+            '>' => Operator::Gt,
+            '<' => Operator::Lt,
+            'A' => Operator::And,
+            'O' => Operator::Or,
+            '!' => Operator::Not,
+            '~' => Operator::BitNot,
+            'B' => Operator::Bcd,
+            'D' => Operator::ReverseCoding,
+            _ => return None,
+        })
+    }
+}
+
+/// printf style output `%[[:]flags][width[.precision]][doxXs]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintfSpec {
+    /// Written with `:` (required for the `-` and `+` flags)
+    pub colon: bool,
+    /// `-` - left justify
+    pub left: bool,
+    /// `+` - always print the sign
+    pub sign: bool,
+    /// ` ` - print a space for positive numbers
+    pub space: bool,
+    /// `#` - alternate form, `0x` prefix for hex and leading `0` for octal numbers
+    pub alternate: bool,
+    /// `0` - pad with zeros
+    pub zero: bool,
+    /// Minimum field width
+    pub width: Option<usize>,
+    /// Minimum number of digits or maximum length of strings
+    pub precision: Option<usize>,
+    /// Conversion, one of `d`, `o`, `x`, `X` or `s`
+    pub conversion: char,
+}
+
+impl PrintfSpec {
+    /// Create a spec without flags, width and precision
+    pub fn new(conversion: char) -> Self {
+        PrintfSpec {
+            colon: false,
+            left: false,
+            sign: false,
+            space: false,
+            alternate: false,
+            zero: false,
+            width: None,
+            precision: None,
+            conversion,
+        }
+    }
+
+    /// Write a value formatted like C printf
+    fn write(&self, param: &Param, out: &mut dyn Output) {
+        let width = self.width.unwrap_or(0);
+
+        if self.conversion == 's' {
+            let text = param.as_str();
+            let text = match self.precision.and_then(|max| text.char_indices().nth(max)) {
+                Some((end, _)) => &text[..end],
+                None => text,
+            };
+            let fill = width.saturating_sub(text.chars().count());
+            if !self.left {
+                repeat(out, ' ', fill);
+            }
+            out.push_str(text);
+            if self.left {
+                repeat(out, ' ', fill);
+            }
+            return;
+        }
+
+        let n = param.as_int();
+        let mut prefix = "";
+        let mut digits = Digits::default();
+        let _ = match self.conversion {
+            'd' => {
+                prefix = if n < 0 {
+                    "-"
+                } else if self.sign {
+                    "+"
+                } else if self.space {
+                    " "
+                } else {
+                    ""
+                };
+                write!(digits, "{}", n.unsigned_abs())
+            }
+            'o' => write!(digits, "{:o}", n as u32),
+            'x' => write!(digits, "{:x}", n as u32),
+            _ => write!(digits, "{:X}", n as u32),
+        };
+
+        let mut digits = digits.as_str();
+        if self.precision == Some(0) && digits == "0" {
+            digits = "";
+        }
+        let mut zeros = self.precision.unwrap_or(0).saturating_sub(digits.len());
+
+        if self.alternate && n != 0 {
+            match self.conversion {
+                'o' if zeros == 0 && !digits.starts_with('0') => zeros = 1,
+                'x' => prefix = "0x",
+                'X' => prefix = "0X",
+                _ => {}
+            }
+        }
+
+        let fill = width.saturating_sub(prefix.len() + zeros + digits.len());
+        if self.left {
+            out.push_str(prefix);
+            repeat(out, '0', zeros);
+            out.push_str(digits);
+            repeat(out, ' ', fill);
+        } else if self.zero && self.precision.is_none() {
+            out.push_str(prefix);
+            repeat(out, '0', zeros + fill);
+            out.push_str(digits);
+        } else {
+            repeat(out, ' ', fill);
+            out.push_str(prefix);
+            repeat(out, '0', zeros);
+            out.push_str(digits);
+        }
+    }
+}
+
+/// Stack buffer for the digits of a printf number (at most 11 octal digits)
+#[derive(Default)]
+struct Digits {
+    buf: [u8; 11],
+    len: usize,
+}
+
+impl Digits {
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for Digits {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.buf.get_mut(self.len..end).ok_or(std::fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn repeat(out: &mut dyn Output, c: char, count: usize) {
+    for _ in 0..count {
+        out.push(c);
+    }
+}
+
+impl Display for PrintfSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "%")?;
+        for (set, c) in [(self.colon, ':'), (self.left, '-'), (self.sign, '+'), (self.space, ' '),
+                         (self.alternate, '#'), (self.zero, '0')].iter() {
+            if *set {
+                write!(f, "{}", c)?;
+            }
+        }
+        if let Some(width) = self.width {
+            write!(f, "{}", width)?;
+        }
+        if let Some(precision) = self.precision {
+            write!(f, ".{}", precision)?;
+        }
+        write!(f, "{}", self.conversion)
+    }
+}
+
+/// Part of a parameterized string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// Text which is copied to the output
+    Literal(String),
+    /// `%%` - outputs `%`
+    Percent,
+    /// `%c` - pop value and print it as char
+    Char,
+    /// `%d`, `%s`, `%x`, `%:-5d`, ... - pop value and print it formatted
+    Format(PrintfSpec),
+    /// `%p[1-9]` - push parameter (1-based)
+    Param(u8),
+    /// `%P[a-z]`, `%P[A-Z]` - pop value and store it in a dynamic or static variable
+    SetVar(char),
+    /// `%g[a-z]`, `%g[A-Z]` - push value of a dynamic or static variable
+    GetVar(char),
+    /// `%'c'` - push char constant
+    CharConstant(char),
+    /// `%{nn}` - push integer constant
+    Constant(i32),
+    /// `%l` - pop string and push its length
+    StrLen,
+    /// `%i` - add 1 to the first two parameters
+    Increment,
+    /// Arithmetic, bit or logical operation
+    Operator(Operator),
+    /// `%?` - start of a conditional
+    If,
+    /// `%t` - pop value and evaluate the then part if it's true
+    Then,
+    /// `%e` - start of the else part
+    Else,
+    /// `%;` - end of a conditional
+    EndIf,
+    /// `$<time*/>` - padding delay
+    Padding(Padding),
+    /// `%r`, `%n` - termcap codes (reverse and xor the first two parameters) left over in
+    /// converted entries, ignored by `evaluate`
+    Obsolete(char),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Literal(text) => write!(f, "{}", text),
+            Token::Percent => write!(f, "%%"),
+            Token::Char => write!(f, "%c"),
+            Token::Format(spec) => write!(f, "{}", spec),
+            Token::Param(n) => write!(f, "%p{}", n),
+            Token::SetVar(name) => write!(f, "%P{}", name),
+            Token::GetVar(name) => write!(f, "%g{}", name),
+            Token::CharConstant(c) => write!(f, "%'{}'", c),
+            Token::Constant(n) => write!(f, "%{{{}}}", n),
+            Token::StrLen => write!(f, "%l"),
+            Token::Increment => write!(f, "%i"),
+            Token::Operator(op) => write!(f, "%{}", op.as_char()),
+            Token::If => write!(f, "%?"),
+            Token::Then => write!(f, "%t"),
+            Token::Else => write!(f, "%e"),
+            Token::EndIf => write!(f, "%;"),
+            Token::Padding(padding) => write!(f, "{}", padding),
+            Token::Obsolete(c) => write!(f, "%{}", c),
+        }
+    }
+}
+
 /// Evaluate a parameterized string
 ///
 /// [https://man.cx/terminfo(4)]Parameterized strings Section 1-2
@@ -123,268 +561,1103 @@ impl Error for EvalError {}
 ///     }
 /// }
 /// ```
+///
+/// Logical operators (`%A`, `%O`, `%!`) use C truthiness and push exactly 0 or 1, `Word`
+/// parameters count as 0:
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, Param};
+///
+/// let params = |p: &[i32]| p.iter().map(|n| Param::Number(*n)).collect::<Vec<Param>>();
+///
+/// // sgr of vt100 compatible terminals (bold = standout | bold, reverse = standout | reverse)
+/// let sgr = "\x1B[0%?%p1%p6%O%t;1%;%?%p2%t;4%;%?%p4%t;5%;%?%p1%p3%O%t;7%;%?%p7%t;8%;m%?%p9%t\x0E%e\x0F%;";
+/// assert_eq!(evaluate(sgr, &params(&[1, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap(), "\x1B[0;1;7m\x0F");
+/// assert_eq!(evaluate(sgr, &params(&[0, 1, 0, 1, 0, 0, 0, 0, 1])).unwrap(), "\x1B[0;4;5m\x0E");
+/// assert_eq!(evaluate(sgr, &params(&[0, 0, 0, 0, 0, 2, 0, 0, 0])).unwrap(), "\x1B[0;1m\x0F");
+///
+/// // sgr of wyse terminals, attributes are added to '0' and printed with %c
+/// let sgr = "\x1BG%'0'%?%p1%p3%O%t%{4}%+%;%?%p2%t%{8}%+%;%?%p4%t%{2}%+%;%c";
+/// assert_eq!(evaluate(sgr, &params(&[1, 1, 0, 0])).unwrap(), "\x1BG<");
+/// assert_eq!(evaluate(sgr, &params(&[0, 0, 5, 1])).unwrap(), "\x1BG6");
+///
+/// // chained logic
+/// assert_eq!(evaluate("%p1%p2%A%p3%O%d", &params(&[7, 0, 0])).unwrap(), "0");
+/// assert_eq!(evaluate("%p1%p2%A%p3%O%d", &params(&[7, -1, 0])).unwrap(), "1");
+/// assert_eq!(evaluate("%p1%p2%>%p2%p3%<%A%!%d", &params(&[3, 2, 5])).unwrap(), "0");
+/// assert_eq!(evaluate("%?%p1%{1}%=%p2%{2}%=%A%tboth%e%p2%tsecond%eneither%;", &params(&[0, 2])).unwrap(), "second");
+/// assert_eq!(evaluate("%p1%p2%O%d", &[Param::from("on"), Param::Number(0)]).unwrap(), "0");
+/// ```
+///
+/// `%B` (BCD) and `%D` (reverse coding) are supported for legacy hardware terminals:
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, Param};
+///
+/// assert_eq!(evaluate("%p1%B%d", &[Param::Number(42)]).unwrap(), "66");
+/// assert_eq!(evaluate("%p1%B%c", &[Param::Number(79)]).unwrap(), "y");
+/// assert_eq!(evaluate("%p1%D%d", &[Param::Number(20)]).unwrap(), "12");
+/// ```
+///
+/// Malformed strings return an error with the position of the offending character, e.g. a
+/// `%{}` literal containing anything but digits:
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, EvalError};
+///
+/// assert_eq!(evaluate("%{1x}%d", &[]), Err(EvalError::Invalid(3)));
+/// assert_eq!(evaluate("%{-1}%d", &[]), Err(EvalError::Invalid(2)));
+/// assert_eq!(evaluate("%{}%d", &[]), Err(EvalError::Invalid(0)));
+/// assert_eq!(evaluate("%{12", &[]), Err(EvalError::Invalid(4)));
+/// ```
 pub fn evaluate(term: &str, params: &[Param]) -> Result<String, EvalError> {
-    let mut vars = Variables::new();
-    let mut stack: Vec<Param> = Vec::new();
-    let mut pos = 0;
-    let chars = Vec::from(term)
-        .iter()
-        .map(|c| *c as char)
-        .collect::<Vec<char>>();
-    __eval(&chars, params, &mut pos, &mut stack, &mut vars)
-}
-
-fn __eval(
-    chars: &Vec<char>,
-    params: &[Param],
-    pos: &mut usize,
-    stack: &mut Vec<Param>,
-    vars: &mut Variables,
-) -> Result<String, EvalError> {
-    let mut output: String = String::new();
-    let mut saw_if = false;
-
-    while *pos < chars.len() {
-        if chars[*pos] != '%' {
-            output.push(chars[*pos]);
-            *pos += 1;
-            continue;
+    __evaluate(term, params, &mut Variables::new(), false)
+}
+
+/// Evaluate a parameterized string in strict mode
+///
+/// Works like `evaluate`, but arithmetic overflow and division by zero return
+/// `EvalError::Overflow` instead of wrapping around and the termcap codes `%r` and `%n`
+/// return `EvalError::ObsoleteCode` instead of being ignored.
+///
+/// `%>` is always the terminfo comparison, the termcap meaning (`%>xy`) can't be told apart.
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, evaluate_strict, EvalError, Param};
+///
+/// let max = Param::Number(i32::MAX);
+/// let min = Param::Number(i32::MIN);
+/// let one = Param::Number(1);
+/// let minus_one = Param::Number(-1);
+///
+/// // wrapping by default
+/// assert_eq!(evaluate("%p1%p2%+%d", &[max.clone(), one.clone()]).unwrap(), i32::MIN.to_string());
+/// assert_eq!(evaluate("%p1%p2%-%d", &[min.clone(), one.clone()]).unwrap(), i32::MAX.to_string());
+/// assert_eq!(evaluate("%p1%{2}%*%d", &[max.clone()]).unwrap(), "-2");
+/// assert_eq!(evaluate("%p1%p2%/%d", &[min.clone(), minus_one.clone()]).unwrap(), i32::MIN.to_string());
+/// assert_eq!(evaluate("%p1%p2%m%d", &[min.clone(), minus_one.clone()]).unwrap(), "0");
+/// assert_eq!(evaluate("%p1%{0}%/%d", &[max.clone()]).unwrap(), "0");
+///
+/// // no overflow at the boundaries
+/// assert_eq!(evaluate_strict("%p1%{0}%+%d", &[max.clone()]).unwrap(), i32::MAX.to_string());
+/// assert_eq!(evaluate_strict("%p1%p2%-%d", &[max.clone(), max.clone()]).unwrap(), "0");
+///
+/// // overflow in strict mode
+/// assert!(matches!(evaluate_strict("%p1%p2%+%d", &[max.clone(), one.clone()]), Err(EvalError::Overflow(_))));
+/// assert!(matches!(evaluate_strict("%p1%p2%-%d", &[min.clone(), one.clone()]), Err(EvalError::Overflow(_))));
+/// assert!(matches!(evaluate_strict("%p1%{2}%*%d", &[max.clone()]), Err(EvalError::Overflow(_))));
+/// assert!(matches!(evaluate_strict("%p1%p2%/%d", &[min.clone(), minus_one.clone()]), Err(EvalError::Overflow(_))));
+/// assert!(matches!(evaluate_strict("%p1%p2%m%d", &[min.clone(), minus_one.clone()]), Err(EvalError::Overflow(_))));
+/// assert!(matches!(evaluate_strict("%p1%{0}%/%d", &[max.clone()]), Err(EvalError::Overflow(_))));
+///
+/// // cup of the HP 2621 converted from termcap with the `%r` left in
+/// let cup = "\x1B&a%r%p2%dc%p1%dY";
+/// let params = [Param::Number(3), Param::Number(5)];
+/// assert_eq!(evaluate(cup, &params).unwrap(), "\x1B&a5c3Y");
+/// assert_eq!(evaluate_strict(cup, &params), Err(EvalError::ObsoleteCode(3, 'r')));
+/// assert_eq!(evaluate_strict("%n%p1%d", &[]), Err(EvalError::ObsoleteCode(0, 'n')));
+/// ```
+pub fn evaluate_strict(term: &str, params: &[Param]) -> Result<String, EvalError> {
+    __evaluate(term, params, &mut Variables::new(), true)
+}
+
+/// Evaluate a parameterized string of raw bytes, e.g. read from a terminfo file
+///
+/// Works like `evaluate`, but every byte of term is a character and bytes 128 to 255 are
+/// copied to the output unchanged instead of becoming two byte UTF-8 sequences, as is the
+/// byte of `%c`. String parameters (`%s`) are written as UTF-8.
+///
+/// # Arguments
+///
+/// * `term` - parameterized string pattern
+/// * `params` - array of parameters
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{evaluate, evaluate_bytes, Param};
+///
+/// // 8-bit CSI
+/// let cup = b"\x9B%i%p1%d;%p2%dH";
+/// assert_eq!(evaluate_bytes(cup, &[Param::Number(4), Param::Number(9)]).unwrap(), b"\x9B5;10H");
+///
+/// assert_eq!(evaluate_bytes(b"%p1%c", &[Param::Number(0xE9)]).unwrap(), b"\xE9");
+/// assert_eq!(evaluate("%p1%c", &[Param::Number(0xE9)]).unwrap().as_bytes(), b"\xC3\xA9");
+/// assert_eq!(evaluate_bytes(b"%p1%s", &[Param::from("\u{E9}")]).unwrap(), b"\xC3\xA9");
+/// ```
+pub fn evaluate_bytes(term: &[u8], params: &[Param]) -> Result<Vec<u8>, EvalError> {
+    let chars = term.iter().map(|b| *b as char).collect::<Vec<char>>();
+    let tokens = tokenize(&chars)?;
+    let mut output = Vec::new();
+    __eval(&tokens, params, &mut Variables::new(), false, &mut output)?;
+    Ok(output)
+}
+
+/// Parse a parameterized string into tokens
+///
+/// The tokens can be printed with `Display` to get the original string back.
+///
+/// # Arguments
+///
+/// * `term` - parameterized string pattern
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{parse, Token};
+///
+/// let tokens = parse("\x1B[%i%p1%d;%p2%dH").unwrap();
+/// assert_eq!(tokens[0], Token::Literal("\x1B[".to_string()));
+/// assert_eq!(tokens[1], Token::Increment);
+/// assert_eq!(tokens[2], Token::Param(1));
+///
+/// // round trip of real capabilities
+/// for term in &[
+///     "\x1B[%i%p1%d;%p2%dH",
+///     "\x1B[%i%p1%d;%p2%dr",
+///     "\x1B[%?%p1%{8}%<%t3%p1%d%e%p1%{16}%<%t9%p1%{8}%-%d%e38;5;%p1%d%;m",
+///     "%?%p9%t\x1B(0%e\x1B(B%;\x1B[0%?%p6%t;1%;%?%p5%t;2%;%?%p2%t;4%;%?%p1%p3%|%t;7%;%?%p4%t;5%;%?%p7%t;8%;m",
+///     "\x1B[0%?%p1%p6%|%t;1%;%?%p2%t;4%;%?%p1%p3%|%t;7%;%?%p4%t;5%;m%?%p9%t\x0E%e\x0F%;$<2>",
+///     "\x1B]4;%p1%d;rgb:%p2%{255}%*%{1000}%/%2.2X/%p3%{255}%*%{1000}%/%2.2X/%p4%{255}%*%{1000}%/%2.2X\x1B\\",
+///     "\x1B]P%p1%x%p2%{255}%*%{1000}%/%02x%p3%{255}%*%{1000}%/%02x%p4%{255}%*%{1000}%/%02x",
+///     "\x1B]52;%p1%s;%p2%s\x07",
+///     "\x1BG%'0'%?%p1%p3%O%t%{4}%+%;%c",
+///     "\x1B[?5h$<100/>\x1B[?5l",
+///     "%p1%PA%gA%:-5d%%%l%~%!",
+///     "\x1B&a%p1%B%dY%p2%D%c",
+///     "\x1B&a%r%p2%dc%p1%dY",
+/// ] {
+///     let tokens = parse(term).unwrap();
+///     assert_eq!(tokens.iter().map(|t| t.to_string()).collect::<String>(), *term);
+/// }
+///
+/// assert!(parse("%p0").is_err());
+/// assert!(parse("%{1x}").is_err());
+/// ```
+pub fn parse(term: &str) -> Result<Vec<Token>, EvalError> {
+    let chars = term.chars().collect::<Vec<char>>();
+    Ok(tokenize(&chars)?.into_iter().map(|(_, token)| token).collect())
+}
+
+/// Check a parameterized string for syntax errors without evaluating it
+///
+/// All `%` codes are parsed, conditionals must be well-formed (`%?` ... `%t` ... `%e` ...
+/// `%;`, with `%e` ... `%t` for else-if chains) and every pop needs a value pushed before it,
+/// the branches of a conditional are checked separately. Values left on the stack are allowed
+/// like in ncurses.
+///
+/// # Arguments
+///
+/// * `term` - parameterized string pattern
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{validate, EvalError};
+///
+/// assert_eq!(validate("\x1B[%i%p1%d;%p2%dH"), Ok(()));
+/// assert_eq!(validate("\x1B[%?%p1%{8}%<%t3%p1%d%e%p1%{16}%<%t9%p1%{8}%-%d%e38;5;%p1%d%;m"), Ok(()));
+///
+/// // malformed code, missing operand, unbalanced conditionals
+/// assert_eq!(validate("%p1%q"), Err(EvalError::Invalid(3)));
+/// assert_eq!(validate("%p1%+%d"), Err(EvalError::StackEmpty(3)));
+/// assert_eq!(validate("%?%p1%t1%e0"), Err(EvalError::Invalid(11)));
+/// assert_eq!(validate("%p1%d%;"), Err(EvalError::Invalid(5)));
+/// assert_eq!(validate("%?%p1%e1%;"), Err(EvalError::Invalid(5)));
+/// ```
+pub fn validate(term: &str) -> Result<(), EvalError> {
+    /// Part of a conditional after `%?`, `%t` and `%e`
+    #[derive(PartialEq)]
+    enum Part {
+        Condition,
+        Then,
+        Else,
+    }
+
+    let chars = term.chars().collect::<Vec<char>>();
+    let mut depth = 0usize;
+    // open conditionals with the stack depth at the start of their current branch
+    let mut conditionals: Vec<(Part, usize)> = Vec::new();
+    for (pos, token) in tokenize(&chars)? {
+        let (pops, pushes) = match token {
+            Token::Param(_) | Token::GetVar(_) => (0, 1),
+            Token::CharConstant(_) | Token::Constant(_) => (0, 1),
+            Token::Char | Token::Format(_) | Token::SetVar(_) | Token::Then => (1, 0),
+            Token::StrLen => (1, 1),
+            Token::Operator(op) if op.is_unary() => (1, 1),
+            Token::Operator(_) => (2, 1),
+            _ => (0, 0),
+        };
+        depth = depth.checked_sub(pops).ok_or(EvalError::StackEmpty(pos))? + pushes;
+
+        match token {
+            Token::If => conditionals.push((Part::Condition, depth)),
+            Token::Then => match conditionals.last_mut() {
+                Some(part) if part.0 != Part::Then => *part = (Part::Then, depth),
+                _ => return Err(EvalError::Invalid(pos)),
+            },
+            Token::Else => match conditionals.last_mut() {
+                Some(part) if part.0 == Part::Then => {
+                    depth = part.1;
+                    part.0 = Part::Else;
+                }
+                _ => return Err(EvalError::Invalid(pos)),
+            },
+            Token::EndIf => match conditionals.pop() {
+                Some((Part::Then, _)) | Some((Part::Else, _)) => {}
+                _ => return Err(EvalError::Invalid(pos)),
+            },
+            _ => {}
         }
+    }
 
-        *pos += 1;
-        match chars[*pos] {
-            '%' => {
-                output.push('%');
+    match conditionals.is_empty() {
+        true => Ok(()),
+        false => Err(EvalError::Invalid(chars.len())),
+    }
+}
+
+/// Compiled parameterized string
+///
+/// Parses the string once, strings without parameters (like `sgr0` or `clear`) or strings which
+/// fold to a constant (like `%{27}%c`) are evaluated at compile time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamString {
+    tokens: Vec<(usize, Token)>,
+    constant: Option<(String, Measure)>,
+}
+
+/// Length and delay of an expanded parameterized string
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Measure {
+    /// Number of bytes of the output (padding delays are part of the output)
+    pub bytes: usize,
+    /// Sum of mandatory padding delays (`$<time/>`) in tenths of a millisecond
+    pub delay_tenths_ms: u32,
+}
+
+impl ParamString {
+    /// Compile a parameterized string
+    ///
+    /// # Arguments
+    ///
+    /// * `term` - parameterized string pattern
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use cxterminfo::param_string::{evaluate, Param, ParamString};
+    ///
+    /// let cup = ParamString::compile("\x1B[%i%p1%d;%p2%dH").unwrap();
+    /// assert!(!cup.is_constant());
+    /// assert_eq!(cup.eval(&[Param::Number(4), Param::Number(9)]).unwrap(), "\x1B[5;10H");
+    ///
+    /// // folded and interpreted output are the same
+    /// for term in &[
+    ///     "\x1B(B\x1B[m",
+    ///     "\x1B[H\x1B[2J",
+    ///     "\x1B[?1049h\x1B[22;0;0t",
+    ///     "\x1B[?5h$<100/>\x1B[?5l",
+    ///     "%{27}%c[0m",
+    ///     "%%%{1}%{2}%+%d",
+    ///     "%?%{1}%tyes%eno%;",
+    /// ] {
+    ///     let compiled = ParamString::compile(term).unwrap();
+    ///     assert!(compiled.is_constant());
+    ///     assert!(matches!(compiled.eval(&[]).unwrap(), Cow::Borrowed(_)));
+    ///     assert_eq!(compiled.eval(&[]).unwrap(), evaluate(term, &[]).unwrap());
+    /// }
+    /// ```
+    pub fn compile(term: &str) -> Result<Self, EvalError> {
+        let chars = term.chars().collect::<Vec<char>>();
+        let tokens = tokenize(&chars)?;
+
+        // output only depends on the tokens if no parameters or static variables are used
+        let foldable = tokens.iter().all(|(_, token)| match token {
+            Token::Param(_) | Token::Increment => false,
+            Token::SetVar(name) | Token::GetVar(name) => name.is_ascii_lowercase(),
+            _ => true,
+        });
+        let mut constant = None;
+        if foldable {
+            let mut output = String::new();
+            let mut measure = Measure::default();
+            if __eval(&tokens, &[], &mut Variables::new(), true, &mut output).is_ok() {
+                __eval(&tokens, &[], &mut Variables::new(), true, &mut measure)?;
+                constant = Some((output, measure));
             }
-            'c' => {
-                if let Some(param) = stack.pop() {
-                    output.push(param.as_char());
-                }
+        }
+
+        Ok(ParamString { tokens, constant })
+    }
+
+    /// Returns true if the output was computed at compile time
+    pub fn is_constant(&self) -> bool {
+        self.constant.is_some()
+    }
+
+    /// Returns the parsed tokens
+    pub fn tokens(&self) -> Vec<Token> {
+        self.tokens.iter().map(|(_, token)| token.clone()).collect()
+    }
+
+    /// Returns the highest parameter index referenced with `%p` (0 if no parameter is used)
+    ///
+    /// `%i` doesn't count as reference, it only changes parameters pushed with `%p1` and `%p2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cxterminfo::param_string::ParamString;
+    ///
+    /// let cup = ParamString::compile("\x1B[%i%p1%d;%p2%dH").unwrap();
+    /// assert_eq!(cup.params_used(), 2);
+    ///
+    /// let sgr = ParamString::compile("%?%p9%t\x1B(0%e\x1B(B%;\x1B[0%?%p6%t;1%;%?%p5%t;2%;%?%p2%t;4%;\
+    ///                                 %?%p1%p3%|%t;7%;%?%p4%t;5%;%?%p7%t;8%;m").unwrap();
+    /// assert_eq!(sgr.params_used(), 9);
+    /// assert_eq!(sgr.referenced_params(), vec![1, 2, 3, 4, 5, 6, 7, 9]);
+    ///
+    /// let hpa = ParamString::compile("\x1B[%i%p1%dG").unwrap();
+    /// assert_eq!(hpa.params_used(), 1);
+    ///
+    /// let sgr0 = ParamString::compile("\x1B(B\x1B[m").unwrap();
+    /// assert_eq!(sgr0.params_used(), 0);
+    /// ```
+    pub fn params_used(&self) -> u8 {
+        self.referenced_params().last().copied().unwrap_or(0)
+    }
+
+    /// Returns the parameter indices referenced with `%p`, sorted and without duplicates
+    ///
+    /// Parameters missing in this list are ignored by the string, e.g. `sgr` of xterm doesn't
+    /// use parameter 8 (protected).
+    pub fn referenced_params(&self) -> Vec<u8> {
+        let mut referenced = [false; 10];
+        for (_, token) in &self.tokens {
+            if let Token::Param(n) = token {
+                referenced[*n as usize] = true;
             }
-            's' => {
-                if let Some(param) = stack.pop() {
-                    output.push_str(param.as_str());
-                }
+        }
+
+        (1..10u8).filter(|n| referenced[*n as usize]).collect()
+    }
+
+    /// Evaluate the compiled string, constant strings are returned without evaluation
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - array of parameters
+    pub fn eval(&self, params: &[Param]) -> Result<Cow<'_, str>, EvalError> {
+        match self.constant {
+            Some((ref constant, _)) => Ok(Cow::Borrowed(constant.as_str())),
+            None => {
+                let mut output = String::new();
+                __eval(&self.tokens, params, &mut Variables::new(), false, &mut output)?;
+                Ok(Cow::Owned(output))
             }
-            'd' => {
-                if let Some(param) = stack.pop() {
-                    output.push_str(&param.as_int().to_string());
-                }
+        }
+    }
+
+    /// Evaluate the compiled string to bytes like `evaluate_bytes`, chars of the string are
+    /// written as single bytes and text of `%s` parameters as UTF-8
+    #[cfg(feature = "parser")]
+    pub(crate) fn eval_bytes(&self, params: &[Param]) -> Result<Vec<u8>, EvalError> {
+        let mut output = Vec::new();
+        match self.constant {
+            Some((ref constant, _)) => constant.chars().for_each(|c| Output::push(&mut output, c)),
+            None => __eval(&self.tokens, params, &mut Variables::new(), false, &mut output)?,
+        }
+        Ok(output)
+    }
+
+    /// Evaluate the compiled string into writer without building the output, padding delays
+    /// are skipped unless `keep_padding` is set
+    ///
+    /// The outer error is the evaluation error, the inner result the first error of writer.
+    #[cfg(feature = "parser")]
+    pub(crate) fn write_into(&self,
+                             params: &[Param],
+                             writer: &mut dyn Write,
+                             keep_padding: bool) -> Result<std::fmt::Result, EvalError> {
+        if let (Some((ref constant, _)), true) = (&self.constant, keep_padding) {
+            return Ok(writer.write_str(constant));
+        }
+
+        let mut output = WriterOutput { writer, keep_padding, result: Ok(()) };
+        __eval(&self.tokens, params, &mut Variables::new(), false, &mut output)?;
+        Ok(output.result)
+    }
+
+    /// Evaluate the compiled string into an `io::Write` without padding delays, chars of the
+    /// string are written as single bytes and text of `%s` parameters as UTF-8 like by
+    /// `eval_bytes`
+    ///
+    /// The outer error is the evaluation error, the inner result the first error of writer. An
+    /// error of writer is returned first.
+    #[cfg(feature = "parser")]
+    pub(crate) fn write_bytes_into(&self,
+                                   params: &[Param],
+                                   writer: &mut dyn std::io::Write)
+                                   -> Result<std::io::Result<()>, EvalError> {
+        let mut output = IoOutput { writer, result: Ok(()) };
+        let evaluated = __eval(&self.tokens, params, &mut Variables::new(), false, &mut output);
+        match output.result {
+            Err(err) => Ok(Err(err)),
+            Ok(()) => evaluated.map(Ok),
+        }
+    }
+
+    /// Returns the number of bytes and the mandatory padding delay of the output without
+    /// building it
+    ///
+    /// The byte count is the same as the length of the output of `eval`.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - array of parameters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cxterminfo::param_string::{Measure, Param, ParamString};
+    ///
+    /// let cup = ParamString::compile("\x1B[%i%p1%d;%p2%dH").unwrap();
+    /// let measure = cup.measure(&[Param::Number(23), Param::Number(79)]).unwrap();
+    /// assert_eq!(measure, Measure { bytes: 8, delay_tenths_ms: 0 });
+    ///
+    /// let flash = ParamString::compile("\x1B[?5h$<100/>\x1B[?5l").unwrap();
+    /// assert_eq!(flash.measure(&[]).unwrap().delay_tenths_ms, 1000);
+    ///
+    /// let padded = ParamString::compile("\x1B[%p1%dL$<5*>$<2/>").unwrap();
+    /// let params = [Param::Number(3)];
+    /// let measure = padded.measure(&params).unwrap();
+    /// assert_eq!(measure.bytes, padded.eval(&params).unwrap().len());
+    /// assert_eq!(measure.delay_tenths_ms, 20);
+    /// ```
+    pub fn measure(&self, params: &[Param]) -> Result<Measure, EvalError> {
+        match self.constant {
+            Some((_, measure)) => Ok(measure),
+            None => {
+                let mut measure = Measure::default();
+                __eval(&self.tokens, params, &mut Variables::new(), false, &mut measure)?;
+                Ok(measure)
             }
-            'p' => {
-                *pos += 1;
-                debug_assert!(CHAR_BETWEEN(chars[*pos], '0', '9'));
-                stack.push(params[CHAR_SUB(chars[*pos], '1') as usize].clone());
+        }
+    }
+}
+
+/// Parameterized string literal validated at compile time
+///
+/// Created by the `param_str!` macro of the `cxterminfo-macros` crate, which runs
+/// `ParamString::compile` at build time and fails the build for malformed strings.
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{Param, StaticParamString};
+///
+/// // what `param_str!("\x1B[%i%p1%d;%p2%dH")` expands to
+/// const CUP: StaticParamString = StaticParamString::new_unchecked("\x1B[%i%p1%d;%p2%dH");
+/// assert_eq!(CUP.eval(&[Param::Number(0), Param::Number(0)]).unwrap(), "\x1B[1;1H");
+/// assert_eq!(CUP.as_str(), "\x1B[%i%p1%d;%p2%dH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticParamString(&'static str);
+
+impl StaticParamString {
+    /// Wrap a string without validation, `term` must compile with `ParamString::compile`
+    pub const fn new_unchecked(term: &'static str) -> Self {
+        StaticParamString(term)
+    }
+
+    /// Returns the parameterized string
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Compile the string, can't fail for strings created by `param_str!`
+    pub fn compile(&self) -> ParamString {
+        ParamString::compile(self.0).expect("param_str! validated an invalid string")
+    }
+
+    /// Evaluate the string
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - array of parameters
+    pub fn eval(&self, params: &[Param]) -> Result<String, EvalError> {
+        evaluate(self.0, params)
+    }
+}
+
+/// Evaluator keeping the static variables (`A` to `Z`) between evaluations
+///
+/// `evaluate` uses fresh variables for every call. Some terminals store state in static
+/// variables with `%P` and read it with `%g` in another capability, ncurses keeps them between
+/// `tparm` calls for this. Dynamic variables (`a` to `z`) are cleared on every call, like
+/// ncurses does.
+///
+/// # Example
+///
+/// ```rust
+/// use cxterminfo::param_string::{Evaluator, Param};
+///
+/// let mut evaluator = Evaluator::new();
+/// assert_eq!(evaluator.evaluate("%p1%PA%p1%Pa", &[Param::Number(7)]).unwrap(), "");
+/// assert_eq!(evaluator.evaluate("%gA%d,%ga%d", &[]).unwrap(), "7,0");
+///
+/// evaluator.reset_static_vars();
+/// assert_eq!(evaluator.evaluate("%gA%d", &[]).unwrap(), "0");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Evaluator {
+    vars: Variables<'static>,
+}
+
+impl Evaluator {
+    /// Create an evaluator, all static variables are 0
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create an evaluator starting with the given variables
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - initial variables, e.g. from `Variables::new_with_state`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cxterminfo::param_string::{Evaluator, Param, Variables};
+    ///
+    /// let mut static_vars: [Param<'static>; 26] = Default::default();
+    /// static_vars[1] = Param::from("on");
+    /// let mut evaluator = Evaluator::with_variables(Variables::new_with_state(static_vars));
+    /// assert_eq!(evaluator.evaluate("%gB%s", &[]).unwrap(), "on");
+    /// ```
+    pub fn with_variables(vars: Variables<'static>) -> Self {
+        Evaluator { vars }
+    }
+
+    /// Evaluate a parameterized string like `evaluate`, static variables set by the string
+    /// are kept for the next call
+    ///
+    /// Variables set before an error are kept as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `term` - parameterized string pattern
+    /// * `params` - array of parameters
+    pub fn evaluate(&mut self, term: &str, params: &[Param]) -> Result<String, EvalError> {
+        let mut vars = Variables::new_with_state(self.vars.static_vars.clone());
+        let result = __evaluate(term, params, &mut vars, false);
+        self.vars = Variables::new_with_state(vars.static_vars.map(Param::into_owned));
+        result
+    }
+
+    /// Set all static variables to 0
+    pub fn reset_static_vars(&mut self) {
+        self.vars = Variables::new();
+    }
+}
+
+fn __evaluate<'a>(term: &str,
+                  params: &'a [Param],
+                  vars: &mut Variables<'a>,
+                  strict: bool) -> Result<String, EvalError> {
+    let chars = term.chars().collect::<Vec<char>>();
+    let tokens = tokenize(&chars)?;
+    let mut output = String::new();
+    __eval(&tokens, params, vars, strict, &mut output)?;
+    Ok(output)
+}
+
+/// Receives the output of the interpreter
+trait Output {
+    /// Text of the parameterized string itself
+    fn push_literal(&mut self, text: &str) {
+        self.push_str(text);
+    }
+    fn push_str(&mut self, text: &str);
+    fn push(&mut self, c: char);
+    fn push_padding(&mut self, padding: &Padding);
+}
+
+impl Output for String {
+    fn push_str(&mut self, text: &str) {
+        String::push_str(self, text);
+    }
+
+    fn push(&mut self, c: char) {
+        String::push(self, c);
+    }
+
+    fn push_padding(&mut self, padding: &Padding) {
+        let _ = write!(self, "{}", padding);
+    }
+}
+
+/// Output of `evaluate_bytes`, literals are chars made from bytes and are written as bytes
+impl Output for Vec<u8> {
+    fn push_literal(&mut self, text: &str) {
+        self.extend(text.chars().map(|c| c as u8));
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.extend_from_slice(text.as_bytes());
+    }
+
+    fn push(&mut self, c: char) {
+        match u8::try_from(c) {
+            Ok(b) => Vec::push(self, b),
+            Err(_) => self.push_str(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    fn push_padding(&mut self, padding: &Padding) {
+        self.extend(padding.to_string().into_bytes());
+    }
+}
+
+/// Output which only counts bytes and mandatory delays
+impl Output for Measure {
+    fn push_str(&mut self, text: &str) {
+        self.bytes += text.len();
+    }
+
+    fn push(&mut self, c: char) {
+        self.bytes += c.len_utf8();
+    }
+
+    fn push_padding(&mut self, padding: &Padding) {
+        let mut count = ByteCount(0);
+        let _ = write!(count, "{}", padding);
+        self.bytes += count.0;
+        if padding.mandatory {
+            self.delay_tenths_ms = self.delay_tenths_ms.saturating_add(padding.delay_tenths_ms);
+        }
+    }
+}
+
+/// Output writing to a formatter, writing stops at the first error
+#[cfg(feature = "parser")]
+struct WriterOutput<'w> {
+    writer: &'w mut dyn Write,
+    keep_padding: bool,
+    result: std::fmt::Result,
+}
+
+#[cfg(feature = "parser")]
+impl<'w> Output for WriterOutput<'w> {
+    fn push_str(&mut self, text: &str) {
+        if self.result.is_ok() {
+            self.result = self.writer.write_str(text);
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        if self.result.is_ok() {
+            self.result = self.writer.write_char(c);
+        }
+    }
+
+    fn push_padding(&mut self, padding: &Padding) {
+        if self.keep_padding && self.result.is_ok() {
+            self.result = write!(self.writer, "{}", padding);
+        }
+    }
+}
+
+/// Output writing bytes to an `io::Write` like `Vec<u8>`, padding delays are skipped and
+/// writing stops at the first error
+#[cfg(feature = "parser")]
+struct IoOutput<'w> {
+    writer: &'w mut dyn std::io::Write,
+    result: std::io::Result<()>,
+}
+
+#[cfg(feature = "parser")]
+impl<'w> IoOutput<'w> {
+    fn write_all(&mut self, bytes: &[u8]) {
+        if self.result.is_ok() {
+            self.result = self.writer.write_all(bytes);
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl<'w> Output for IoOutput<'w> {
+    fn push_literal(&mut self, text: &str) {
+        match text.is_ascii() {
+            true => self.write_all(text.as_bytes()),
+            false => self.write_all(&text.chars().map(|c| c as u8).collect::<Vec<u8>>()),
+        }
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.write_all(text.as_bytes());
+    }
+
+    fn push(&mut self, c: char) {
+        match u8::try_from(c) {
+            Ok(b) => self.write_all(&[b]),
+            Err(_) => self.push_str(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    fn push_padding(&mut self, _padding: &Padding) {}
+}
+
+/// Counts the bytes written with `write!`
+struct ByteCount(usize);
+
+impl Write for ByteCount {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Split chars into tokens, each token with the position where it starts
+fn tokenize(chars: &[char]) -> Result<Vec<(usize, Token)>, EvalError> {
+    let mut tokens: Vec<(usize, Token)> = Vec::new();
+    let mut literal = String::new();
+    let mut literal_start = 0;
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        if chars[pos] == '$' {
+            if let Some((padding, len)) = padding_at(chars, pos) {
+                push_literal(&mut tokens, &mut literal, literal_start);
+                tokens.push((pos, Token::Padding(padding)));
+                pos += len;
+                continue;
             }
-            'l' => {
-                if let Some(param) = stack.pop() {
-                    stack.push(Param::Number(param.as_str().len() as i32))
+        }
+
+        if chars[pos] != '%' {
+            if literal.is_empty() {
+                literal_start = pos;
+            }
+            literal.push(chars[pos]);
+            pos += 1;
+            continue;
+        }
+
+        push_literal(&mut tokens, &mut literal, literal_start);
+        let start = pos;
+        pos += 1;
+        let c = *chars.get(pos).ok_or(EvalError::Invalid(start))?;
+        let token = match c {
+            '%' => Token::Percent,
+            'c' => Token::Char,
+            'l' => Token::StrLen,
+            'i' => Token::Increment,
+            '?' => Token::If,
+            't' => Token::Then,
+            'e' => Token::Else,
+            ';' => Token::EndIf,
+            'r' | 'n' => Token::Obsolete(c),
+            'p' => {
+                pos += 1;
+                match chars.get(pos) {
+                    Some(n @ '1'..='9') => Token::Param(*n as u8 - b'0'),
+                    _ => return Err(EvalError::Invalid(start)),
                 }
             }
-            '{' => {
-                *pos += 1;
-                let mut lit = 0;
-                while chars[*pos] != '}' {
-                    debug_assert!(CHAR_BETWEEN(chars[*pos], '0', '9'));
-                    lit = (lit * 10) + CHAR_SUB(chars[*pos], '0');
-                    *pos += 1;
+            'P' | 'g' => {
+                pos += 1;
+                match chars.get(pos) {
+                    Some(name) if name.is_ascii_alphabetic() && c == 'P' => Token::SetVar(*name),
+                    Some(name) if name.is_ascii_alphabetic() => Token::GetVar(*name),
+                    _ => return Err(EvalError::Invalid(start)),
                 }
-
-                stack.push(Param::Number(lit as i32))
             }
             '\'' => {
-                stack.push(Param::Number(chars[*pos + 1] as i32));
-                debug_assert!(chars[*pos + 2] == '\'');
-                *pos += 2;
-            }
-            'P' | 'g' => {
-                *pos += 1;
-                debug_assert!(
-                    CHAR_BETWEEN(chars[*pos], 'A', 'Z') || CHAR_BETWEEN(chars[*pos], 'a', 'z')
-                );
-                let is_static = CHAR_BETWEEN(chars[*pos], 'A', 'Z');
-                let idx = if is_static {
-                    CHAR_SUB(chars[*pos], 'A')
-                } else {
-                    CHAR_SUB(chars[*pos], 'a')
-                } as usize;
-
-                match chars[*pos - 1] == 'P' {
-                    true => {
-                        // P = pop value
-                        match is_static {
-                            true => vars.static_vars[idx] = stack.pop().unwrap(),
-                            false => vars.dynamic_vars[idx] = stack.pop().unwrap(),
-                        }
+                match (chars.get(pos + 1), chars.get(pos + 2)) {
+                    (Some(value), Some('\'')) => {
+                        pos += 2;
+                        Token::CharConstant(*value)
                     }
-                    false => {
-                        // g = push value
-                        match is_static {
-                            true => stack.push(vars.static_vars[idx].clone()),
-                            false => stack.push(vars.dynamic_vars[idx].clone()),
+                    _ => return Err(EvalError::Invalid(start)),
+                }
+            }
+            '{' => {
+                pos += 1;
+                let mut lit: i32 = 0;
+                let mut digits = 0;
+                while chars.get(pos) != Some(&'}') {
+                    match chars.get(pos) {
+                        Some(d) if d.is_ascii_digit() => {
+                            lit = lit.wrapping_mul(10).wrapping_add(*d as i32 - '0' as i32);
                         }
+                        _ => return Err(EvalError::Invalid(pos)),
                     }
+                    digits += 1;
+                    pos += 1;
                 }
-            }
-            // Unary operatioin
-            '!' | '~' => {
-                if let Some(val) = stack.pop() {
-                    stack.push(if chars[*pos] == '!' {
-                        Param::Number(match !val.as_bool() {
-                            true => 1,
-                            false => 0,
-                        })
-                    } else {
-                        Param::Number(!val.as_int())
-                    });
+
+                if digits == 0 {
+                    return Err(EvalError::Invalid(start));
                 }
+                Token::Constant(lit)
             }
-            // Binary operations
-            '+' | '-' | '*' | '/' | 'm' | '^' | '&' | '|' | '=' | '>' | '<' | 'A' | 'O' => {
-                if let (Some(second), Some(first)) = (stack.pop(), stack.pop()) {
-                    let fi = first.as_int();
-                    let si = second.as_int();
-                    stack.push(Param::Number(match chars[*pos] {
-                        '+' => (fi + si),
-                        '-' => (fi - si),
-                        '*' => (fi * si),
-                        '/' => (fi / si),
-                        'm' => (fi % si),
-                        '^' => (fi ^ si),
-                        '&' => (fi & si),
-                        '|' => (fi | si),
-                        '=' => {
-                            if fi == si {
-                                1
-                            } else {
-                                0
-                            }
-                        }
-                        '>' => {
-                            if fi > si {
-                                1
-                            } else {
-                                0
-                            }
-                        }
-                        '<' => {
-                            if fi < si {
-                                1
-                            } else {
-                                0
-                            }
-                        }
-                        'A' => {
-                            if first.as_bool() && second.as_bool() {
-                                1
-                            } else {
-                                0
-                            }
-                        }
-                        'O' => {
-                            if first.as_bool() || second.as_bool() {
-                                1
-                            } else {
-                                0
-                            }
-                        }
-                        _ => 0,
-                    }));
+            _ => {
+                if let Some(op) = Operator::from_char(c) {
+                    Token::Operator(op)
+                } else if c == ':' || " #.0123456789doxXs".contains(c) {
+                    let (spec, end) = parse_printf(chars, pos).ok_or(EvalError::InvalidPrintf(start))?;
+                    pos = end;
+                    Token::Format(spec)
+                } else {
+                    return Err(EvalError::Invalid(start));
                 }
             }
-            '?' => {
-                saw_if = true;
-            }
-            't' => {
-                let result = if let Some(x) = stack.pop() {
-                    x.as_bool()
-                } else {
-                    return Err(EvalError::StackEmpty(*pos));
-                };
-                *pos += 1;
+        };
 
-                let then_res = __eval(chars, params, pos, stack, vars)?;
-                if result {
-                    output.push_str(then_res.as_str());
-                }
+        tokens.push((start, token));
+        pos += 1;
+    }
 
-                debug_assert!(chars[*pos] == 'e' || chars[*pos] == ';');
-                if let Some(is_else) = stack.pop() {
-                    if !is_else.as_bool() {
-                        *pos += 1;
-                        let else_res = __eval(chars, params, pos, stack, vars)?;
-                        if !result {
-                            output.push_str(else_res.as_str());
-                        }
+    push_literal(&mut tokens, &mut literal, literal_start);
+    Ok(tokens)
+}
 
-                        if let Some(done_check) = stack.pop() {
-                            if !done_check.as_bool() {
-                                return Err(EvalError::Invalid(*pos));
-                            }
-                        }
-                    }
-                } else {
-                    return Err(EvalError::Invalid(*pos));
-                }
+fn push_literal(tokens: &mut Vec<(usize, Token)>, literal: &mut String, start: usize) {
+    if !literal.is_empty() {
+        tokens.push((start, Token::Literal(std::mem::take(literal))));
+    }
+}
 
-                if saw_if {
-                    stack.push(Param::Number(1));
-                    return Ok(output);
-                }
+/// Parse padding at pos, returns the padding and the number of chars used
+fn padding_at(chars: &[char], pos: usize) -> Option<(Padding, usize)> {
+    let end = chars[pos..].iter().take_while(|c| c.is_ascii()).position(|c| *c == '>')?;
+    let bytes = chars[pos..=pos + end].iter().map(|c| *c as u8).collect::<Vec<u8>>();
+    match parse_padding(&bytes) {
+        Some((padding, len)) if len == bytes.len() => Some((padding, len)),
+        _ => None,
+    }
+}
 
-                saw_if = false;
-            }
-            ';' | 'e' => {
-                stack.push(Param::Number(match chars[*pos] == ';' {
-                    true => 1,
-                    false => 0,
-                }));
-                return Ok(output);
-            }
-            _ => {
-                if [
-                    'o', 'X', 'x', ':', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-                ]
-                    .contains(&chars[*pos])
-                {
-                    let mut printf_end = *pos;
-                    while printf_end < chars.len() {
-                        printf_end += 1;
-                        if ['d', 'o', 'x', 'X', 's'].contains(&chars[printf_end]) {
-                            break;
-                        }
-                    }
+/// Parse printf flags, width, precision and conversion starting at pos (after `%`)
+///
+/// Returns the spec and the position of the conversion char.
+fn parse_printf(chars: &[char], mut pos: usize) -> Option<(PrintfSpec, usize)> {
+    let mut spec = PrintfSpec::new('d');
+    if chars.get(pos) == Some(&':') {
+        spec.colon = true;
+        pos += 1;
+    }
 
-                    if printf_end >= chars.len() {
-                        return Err(EvalError::Invalid(*pos));
-                    }
+    loop {
+        match chars.get(pos) {
+            Some('-') => spec.left = true,
+            Some('+') => spec.sign = true,
+            Some(' ') => spec.space = true,
+            Some('#') => spec.alternate = true,
+            Some('0') => spec.zero = true,
+            _ => break,
+        }
+        pos += 1;
+    }
 
-                    let printf_fmt = chars[*pos - 1..printf_end].iter().collect::<String>();
-                    if let Some(a) = stack.pop() {
-                        let printf_res = match a {
-                            Param::Bool(_) | Param::Number(_) => sprintf!(printf_fmt, a.as_int()),
-                            Param::Word(_) => sprintf!(printf_fmt, CString::new(a.as_str())),
-                        };
+    spec.width = parse_number(chars, &mut pos);
+    if chars.get(pos) == Some(&'.') {
+        pos += 1;
+        spec.precision = Some(parse_number(chars, &mut pos).unwrap_or(0));
+    }
 
-                        match printf_res {
-                            Ok(res_str) => output.push_str(res_str.as_str()),
-                            Err(_) => return Err(EvalError::InvalidPrintf(*pos)),
-                        }
+    match chars.get(pos) {
+        Some(c @ 'd') | Some(c @ 'o') | Some(c @ 'x') | Some(c @ 'X') | Some(c @ 's') => {
+            spec.conversion = *c;
+            Some((spec, pos))
+        }
+        _ => None,
+    }
+}
+
+fn parse_number(chars: &[char], pos: &mut usize) -> Option<usize> {
+    let mut number: Option<usize> = None;
+    while let Some(d) = chars.get(*pos).filter(|c| c.is_ascii_digit()) {
+        number = Some(number.unwrap_or(0).saturating_mul(10).saturating_add(*d as usize - '0' as usize));
+        *pos += 1;
+    }
+    number
+}
+
+/// Returns parameter n (1-based), missing parameters are 0
+fn get_param<'a>(params: &'a [Param], n: u8, increments: i32) -> Param<'a> {
+    let param = params.get(n as usize - 1).map(Param::borrowed).unwrap_or_default();
+    match param {
+        Param::Word(_) => param,
+        _ if n <= 2 && increments > 0 => Param::Number(param.as_int().wrapping_add(increments)),
+        _ => param,
+    }
+}
+
+fn __eval<'a>(
+    tokens: &[(usize, Token)],
+    params: &'a [Param],
+    vars: &mut Variables<'a>,
+    strict: bool,
+    output: &mut dyn Output,
+) -> Result<(), EvalError> {
+    let mut stack: Vec<Param<'a>> = Vec::new();
+    let mut increments = 0;
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let (pos, ref token) = tokens[idx];
+        match token {
+            Token::Literal(text) => output.push_literal(text),
+            Token::Percent => output.push('%'),
+            Token::Char => {
+                if let Some(param) = stack.pop() {
+                    output.push(param.as_char());
+                }
+            }
+            Token::Format(spec) => {
+                if let Some(param) = stack.pop() {
+                    spec.write(&param, output);
+                }
+            }
+            Token::Param(n) => stack.push(get_param(params, *n, increments)),
+            Token::SetVar(name) => *vars.get_mut(*name) = stack.pop().unwrap_or_default(),
+            Token::GetVar(name) => stack.push(vars.get_mut(*name).clone()),
+            Token::CharConstant(c) => stack.push(Param::Number(*c as i32)),
+            Token::Constant(n) => stack.push(Param::Number(*n)),
+            Token::StrLen => {
+                if let Some(param) = stack.pop() {
+                    stack.push(Param::Number(param.as_str().len() as i32))
+                }
+            }
+            Token::Increment => increments += 1,
+            Token::Operator(op) if op.is_unary() => {
+                if let Some(val) = stack.pop() {
+                    stack.push(Param::Number(match op {
+                        Operator::Not => i32::from(!val.as_bool()),
+                        Operator::BitNot => !val.as_int(),
+                        _ => legacy_coding(*op, val.as_int(), strict).ok_or(EvalError::Overflow(pos))?,
+                    }));
+                }
+            }
+            Token::Operator(op) => {
+                // missing operands are 0, like ncurses
+                let second = stack.pop().unwrap_or_default();
+                let first = stack.pop().unwrap_or_default();
+                let fi = first.as_int();
+                let si = second.as_int();
+                stack.push(Param::Number(match op {
+                    Operator::Add | Operator::Sub | Operator::Mul | Operator::Div | Operator::Mod => {
+                        arithmetic(*op, fi, si, strict).ok_or(EvalError::Overflow(pos))?
                     }
+                    Operator::BitXor => fi ^ si,
+                    Operator::BitAnd => fi & si,
+                    Operator::BitOr => fi | si,
+                    Operator::Eq => i32::from(fi == si),
+                    Operator::Gt => i32::from(fi > si),
+                    Operator::Lt => i32::from(fi < si),
+                    Operator::And => i32::from(first.as_bool() && second.as_bool()),
+                    Operator::Or => i32::from(first.as_bool() || second.as_bool()),
+                    _ => 0,
+                }));
+            }
+            Token::If | Token::EndIf => {}
+            Token::Then => {
+                let result = match stack.pop() {
+                    Some(x) => x.as_bool(),
+                    None => return Err(EvalError::StackEmpty(pos)),
+                };
+
+                if !result {
+                    // continue with the else part or after the end of the conditional
+                    idx = skip_conditional(tokens, idx + 1, true);
+                    continue;
                 }
             }
+            Token::Else => {
+                // end of the then part, continue after the end of the conditional
+                idx = skip_conditional(tokens, idx + 1, false);
+                continue;
+            }
+            Token::Padding(padding) => output.push_padding(padding),
+            Token::Obsolete(c) if strict => return Err(EvalError::ObsoleteCode(pos, *c)),
+            Token::Obsolete(_) => {}
         }
 
-        *pos += 1;
+        idx += 1;
     }
 
-    stack.push(Param::Number(1));
-    Ok(output)
+    Ok(())
+}
+
+/// Find the end of a conditional part
+///
+/// Returns the index after the matching `%;` (or `%e` if `stop_at_else` is set) at the same
+/// nesting level, or the end of tokens if the conditional is not terminated.
+fn skip_conditional(tokens: &[(usize, Token)], start: usize, stop_at_else: bool) -> usize {
+    let mut level = 0;
+
+    for (idx, (_, token)) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::If => level += 1,
+            Token::EndIf if level == 0 => return idx + 1,
+            Token::EndIf => level -= 1,
+            Token::Else if level == 0 && stop_at_else => return idx + 1,
+            _ => {}
+        }
+    }
+
+    tokens.len()
+}
+
+/// Integer arithmetic with the same result in debug and release builds
+///
+/// Wraps around like C and treats division by zero as 0 (like ncurses). In strict mode
+/// overflow and division by zero return Option::None.
+fn arithmetic(op: Operator, a: i32, b: i32, strict: bool) -> Option<i32> {
+    let checked = match op {
+        Operator::Add => a.checked_add(b),
+        Operator::Sub => a.checked_sub(b),
+        Operator::Mul => a.checked_mul(b),
+        Operator::Div => a.checked_div(b),
+        Operator::Mod => a.checked_rem(b),
+        _ => None,
+    };
+
+    match (checked, strict) {
+        (Some(n), _) => Some(n),
+        (None, true) => None,
+        (None, false) => Some(match op {
+            Operator::Add => a.wrapping_add(b),
+            Operator::Sub => a.wrapping_sub(b),
+            Operator::Mul => a.wrapping_mul(b),
+            Operator::Div | Operator::Mod if b == 0 => 0,
+            Operator::Div => a.wrapping_div(b),
+            _ => a.wrapping_rem(b),
+        }),
+    }
 }
 
-// Some helper functions for working with chars
-static CHAR_SUB: fn(char, char) -> u32 = |a: char, b: char| (a as u32) - (b as u32);
-static CHAR_LE: fn(char, char) -> bool = |a: char, b: char| (a as u32) <= (b as u32);
-static CHAR_GE: fn(char, char) -> bool = |a: char, b: char| (a as u32) >= (b as u32);
-static CHAR_BETWEEN: fn(char, char, char) -> bool =
-    |a: char, b: char, c: char| CHAR_GE(a, b) && CHAR_LE(a, c);
+/// BCD (`%B`) and reverse coding (`%D`) of legacy terminals, overflow is handled like in
+/// `arithmetic`
+fn legacy_coding(op: Operator, n: i32, strict: bool) -> Option<i32> {
+    let checked = match op {
+        Operator::Bcd => (n / 10).checked_mul(16).and_then(|high| high.checked_add(n % 10)),
+        _ => n.checked_sub(2 * (n % 16)),
+    };
+
+    match (checked, strict) {
+        (Some(n), _) => Some(n),
+        (None, true) => None,
+        (None, false) => Some(match op {
+            Operator::Bcd => (n / 10).wrapping_mul(16).wrapping_add(n % 10),
+            _ => n.wrapping_sub(2 * (n % 16)),
+        }),
+    }
+}
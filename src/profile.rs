@@ -0,0 +1,153 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Capability profiles of well-known terminals, see `TermInfo::missing_for_profile`
+
+use std::fmt::{Display, Formatter};
+
+use crate::capabilities::{BoolCapability, NumberCapability, StringCapability};
+
+/// Terminal whose capabilities an entry should provide
+///
+/// The required capabilities are the ones the ncurses entries of these terminals define for
+/// cursor motion, scrolling, editing, attributes, line drawing and the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// DEC VT100: cursor motion, scroll region, attributes, line drawing, keypad, PF1-PF4
+    Vt100,
+    /// DEC VT220: VT100 without keypad mode, insert/delete, cursor visibility, editing keys, F6-F10
+    Vt220,
+    /// xterm: VT220 with keypad mode, colors, alternate screen, mouse, F1-F12, home/end
+    Xterm,
+    /// rxvt: VT100 with insert/delete, colors, alternate screen, mouse, F1-F12, home/end
+    Rxvt,
+}
+
+/// Capability required by a profile, but absent in an entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingCapability {
+    /// Bool capability isn't set
+    Bool(BoolCapability),
+    /// Number capability is absent
+    Number(NumberCapability),
+    /// String capability is absent
+    String(StringCapability),
+}
+
+impl Display for MissingCapability {
+    /// Writes the capability name, the variable name with `{:#}` (feature `names`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::profile::MissingCapability;
+    ///
+    /// let missing = MissingCapability::String(StringCapability::EraseChars);
+    /// # #[cfg(feature = "names")] {
+    /// assert_eq!(format!("missing {}", missing), "missing ech");
+    /// assert_eq!(format!("missing {:#}", missing), "missing erase_chars");
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissingCapability::Bool(cap) => Display::fmt(cap, f),
+            MissingCapability::Number(cap) => Display::fmt(cap, f),
+            MissingCapability::String(cap) => Display::fmt(cap, f),
+        }
+    }
+}
+
+/// Capabilities of a profile
+pub(crate) struct Requirements {
+    pub(crate) bools: &'static [BoolCapability],
+    pub(crate) numbers: &'static [NumberCapability],
+    pub(crate) strings: &'static [StringCapability],
+}
+
+/// Capabilities shared by all profiles
+static CORE: Requirements = Requirements {
+    bools: &[BoolCapability::AutoRightMargin, BoolCapability::EatNewlineGlitch],
+    numbers: &[NumberCapability::Columns, NumberCapability::Lines],
+    strings: &[StringCapability::Bell, StringCapability::CarriageReturn, StringCapability::Tab,
+               StringCapability::CursorAddress, StringCapability::CursorHome, StringCapability::CursorUp,
+               StringCapability::CursorDown, StringCapability::CursorLeft, StringCapability::CursorRight,
+               StringCapability::SaveCursor, StringCapability::RestoreCursor,
+               StringCapability::ChangeScrollRegion, StringCapability::ScrollForward,
+               StringCapability::ScrollReverse, StringCapability::ClearScreen, StringCapability::ClearEOS,
+               StringCapability::ClearEOL, StringCapability::SetTab, StringCapability::ClearAllTabs,
+               StringCapability::ExitAttributeMode, StringCapability::EnterBoldMode,
+               StringCapability::EnterBlinkMode, StringCapability::EnterReverseMode,
+               StringCapability::EnterStandoutMode, StringCapability::ExitStandoutMode,
+               StringCapability::EnterUnderlineMode, StringCapability::ExitUnderlineMode,
+               StringCapability::AcsChars, StringCapability::EnterAltCharsetMode,
+               StringCapability::ExitAltCharsetMode, StringCapability::KeyBackspace, StringCapability::KeyUp,
+               StringCapability::KeyDown, StringCapability::KeyLeft, StringCapability::KeyRight],
+};
+
+static VT100: Requirements = Requirements {
+    bools: &[],
+    numbers: &[],
+    strings: &[StringCapability::KeypadXmit, StringCapability::KeypadLocal, StringCapability::KeyF1,
+               StringCapability::KeyF2, StringCapability::KeyF3, StringCapability::KeyF4],
+};
+
+static VT220: Requirements = Requirements {
+    bools: &[BoolCapability::MoveInsertMode],
+    numbers: &[],
+    strings: &[StringCapability::CursorInvisible, StringCapability::CursorNormal,
+               StringCapability::EnterInsertMode, StringCapability::ExitInsertMode,
+               StringCapability::ParmInsertCharacters, StringCapability::InsertLine,
+               StringCapability::DeleteLine, StringCapability::DeleteCharacter, StringCapability::EraseChars,
+               StringCapability::KeyInsertCharacter, StringCapability::KeyDeleteCharacter,
+               StringCapability::KeyPreviousPage, StringCapability::KeyNextPage, StringCapability::KeyF6,
+               StringCapability::KeyF7, StringCapability::KeyF8, StringCapability::KeyF9,
+               StringCapability::KeyF10],
+};
+
+static XTERM: Requirements = Requirements {
+    bools: &[BoolCapability::BackColorErase],
+    numbers: &[NumberCapability::MaxColors, NumberCapability::MaxPairs],
+    strings: &[StringCapability::KeypadXmit, StringCapability::KeypadLocal,
+               StringCapability::SetAnsiForeground, StringCapability::SetAnsiBackground,
+               StringCapability::OrigColorPair, StringCapability::EnterAlternativeMode,
+               StringCapability::ExitAlternativeMode, StringCapability::KeyMouse, StringCapability::KeyHome,
+               StringCapability::KeyEnd, StringCapability::KeyF1, StringCapability::KeyF2,
+               StringCapability::KeyF3, StringCapability::KeyF4, StringCapability::KeyF5,
+               StringCapability::KeyF11, StringCapability::KeyF12],
+};
+
+static RXVT: Requirements = Requirements {
+    bools: &[BoolCapability::BackColorErase, BoolCapability::MoveInsertMode],
+    numbers: &[NumberCapability::MaxColors, NumberCapability::MaxPairs],
+    strings: &[StringCapability::KeypadXmit, StringCapability::KeypadLocal,
+               StringCapability::CursorInvisible, StringCapability::CursorNormal,
+               StringCapability::EnterInsertMode, StringCapability::ExitInsertMode,
+               StringCapability::ParmInsertCharacters, StringCapability::InsertLine,
+               StringCapability::DeleteLine, StringCapability::SetAnsiForeground,
+               StringCapability::SetAnsiBackground, StringCapability::OrigColorPair,
+               StringCapability::EnterAlternativeMode, StringCapability::ExitAlternativeMode,
+               StringCapability::KeyMouse, StringCapability::KeyHome, StringCapability::KeyEnd,
+               StringCapability::KeyPreviousPage, StringCapability::KeyNextPage,
+               StringCapability::KeyInsertCharacter, StringCapability::KeyDeleteCharacter,
+               StringCapability::KeyF1, StringCapability::KeyF2, StringCapability::KeyF3,
+               StringCapability::KeyF4, StringCapability::KeyF5, StringCapability::KeyF6,
+               StringCapability::KeyF7, StringCapability::KeyF8, StringCapability::KeyF9,
+               StringCapability::KeyF10, StringCapability::KeyF11, StringCapability::KeyF12],
+};
+
+static NONE: Requirements = Requirements { bools: &[], numbers: &[], strings: &[] };
+
+impl Profile {
+    /// Returns the required capabilities, the core set first
+    pub(crate) fn requirements(&self) -> [&'static Requirements; 3] {
+        match self {
+            Profile::Vt100 => [&CORE, &VT100, &NONE],
+            Profile::Vt220 => [&CORE, &VT220, &NONE],
+            Profile::Xterm => [&CORE, &VT220, &XTERM],
+            Profile::Rxvt => [&CORE, &RXVT, &NONE],
+        }
+    }
+}
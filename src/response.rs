@@ -0,0 +1,152 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+/// CSI sequence to request a cursor position report (CPR)
+const CURSOR_POSITION_QUERY: &str = "\x1B[6n";
+
+/// Kind of a device attributes response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributesKind {
+    /// Primary device attributes (`ESC [?...c`)
+    Primary,
+    /// Secondary device attributes (`ESC [>...c`)
+    Secondary,
+}
+
+/// Device attributes reported by the terminal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceAttributes {
+    /// Primary or secondary response
+    pub kind: AttributesKind,
+    /// Primary: operating level (e.g. 62 for VT220), secondary: terminal type (e.g. 41 for VT420)
+    pub class: u8,
+    /// Primary: supported extensions, secondary: firmware version and ROM cartridge number
+    pub extensions: Vec<u16>,
+}
+
+impl DeviceAttributes {
+    /// Returns terminfo names matching secondary device attributes
+    ///
+    /// The names are ordered from the most to the least specific entry, the first one found in
+    /// the terminfo database should be used. Returns an empty slice for primary device
+    /// attributes or unknown terminal types.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::response::{AttributesKind, DeviceAttributes};
+    ///
+    /// let da2 = |class, version| DeviceAttributes {
+    ///     kind: AttributesKind::Secondary,
+    ///     class,
+    ///     extensions: vec![version, 0],
+    /// };
+    /// assert_eq!(da2(84, 0).terminal_names()[0], "tmux-256color");
+    /// assert_eq!(da2(41, 354).terminal_names(), &["xterm-256color", "xterm"]);
+    /// assert_eq!(da2(1, 10).terminal_names(), &["vt220"]);
+    ///
+    /// let da1 = DeviceAttributes { kind: AttributesKind::Primary, ..da2(62, 1) };
+    /// assert!(da1.terminal_names().is_empty());
+    /// ```
+    pub fn terminal_names(&self) -> &'static [&'static str] {
+        if self.kind != AttributesKind::Secondary {
+            return &[];
+        }
+
+        let version = self.extensions.first().copied().unwrap_or(0);
+        match self.class {
+            // VT100 class, reported by xterm compatible emulators (Konsole, iTerm2, Alacritty)
+            0 => &["xterm-256color", "xterm"],
+            // VT220 class, emulators (VTE before 0.54, kitty, WezTerm) report large versions
+            1 if version >= 100 => &["xterm-256color", "xterm"],
+            1 => &["vt220"],
+            18 => &["vt330"],
+            19 => &["vt340"],
+            24 => &["vt320"],
+            // VT420 class, default of xterm
+            41 => &["xterm-256color", "xterm"],
+            61 => &["vt510"],
+            64 => &["vt520"],
+            // VT525 class, reported by VTE since 0.54 with the VTE version (e.g. 6003 for 0.60.3)
+            65 if version >= 1000 => &["vte-256color", "vte", "xterm-256color", "xterm"],
+            65 => &["vt525"],
+            // 'M'
+            77 => &["mintty", "xterm-256color", "xterm"],
+            // 'R'
+            82 => &["rxvt"],
+            // 'S'
+            83 => &["screen-256color", "screen"],
+            // 'T'
+            84 => &["tmux-256color", "tmux", "screen-256color", "screen"],
+            // 'U'
+            85 => &["rxvt-unicode-256color", "rxvt-unicode", "rxvt"],
+            _ => &[],
+        }
+    }
+}
+
+/// Returns the sequence which asks the terminal to report the cursor position
+///
+/// The terminal answers with `ESC [row;colR`, use `parse_cursor_position_report` to read it.
+///
+/// # Example
+/// ```
+/// use cxterminfo::response::report_cursor_position_query;
+///
+/// assert_eq!(report_cursor_position_query(), "\x1B[6n");
+/// ```
+pub fn report_cursor_position_query() -> &'static str {
+    CURSOR_POSITION_QUERY
+}
+
+/// Parse a cursor position report (`ESC [row;colR`)
+///
+/// Returns `(row, col)` as reported by the terminal (1-based) or Option::None if the
+/// response is not a valid cursor position report.
+///
+/// # Arguments
+/// * `response` - bytes read from the terminal
+///
+/// # Example
+/// ```
+/// use cxterminfo::response::parse_cursor_position_report;
+///
+/// assert_eq!(parse_cursor_position_report(b"\x1B[12;40R"), Some((12, 40)));
+/// assert_eq!(parse_cursor_position_report(b"\x1B[12R"), None);
+/// ```
+pub fn parse_cursor_position_report(response: &[u8]) -> Option<(u16, u16)> {
+    let params = strip_csi(response, b'R')?;
+    let mut parts = params.split(|c| *c == b';');
+
+    let row = parse_u16(parts.next()?)?;
+    let col = parse_u16(parts.next()?)?;
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some((row, col)),
+    }
+}
+
+/// Returns the parameter bytes between `ESC [` and the final byte
+pub(crate) fn strip_csi(response: &[u8], final_byte: u8) -> Option<&[u8]> {
+    if response.len() < 3 || response[0] != 0x1B || response[1] != b'[' {
+        return None;
+    }
+
+    match response.last() {
+        Some(c) if *c == final_byte => Some(&response[2..response.len() - 1]),
+        _ => None,
+    }
+}
+
+/// Parse a decimal u16 value, empty or non-digit input is invalid
+pub(crate) fn parse_u16(digits: &[u8]) -> Option<u16> {
+    if digits.is_empty() || !digits.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.iter()
+          .try_fold(0u16, |acc, c| acc.checked_mul(10)?.checked_add((c - b'0') as u16))
+}
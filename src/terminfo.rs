@@ -4,14 +4,40 @@
 //  terms of the Do What The Fuck You Want To Public License, Version 2,
 //  as published by Sam Hocevar. See the COPYING file for more details.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
-use std::fs;
 use std::fs::File;
-use std::io::Read;
-use std::path::PathBuf;
+use std::io::{Read, Write};
+use std::ops::{BitAnd, BitOr, Sub};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use crate::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use crate::capabilities::{BoolCapability, Capability, NumberCapability, StringCapability};
+use crate::ext::{announces_truecolor, PROGRAM_ENTRIES};
+#[cfg(feature = "names")]
+use crate::capabilities::{Category, BOOL_CATEGORIES, NUMBER_CATEGORIES, STRING_CATEGORIES};
+#[cfg(feature = "names")]
+use crate::capabilities::{BOOL_NAMES, NUMBER_NAMES, STRING_NAMES};
+use crate::padding::{split_padding, Segment};
+use crate::param_string::{evaluate, EvalError, Param, ParamString};
+use crate::profile::{MissingCapability, Profile};
+use crate::response::{parse_u16, strip_csi, AttributesKind, DeviceAttributes};
+
+use self::display::CapDisplay;
+use self::policy::{ColorPolicy, EnvProvider, ProcessEnv};
+
+pub mod display;
+pub mod pairs;
+pub mod policy;
+pub mod style;
+#[cfg(all(unix, feature = "termios"))]
+pub mod speed;
+#[cfg(all(unix, feature = "winsize"))]
+pub mod winsize;
 
 /// magic number octal 0432 for legacy ncurses terminfo
 const MAGIC_LEGACY: i16 = 0x11A;
@@ -23,11 +49,17 @@ const NAMES_OFFSET: usize = 12;
 const EXT_HEADER_SIZE: usize = 10;
 const TERMINFO_HEADER_SIZE: usize = 12;
 const TERMINFO_MAX_SIZE: usize = 4096;
+/// maximum size of 32-bit entries (magic 01036)
+const TERMINFO_MAX_SIZE_32BIT: usize = 32768;
+/// CSI sequence to request the primary device attributes (DA1)
+const DEVICE_ATTRIBUTES_QUERY: &str = "\x1B[c";
+/// CSI sequence to request the secondary device attributes (DA2)
+const SECONDARY_DEVICE_ATTRIBUTES_QUERY: &str = "\x1B[>c";
 
 /// Terminfo database information
 #[derive(Debug)]
 pub struct TermInfo {
-    data: Vec<u8>,
+    data: Cow<'static, [u8]>,
     read_i32: bool,
     int_size: usize,
     sec_name_size: usize,
@@ -38,6 +70,170 @@ pub struct TermInfo {
     ext_bool: HashMap<String, bool>,
     ext_numbers: HashMap<String, i32>,
     ext_strings: HashMap<String, String>,
+    /// compiled string capabilities, Option::None if absent
+    compiled: Mutex<HashMap<CapabilityKey, Option<Compiled>>>,
+    color_policy: ColorPolicy,
+    /// `COLORTERM` announces 24-bit colors, Option::None reads the environment of the process
+    colorterm_truecolor: Option<bool>,
+}
+
+/// Key of a standard or extended string capability
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum CapabilityKey {
+    Standard(StringCapability),
+    Extended(String),
+}
+
+/// Cursor movement and its cost, see `TermInfo::move_cursor`
+#[derive(Debug, Default)]
+struct Route {
+    bytes: Vec<u8>,
+    cost: usize,
+}
+
+impl Route {
+    /// Returns the route followed by next
+    fn then(mut self, next: Route) -> Route {
+        self.bytes.extend(next.bytes);
+        self.cost += next.cost;
+        self
+    }
+}
+
+/// Compiled string capability shared with the cache or the compile error
+type Compiled = Result<Arc<ParamString>, EvalError>;
+
+/// Bell of a terminal, see `TermInfo::bell_sequence`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BellSequence {
+    /// Audible bell (`bel`)
+    Audible(String),
+    /// Visual bell (`flash`), may contain padding delays
+    Visual(String),
+}
+
+/// Color of `TermInfo::set_foreground_color` and `TermInfo::set_background_color`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Default color of the terminal
+    Default,
+    /// ANSI color number
+    Index(u16),
+}
+
+impl From<u16> for Color {
+    fn from(color: u16) -> Self {
+        Color::Index(color)
+    }
+}
+
+/// Set of video attributes, the bits are those of `ncv` (`no_color_video`)
+///
+/// Bits 0 to 8 follow the parameters of `sgr`, italic is bit 15 like in ncurses.
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::AttrSet;
+///
+/// let attrs = AttrSet::BOLD | AttrSet::UNDERLINE;
+/// assert!(attrs.contains(AttrSet::BOLD));
+/// assert_eq!(attrs - AttrSet::BOLD, AttrSet::UNDERLINE);
+/// assert_eq!(AttrSet::from_bits(18), AttrSet::UNDERLINE | AttrSet::DIM);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AttrSet(u16);
+
+impl AttrSet {
+    pub const EMPTY: AttrSet = AttrSet(0);
+    pub const STANDOUT: AttrSet = AttrSet(1);
+    pub const UNDERLINE: AttrSet = AttrSet(1 << 1);
+    pub const REVERSE: AttrSet = AttrSet(1 << 2);
+    pub const BLINK: AttrSet = AttrSet(1 << 3);
+    pub const DIM: AttrSet = AttrSet(1 << 4);
+    pub const BOLD: AttrSet = AttrSet(1 << 5);
+    pub const INVISIBLE: AttrSet = AttrSet(1 << 6);
+    pub const PROTECTED: AttrSet = AttrSet(1 << 7);
+    pub const ALT_CHARSET: AttrSet = AttrSet(1 << 8);
+    pub const ITALIC: AttrSet = AttrSet(1 << 15);
+
+    /// Returns the set of an `ncv` mask, unknown bits are dropped
+    pub fn from_bits(bits: u16) -> AttrSet {
+        AttrSet(bits & 0x81FF)
+    }
+
+    /// Returns the `ncv` mask
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns true if all attributes of other are in the set
+    pub fn contains(&self, other: AttrSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the set has no attributes
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the 9 parameters of `sgr`
+    fn sgr_params(&self) -> Vec<Param<'static>> {
+        (0..9).map(|bit| Param::Number(self.contains(AttrSet(1 << bit)) as i32)).collect()
+    }
+}
+
+/// Attributes and the capabilities setting them in the order of `sgr`, without italic
+const ATTRIBUTE_MODES: [(AttrSet, StringCapability); 9] = [
+    (AttrSet::STANDOUT, StringCapability::EnterStandoutMode),
+    (AttrSet::BOLD, StringCapability::EnterBoldMode),
+    (AttrSet::DIM, StringCapability::EnterDimMode),
+    (AttrSet::UNDERLINE, StringCapability::EnterUnderlineMode),
+    (AttrSet::BLINK, StringCapability::EnterBlinkMode),
+    (AttrSet::REVERSE, StringCapability::EnterReverseMode),
+    (AttrSet::INVISIBLE, StringCapability::EnterSecureMode),
+    (AttrSet::PROTECTED, StringCapability::EnterProtectedMode),
+    (AttrSet::ALT_CHARSET, StringCapability::EnterAltCharsetMode),
+];
+
+impl BitOr for AttrSet {
+    type Output = AttrSet;
+
+    fn bitor(self, other: AttrSet) -> AttrSet {
+        AttrSet(self.0 | other.0)
+    }
+}
+
+impl BitAnd for AttrSet {
+    type Output = AttrSet;
+
+    fn bitand(self, other: AttrSet) -> AttrSet {
+        AttrSet(self.0 & other.0)
+    }
+}
+
+impl Sub for AttrSet {
+    type Output = AttrSet;
+
+    fn sub(self, other: AttrSet) -> AttrSet {
+        AttrSet(self.0 & !other.0)
+    }
+}
+
+/// Value of an extended capability, see `TermInfo::get_ext`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtCapability {
+    Bool(bool),
+    Number(i32),
+    String(String),
+}
+
+/// Binary format of a terminfo file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminfoVersion {
+    /// Legacy format (magic 0432), numbers are 16-bit and limited to 32767
+    Legacy16Bit,
+    /// ncurses 6.1 extended number format (magic 01036), numbers are 32-bit
+    New32Bit,
 }
 
 #[derive(Debug)]
@@ -46,203 +242,2814 @@ pub enum TermInfoError {
     InvalidMagicNum,
     InvalidData,
     InvalidName,
+    /// Reading the terminfo file failed
+    Io(std::io::Error),
 }
 
 impl Display for TermInfoError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}",
-               match self {
-                   TermInfoError::InvalidDataSize => "file/data length is above 4096 bytes or under 12 bytes",
-                   TermInfoError::InvalidMagicNum => "magic number mismatch",
-                   TermInfoError::InvalidData => "terminfo data is invalid or corrupt",
-                   TermInfoError::InvalidName => "terminfo not found"
-               })
+        match self {
+            TermInfoError::InvalidDataSize => write!(f, "file/data length is above 4096 bytes (32768 bytes in the 32-bit format) or under 12 bytes"),
+            TermInfoError::InvalidMagicNum => write!(f, "magic number mismatch"),
+            TermInfoError::InvalidData => write!(f, "terminfo data is invalid or corrupt"),
+            TermInfoError::InvalidName => write!(f, "terminfo not found"),
+            TermInfoError::Io(err) => write!(f, "unable to read terminfo file: {}", err),
+        }
+    }
+}
+
+impl From<std::io::Error> for TermInfoError {
+    fn from(err: std::io::Error) -> Self {
+        TermInfoError::Io(err)
+    }
+}
+
+/// Error while writing a capability to a terminal
+#[derive(Debug)]
+pub enum TermInfoSendError {
+    /// The capability is not defined for this terminal
+    CapabilityNotFound(StringCapability),
+    /// The parameterized string could not be evaluated
+    Eval(EvalError),
+    /// Writing to the terminal failed
+    Io(std::io::Error),
+}
+
+impl Display for TermInfoSendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TermInfoSendError::CapabilityNotFound(cap) => write!(f, "capability {} not found", cap),
+            TermInfoSendError::Eval(err) => write!(f, "{}", err),
+            TermInfoSendError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for TermInfoSendError {}
+
+impl From<EvalError> for TermInfoSendError {
+    fn from(err: EvalError) -> Self {
+        TermInfoSendError::Eval(err)
+    }
+}
+
+impl From<std::io::Error> for TermInfoSendError {
+    fn from(err: std::io::Error) -> Self {
+        TermInfoSendError::Io(err)
+    }
+}
+
+/// Error of `TermInfo::format` and `TermInfo::format_ext`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// The capability is not defined for this terminal
+    CapabilityNotFound(StringCapability),
+    /// The extended string capability is not defined for this terminal
+    ExtCapabilityNotFound(String),
+    /// The color is not below `max_colors`
+    ColorOutOfRange(u16),
+    /// All color pairs below `max_pairs` are in use, see `pairs::ColorPairs`
+    PairsExhausted(u32),
+    /// The parameterized string could not be evaluated
+    Eval(EvalError),
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::CapabilityNotFound(cap) => write!(f, "capability {} not found", cap),
+            FormatError::ExtCapabilityNotFound(name) => {
+                write!(f, "extended capability {} not found", name)
+            }
+            FormatError::ColorOutOfRange(color) => write!(f, "color {} out of range", color),
+            FormatError::PairsExhausted(pairs) => write!(f, "all {} color pairs in use", pairs),
+            FormatError::Eval(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+impl From<EvalError> for FormatError {
+    fn from(err: EvalError) -> Self {
+        FormatError::Eval(err)
+    }
+}
+
+impl TermInfo {
+    /// Returns the value of a bool, number or string capability or Option::None
+    ///
+    /// The value type follows the kind of the capability, see `Capability`. Strings are returned
+    /// as raw bytes without the terminating null.
+    ///
+    /// # Arguments
+    /// * `cap` - capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.get(BoolCapability::AutoRightMargin), Some(true));
+    /// assert_eq!(info.get(NumberCapability::MaxColors), Some(256));
+    /// assert_eq!(info.get(StringCapability::Bell), Some(&b"\x07"[..]));
+    /// ```
+    pub fn get<C: Capability>(&self, cap: C) -> Option<C::Value<'_>> {
+        cap.lookup(self)
+    }
+
+    /// Returns the string value for the capability or Option::None
+    ///
+    /// # Arguments
+    /// * `cap` - string capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_string(StringCapability::Bell));
+    /// }
+    /// ```
+    pub fn get_string(&self, cap: StringCapability) -> Option<String> {
+        self.string_offset(cap as usize)
+            .map(|offset| read_str(&self.data, offset).0)
+    }
+
+    /// Returns the bytes of the capability for writing them to the terminal or Option::None
+    ///
+    /// `get_string` maps every byte to the char with the same value, bytes 128 to 255 (e.g.
+    /// the 8-bit CSI `0x9B`) become two bytes when the string is written. The bytes returned
+    /// here are the ones stored in the entry.
+    ///
+    /// # Arguments
+    /// * `cap` - string capability
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Write;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let clear = info.get_string_for_output(StringCapability::ClearScreen).unwrap();
+    /// assert_eq!(clear, b"\x1B[H\x1B[2J");
+    ///
+    /// let mut out = Vec::new();
+    /// out.write_all(&clear).unwrap();
+    /// ```
+    pub fn get_string_for_output(&self, cap: StringCapability) -> Option<Vec<u8>> {
+        self.get(cap).map(<[u8]>::to_vec)
+    }
+
+    /// Returns the string value for the capability, the default curses assumes if it's absent
+    /// or Option::None
+    ///
+    /// Defaults of absent capabilities:
+    /// * `bel` - `^G`
+    /// * `cr` - `\r`, unless `OTnc` (no working carriage return) is set
+    /// * `cud1` - `OTnl` or `\n`
+    /// * `ind` - `\n`, unless `OTns` (crt can't scroll) is set
+    /// * `cub1` - `OTbc`, or `^H` if `OTbs` (backspaces with ^H) is set
+    /// * `ht` - `^I` if `OTpt` (hardware tabs) is set
+    ///
+    /// `get_string` returns Option::None for defaulted capabilities.
+    ///
+    /// # Arguments
+    /// * `cap` - string capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// // names "serial", no capabilities
+    /// let serial = TermInfo::from_bytes(&[0x1A, 0x01, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ///                                     b's', b'e', b'r', b'i', b'a', b'l', 0, 0]).unwrap();
+    /// assert_eq!(serial.get_string(StringCapability::CarriageReturn), None);
+    /// assert_eq!(serial.get_string_or_default(StringCapability::CarriageReturn), Some("\r".to_string()));
+    /// assert_eq!(serial.get_string_or_default(StringCapability::CursorLeft), None);
+    /// ```
+    pub fn get_string_or_default(&self, cap: StringCapability) -> Option<String> {
+        let flag = |cap: BoolCapability| self.get_bool(cap) == Some(true);
+        self.get_string(cap).or_else(|| match cap {
+            StringCapability::Bell => Some("\x07".to_string()),
+            StringCapability::CarriageReturn if !flag(BoolCapability::NoCorrectlyWorkingCr) => {
+                Some("\r".to_string())
+            }
+            StringCapability::CursorDown => {
+                self.get_string(StringCapability::LinefeedIfNotLf).or_else(|| Some("\n".to_string()))
+            }
+            StringCapability::ScrollForward if !flag(BoolCapability::CrtNoScrolling) => {
+                Some("\n".to_string())
+            }
+            StringCapability::CursorLeft => {
+                self.get_string(StringCapability::BackspaceIfNotBs)
+                    .or_else(|| flag(BoolCapability::BackspacesWithBs).then(|| "\x08".to_string()))
+            }
+            StringCapability::Tab if flag(BoolCapability::HasHardwareTabs) => Some("\t".to_string()),
+            _ => None,
+        })
+    }
+
+    /// Returns the string value for the capability in readable form or Option::None
+    ///
+    /// Escapes are written like infocmp: `\E` for escape, `\n`, `\r`, `^X` for control
+    /// characters and octal (`\200`) for bytes above 0x7F, where `\200` stores a null character.
+    ///
+    /// # Arguments
+    /// * `cap` - string capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.get_string_escaped(StringCapability::ClearScreen), Some("\\E[H\\E[2J".to_string()));
+    /// assert_eq!(info.get_string_escaped(StringCapability::Bell), Some("^G".to_string()));
+    /// ```
+    pub fn get_string_escaped(&self, cap: StringCapability) -> Option<String> {
+        let offset = self.string_offset(cap as usize)?;
+        let mut escaped = Vec::new();
+        write_escaped(&mut escaped, &self.data[offset..find_null_term(&self.data, offset)]).ok()?;
+        Some(escaped.into_iter().map(|c| c as char).collect())
+    }
+
+    /// Returns the number value for the capability or Option::None
+    ///
+    /// # Arguments
+    /// * `cap` - number capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_number(NumberCapability::MaxColors));
+    /// }
+    /// ```
+    pub fn get_number(&self, cap: NumberCapability) -> Option<i32> {
+        self.number_at(cap as usize)
+    }
+
+    /// Returns the number value for the capability, the default curses assumes if it's absent
+    /// or Option::None
+    ///
+    /// Defaults of absent capabilities:
+    /// * `cols` and `lines` - `COLUMNS` and `LINES` environment variables
+    /// * `it` - 8 if the terminal has hardware tabs (`ht` or `OTpt`)
+    ///
+    /// `get_number` returns Option::None for defaulted capabilities.
+    ///
+    /// # Arguments
+    /// * `cap` - number capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// // the value of the entry is used if present
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.get_number_or_default(NumberCapability::Lines), Some(24));
+    ///
+    /// // names "serial", no capabilities
+    /// let serial = TermInfo::from_bytes(&[0x1A, 0x01, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ///                                     b's', b'e', b'r', b'i', b'a', b'l', 0, 0]).unwrap();
+    /// std::env::set_var("LINES", "40");
+    /// assert_eq!(serial.get_number(NumberCapability::Lines), None);
+    /// assert_eq!(serial.get_number_or_default(NumberCapability::Lines), Some(40));
+    /// assert_eq!(serial.get_number_or_default(NumberCapability::InitTabs), None);
+    /// assert_eq!(serial.get_number_or_default(NumberCapability::MaxColors), None);
+    /// ```
+    pub fn get_number_or_default(&self, cap: NumberCapability) -> Option<i32> {
+        self.get_number(cap).or_else(|| match cap {
+            NumberCapability::Columns => env_number("COLUMNS"),
+            NumberCapability::Lines => env_number("LINES"),
+            NumberCapability::InitTabs
+                if self.get(StringCapability::Tab).is_some()
+                    || self.get_bool(BoolCapability::HasHardwareTabs) == Some(true) => Some(8),
+            _ => None,
+        })
+    }
+
+    /// Returns the number of columns or Option::None if it's absent or above u16::MAX
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.column_count(), Some(80));
+    /// ```
+    pub fn column_count(&self) -> Option<u16> {
+        self.get_number(NumberCapability::Columns).and_then(|n| u16::try_from(n).ok())
+    }
+
+    /// Returns the number of lines or Option::None if it's absent or above u16::MAX
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.line_count(), Some(24));
+    /// ```
+    pub fn line_count(&self) -> Option<u16> {
+        self.get_number(NumberCapability::Lines).and_then(|n| u16::try_from(n).ok())
+    }
+
+    /// Returns columns and lines or Option::None if one of them is absent, 0 or above u16::MAX
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.dimensions(), Some((80, 24)));
+    /// assert_eq!(TermInfo::from_file("tests/data/cx-ext").unwrap().dimensions(), None);
+    /// ```
+    pub fn dimensions(&self) -> Option<(u16, u16)> {
+        match (self.column_count()?, self.line_count()?) {
+            (0, _) | (_, 0) => None,
+            dimensions => Some(dimensions),
+        }
+    }
+
+    /// Returns the bool value for the capability or Option::None
+    ///
+    /// # Arguments
+    /// * `cap` - bool capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_bool(BoolCapability::AutoLeftMargin));
+    /// }
+    /// ```
+    pub fn get_bool(&self, cap: BoolCapability) -> Option<bool> {
+        let idx = cap as usize;
+        if idx >= self.sec_bool_size {
+            None
+        } else {
+            Some(self.data[self.offset_bool() + idx] == 1)
+        }
+    }
+
+    /// Returns an iterator over the set bool capabilities in file order
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.bools().count(), 10);
+    /// assert!(info.bools().any(|cap| cap == BoolCapability::AutoRightMargin));
+    /// ```
+    pub fn bools(&self) -> impl Iterator<Item = BoolCapability> + '_ {
+        BoolCapability::all().filter(move |cap| self.get_bool(*cap) == Some(true))
+    }
+
+    /// Returns an iterator over the present number capabilities and their values in file order
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.numbers().count(), 5);
+    /// assert_eq!(info.numbers().next(), Some((NumberCapability::Columns, 80)));
+    /// ```
+    pub fn numbers(&self) -> impl Iterator<Item = (NumberCapability, i32)> + '_ {
+        NumberCapability::all().filter_map(move |cap| self.get_number(cap).map(|n| (cap, n)))
+    }
+
+    /// Returns an iterator over the present string capabilities and their values in file order
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.strings().count(), 183);
+    /// assert_eq!(info.strings().next(), Some((StringCapability::BackTab, "\x1B[Z".to_string())));
+    /// ```
+    pub fn strings(&self) -> impl Iterator<Item = (StringCapability, String)> + '_ {
+        StringCapability::all().filter_map(move |cap| self.get_string(cap).map(|s| (cap, s)))
+    }
+
+    /// Returns the set bool capabilities in file order, same as `bools` collected
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::BoolCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let bools = info.all_bool_capabilities();
+    /// assert_eq!(bools.len(), 10);
+    /// assert_eq!(bools[0], BoolCapability::AutoRightMargin);
+    /// ```
+    pub fn all_bool_capabilities(&self) -> Vec<BoolCapability> {
+        self.bools().collect()
+    }
+
+    /// Returns the present number capabilities and their values in file order, same as
+    /// `numbers` collected
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let numbers = info.all_number_capabilities();
+    /// assert_eq!(numbers.len(), 5);
+    /// assert!(numbers.contains(&(NumberCapability::MaxColors, 256)));
+    /// ```
+    pub fn all_number_capabilities(&self) -> Vec<(NumberCapability, i32)> {
+        self.numbers().collect()
+    }
+
+    /// Returns the present string capabilities and their values in file order, same as
+    /// `strings` collected
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let strings = info.all_string_capabilities();
+    /// assert_eq!(strings.len(), 183);
+    /// assert!(strings.contains(&(StringCapability::Bell, "\x07".to_string())));
+    /// assert!(strings.iter().all(|(cap, _)| *cap != StringCapability::ToStatusLine));
+    /// ```
+    pub fn all_string_capabilities(&self) -> Vec<(StringCapability, String)> {
+        self.strings().collect()
+    }
+
+    /// Returns the extended bool value for the given name or Option::None if name not exist
+    ///
+    /// # Arguments
+    /// * `name` - key
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_ext_bool("AT"));
+    /// }
+    /// ```
+    pub fn get_ext_bool(&self, name: &str) -> Option<&bool> {
+        self.ext_bool.get(name)
+    }
+
+    /// Returns the extended number value for the given name or Option::None if name not exist
+    ///
+    /// # Arguments
+    /// * `name` - key
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_ext_number("?"));
+    /// }
+    /// ```
+    pub fn get_ext_number(&self, name: &str) -> Option<&i32> {
+        self.ext_numbers.get(name)
+    }
+
+    /// Returns the extended string value for the given name or Option::None if name not exist
+    ///
+    /// # Arguments
+    /// * `name` - key
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{:?}", info.get_ext_number("xm"));
+    /// }
+    /// ```
+    pub fn get_ext_string(&self, name: &str) -> Option<&String> {
+        self.ext_strings.get(name)
+    }
+
+    /// Sets an extended boolean capability
+    pub(crate) fn set_ext_bool(&mut self, name: &str, value: bool) {
+        self.ext_bool.insert(name.to_string(), value);
+    }
+
+    /// Sets an extended string capability and drops its compiled form
+    pub(crate) fn set_ext_string(&mut self, name: &str, value: &str) {
+        self.ext_strings.insert(name.to_string(), value.to_string());
+        self.compiled.get_mut()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&CapabilityKey::Extended(name.to_string()));
+    }
+
+    /// Returns the extended capability of any type for the given name or Option::None if name
+    /// not exist
+    ///
+    /// # Arguments
+    /// * `name` - key
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{ExtCapability, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// assert_eq!(info.get_ext("Tc"), Some(ExtCapability::Bool(true)));
+    /// assert_eq!(info.get_ext("Sync"), Some(ExtCapability::String("\x1BP=%p1%ds\x1B\\".to_string())));
+    /// assert_eq!(info.get_ext("RGB"), None);
+    /// ```
+    pub fn get_ext(&self, name: &str) -> Option<ExtCapability> {
+        self.ext_bool.get(name).map(|b| ExtCapability::Bool(*b))
+            .or_else(|| self.ext_numbers.get(name).map(|n| ExtCapability::Number(*n)))
+            .or_else(|| self.ext_strings.get(name).map(|s| ExtCapability::String(s.clone())))
+    }
+
+    /// Returns all extended capabilities in one map
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{ExtCapability, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    /// let all = info.get_ext_all();
+    /// assert_eq!(all.len(), 7);
+    /// assert_eq!(all["Tc"], ExtCapability::Bool(true));
+    /// assert!(matches!(all["setrgbf"], ExtCapability::String(_)));
+    /// ```
+    pub fn get_ext_all(&self) -> HashMap<String, ExtCapability> {
+        let strings = self.ext_strings.iter()
+                          .map(|(name, s)| (name.clone(), ExtCapability::String(s.clone())));
+        let numbers = self.ext_numbers.iter()
+                          .map(|(name, n)| (name.clone(), ExtCapability::Number(*n)));
+        let bools = self.ext_bool.iter()
+                        .map(|(name, b)| (name.clone(), ExtCapability::Bool(*b)));
+        // same precedence as get_ext if a name is used twice
+        strings.chain(numbers).chain(bools).collect()
+    }
+
+    /// Returns the extended key capabilities (names starting with `k`) and their sequences
+    ///
+    /// Extended keys are usually keys with modifiers, e.g. `kUP5` (ctrl + up) or `kDC3`
+    /// (alt + delete), see `ext::ArrowOrEditKey`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let keys = info.extended_keys();
+    /// assert_eq!(keys.len(), 66);
+    /// assert_eq!(keys["kUP5"], "\x1B[1;5A");
+    /// assert!(!keys.contains_key("Ms"));
+    /// ```
+    pub fn extended_keys(&self) -> HashMap<String, String> {
+        self.ext_strings.iter()
+                        .filter(|(name, _)| name.starts_with('k'))
+                        .map(|(name, value)| (name.clone(), value.clone()))
+                        .collect()
+    }
+
+    /// Returns the present capabilities whose short or variable name matches a glob pattern,
+    /// sorted by short name
+    ///
+    /// `*` matches any sequence of characters and `?` a single character. Standard capabilities
+    /// and extended capabilities are searched, standard ones are returned as `ExtCapability` too.
+    ///
+    /// # Arguments
+    /// * `pattern` - glob pattern, e.g. `k*` or `set?f`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{ExtCapability, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let names = |pattern| {
+    ///     info.capabilities_matching(pattern).into_iter().map(|(name, _)| name).collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(names("set?f"), ["setaf"]);
+    /// assert_eq!(names("kUP?"), ["kUP3", "kUP4", "kUP5", "kUP6", "kUP7"]);
+    ///
+    /// let colors = info.capabilities_matching("max_col*");
+    /// assert_eq!(colors, [("colors".to_string(), ExtCapability::Number(256))]);
+    /// assert!(info.capabilities_matching("A?").iter().any(|(name, _)| name == "AX"));
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn capabilities_matching(&self, pattern: &str) -> Vec<(String, ExtCapability)> {
+        let matches = |name: &str, long_name: &str| {
+            glob_match(pattern, name) || glob_match(pattern, long_name)
+        };
+
+        let bools = self.bools()
+                        .filter(|cap| matches(cap.name(), cap.long_name()))
+                        .map(|cap| (cap.name().to_string(), ExtCapability::Bool(true)));
+        let numbers = self.numbers()
+                          .filter(|(cap, _)| matches(cap.name(), cap.long_name()))
+                          .map(|(cap, n)| (cap.name().to_string(), ExtCapability::Number(n)));
+        let strings = self.strings()
+                          .filter(|(cap, _)| matches(cap.name(), cap.long_name()))
+                          .map(|(cap, s)| (cap.name().to_string(), ExtCapability::String(s)));
+        let extended = self.get_ext_all().into_iter().filter(|(name, _)| glob_match(pattern, name));
+
+        let mut found = bools.chain(numbers)
+                             .chain(strings)
+                             .chain(extended)
+                             .collect::<Vec<(String, ExtCapability)>>();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        found
+    }
+
+    /// Returns the number of parameters a string capability expects (highest `%p` referenced)
+    /// or Option::None if the capability is absent or invalid
+    ///
+    /// `%i` doesn't change the count. The result is cached per capability.
+    ///
+    /// # Arguments
+    /// * `cap` - string capability
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.param_count(StringCapability::CursorAddress), Some(2));
+    /// assert_eq!(info.param_count(StringCapability::SetAnsiForeground), Some(1));
+    /// assert_eq!(info.param_count(StringCapability::SetAttributes), Some(9));
+    /// assert_eq!(info.param_count(StringCapability::ClearScreen), Some(0));
+    /// assert_eq!(info.param_count(StringCapability::ToStatusLine), None);
+    /// ```
+    pub fn param_count(&self, cap: StringCapability) -> Option<u8> {
+        self.cached_param_count(CapabilityKey::Standard(cap), || self.get_string(cap))
+    }
+
+    /// Returns the number of parameters an extended string capability expects or Option::None
+    /// if the capability is absent or invalid
+    ///
+    /// # Arguments
+    /// * `name` - key
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.ext_param_count("Ms"), Some(2));
+    /// assert_eq!(info.ext_param_count("Cr"), Some(0));
+    /// assert_eq!(info.ext_param_count("unknown"), None);
+    /// ```
+    pub fn ext_param_count(&self, name: &str) -> Option<u8> {
+        self.cached_param_count(CapabilityKey::Extended(name.to_string()),
+                                || self.get_ext_string(name).cloned())
+    }
+
+    fn cached_param_count(&self,
+                          key: CapabilityKey,
+                          value: impl FnOnce() -> Option<String>) -> Option<u8> {
+        match self.compiled(key, value) {
+            Some(Ok(compiled)) => Some(compiled.params_used()),
+            _ => None,
+        }
+    }
+
+    /// Returns the compiled capability, the capability is compiled on first use
+    fn compiled(&self,
+                key: CapabilityKey,
+                value: impl FnOnce() -> Option<String>) -> Option<Compiled> {
+        let mut cache = self.compiled.lock().unwrap_or_else(|err| err.into_inner());
+        cache.entry(key)
+             .or_insert_with(|| value().map(|term| ParamString::compile(&term).map(Arc::new)))
+             .clone()
+    }
+
+    /// Evaluate a string capability, padding delays (`$<n>`) are removed from the output
+    ///
+    /// The capability is compiled once and cached, see `ParamString`.
+    ///
+    /// # Arguments
+    /// * `cap`    - string capability
+    /// * `params` - array of parameters
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{FormatError, TermInfo};
+    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::param_string::Param;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let cup = info.format(StringCapability::CursorAddress, &[Param::Number(4), Param::Number(9)]);
+    /// assert_eq!(cup.unwrap(), b"\x1B[5;10H");
+    ///
+    /// let setaf = info.format(StringCapability::SetAnsiForeground, &[Param::Number(196)]);
+    /// assert_eq!(setaf.unwrap(), b"\x1B[38;5;196m");
+    ///
+    /// assert!(matches!(info.format(StringCapability::ToStatusLine, &[]),
+    ///                  Err(FormatError::CapabilityNotFound(StringCapability::ToStatusLine))));
+    /// ```
+    pub fn format(&self, cap: StringCapability, params: &[Param]) -> Result<Vec<u8>, FormatError> {
+        self.format_key(CapabilityKey::Standard(cap), || self.get_string(cap),
+                        || FormatError::CapabilityNotFound(cap), params, false)
+    }
+
+    /// Same as `format`, but padding delays are kept in the output
+    ///
+    /// # Arguments
+    /// * `cap`    - string capability
+    /// * `params` - array of parameters
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.format_with_padding(StringCapability::FlashScreen, &[]).unwrap(),
+    ///            b"\x1B[?5h$<100/>\x1B[?5l");
+    /// assert_eq!(info.format(StringCapability::FlashScreen, &[]).unwrap(), b"\x1B[?5h\x1B[?5l");
+    /// ```
+    pub fn format_with_padding(&self,
+                               cap: StringCapability,
+                               params: &[Param]) -> Result<Vec<u8>, FormatError> {
+        self.format_key(CapabilityKey::Standard(cap), || self.get_string(cap),
+                        || FormatError::CapabilityNotFound(cap), params, true)
+    }
+
+    /// Returns a value evaluating a string capability when it's formatted, the output isn't
+    /// built in between
+    ///
+    /// # Arguments
+    /// * `cap`    - string capability
+    /// * `params` - array of parameters
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::param_string::Param;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let cup = [Param::Number(4), Param::Number(9)];
+    /// let text = format!("{}hello", info.display(StringCapability::CursorAddress, &cup));
+    /// assert_eq!(text, "\x1B[5;10Hhello");
+    /// ```
+    pub fn display<'a>(&'a self, cap: StringCapability, params: &'a [Param<'a>]) -> CapDisplay<'a> {
+        CapDisplay::new(self, cap, params)
+    }
+
+    /// Evaluate a string capability into writer without padding delays, see `CapDisplay`
+    fn write_into(&self,
+                  cap: StringCapability,
+                  params: &[Param],
+                  writer: &mut dyn std::fmt::Write) -> Result<std::fmt::Result, FormatError> {
+        match self.compiled(CapabilityKey::Standard(cap), || self.get_string(cap)) {
+            Some(Ok(compiled)) => Ok(compiled.write_into(params, writer, false)?),
+            Some(Err(err)) => Err(FormatError::Eval(err)),
+            None => Err(FormatError::CapabilityNotFound(cap)),
+        }
+    }
+
+    /// Evaluate a string capability into an `io::Write` like `format`, see `CapDisplay::write_to`
+    fn write_bytes_into(&self,
+                        cap: StringCapability,
+                        params: &[Param],
+                        writer: &mut dyn std::io::Write)
+                        -> Result<std::io::Result<()>, FormatError> {
+        match self.compiled(CapabilityKey::Standard(cap), || self.get_string(cap)) {
+            Some(Ok(compiled)) => Ok(compiled.write_bytes_into(params, writer)?),
+            Some(Err(err)) => Err(FormatError::Eval(err)),
+            None => Err(FormatError::CapabilityNotFound(cap)),
+        }
+    }
+
+    /// Evaluate an extended string capability like `format`, padding delays are removed from
+    /// the output
+    ///
+    /// # Arguments
+    /// * `name`   - key
+    /// * `params` - array of parameters
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{FormatError, TermInfo};
+    /// use cxterminfo::param_string::Param;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// let smulx = info.format_ext("Smulx", &[Param::Number(3)]);
+    /// assert_eq!(smulx.unwrap(), b"\x1B[4:3m");
+    ///
+    /// assert_eq!(info.format_ext("Setulc", &[Param::Number(0xFF0000)]),
+    ///            Err(FormatError::ExtCapabilityNotFound("Setulc".to_string())));
+    /// ```
+    pub fn format_ext(&self, name: &str, params: &[Param]) -> Result<Vec<u8>, FormatError> {
+        self.format_key(CapabilityKey::Extended(name.to_string()),
+                        || self.get_ext_string(name).cloned(),
+                        || FormatError::ExtCapabilityNotFound(name.to_string()), params, false)
+    }
+
+    /// Same as `format_ext`, but padding delays are kept in the output
+    ///
+    /// # Arguments
+    /// * `name`   - key
+    /// * `params` - array of parameters
+    pub fn format_ext_with_padding(&self,
+                                   name: &str,
+                                   params: &[Param]) -> Result<Vec<u8>, FormatError> {
+        self.format_key(CapabilityKey::Extended(name.to_string()),
+                        || self.get_ext_string(name).cloned(),
+                        || FormatError::ExtCapabilityNotFound(name.to_string()), params, true)
+    }
+
+    /// Returns the sequence to set the foreground color
+    ///
+    /// Uses `setaf`, or `setf` of older terminals which swaps red and blue (1 and 4) and yellow
+    /// and cyan (3 and 6). The color is remapped, callers always pass ANSI color numbers.
+    ///
+    /// `Color::Default` is SGR 39 if the terminal supports it (`AX`, see
+    /// `supports_default_colors`), otherwise `op` or `oc` which reset the background too.
+    ///
+    /// The sequence is empty if colors are turned off by the color policy
+    /// (`set_color_policy`), `ColorPolicy::Always` sets the 8 ANSI colors with SGR 30 to 37 on
+    /// terminals without colors.
+    ///
+    /// # Arguments
+    /// * `color` - ANSI color number or `Color::Default`
+    ///
+    /// # Errors
+    /// * `FormatError::ColorOutOfRange` - color is not below `max_colors`
+    /// * `FormatError::CapabilityNotFound(SetAnsiForeground)` - neither `setaf` nor `setf` is
+    ///   defined
+    /// * `FormatError::CapabilityNotFound(OrigColorPair)` - no way to set the default color
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{Color, FormatError, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.set_foreground_color(1).unwrap(), b"\x1B[31m");
+    /// assert_eq!(info.set_foreground_color(208).unwrap(), b"\x1B[38;5;208m");
+    /// assert_eq!(info.set_foreground_color(Color::Default).unwrap(), b"\x1B[39m");
+    /// assert_eq!(info.set_foreground_color(256), Err(FormatError::ColorOutOfRange(256)));
+    /// ```
+    pub fn set_foreground_color<C: Into<Color>>(&self, color: C) -> Result<Vec<u8>, FormatError> {
+        self.set_color(color.into(),
+                       StringCapability::SetAnsiForeground,
+                       StringCapability::SetForeground,
+                       30)
+    }
+
+    /// Returns the sequence to set the background color, see `set_foreground_color`
+    ///
+    /// # Arguments
+    /// * `color` - ANSI color number or `Color::Default`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{Color, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.set_background_color(4).unwrap(), b"\x1B[44m");
+    /// assert_eq!(info.set_background_color(12).unwrap(), b"\x1B[104m");
+    /// assert_eq!(info.set_background_color(Color::Default).unwrap(), b"\x1B[49m");
+    /// ```
+    pub fn set_background_color<C: Into<Color>>(&self, color: C) -> Result<Vec<u8>, FormatError> {
+        self.set_color(color.into(),
+                       StringCapability::SetAnsiBackground,
+                       StringCapability::SetBackground,
+                       40)
+    }
+
+    fn set_color(&self,
+                 color: Color,
+                 ansi: StringCapability,
+                 legacy: StringCapability,
+                 sgr: u8) -> Result<Vec<u8>, FormatError> {
+        if self.colors_suppressed() {
+            return Ok(Vec::new());
+        }
+
+        let sgr_default = format!("\x1B[{}m", sgr + 9).into_bytes();
+        let color = match color {
+            Color::Index(color) if self.forces_ansi_colors() => {
+                return match color {
+                    0..=7 => Ok(format!("\x1B[{}m", sgr as u16 + color).into_bytes()),
+                    _ => Err(FormatError::ColorOutOfRange(color)),
+                };
+            }
+            Color::Index(color) => color,
+            Color::Default if self.supports_default_colors() || self.forces_ansi_colors() => {
+                return Ok(sgr_default);
+            }
+            Color::Default => {
+                return match self.format(StringCapability::OrigColorPair, &[]) {
+                    Err(FormatError::CapabilityNotFound(op)) => {
+                        match self.format(StringCapability::OrigColors, &[]) {
+                            Err(FormatError::CapabilityNotFound(_)) => {
+                                Err(FormatError::CapabilityNotFound(op))
+                            }
+                            result => result,
+                        }
+                    }
+                    result => result,
+                };
+            }
+        };
+
+        if let Some(colors) = self.get_number(NumberCapability::MaxColors) {
+            if color as i32 >= colors {
+                return Err(FormatError::ColorOutOfRange(color));
+            }
+        }
+
+        match self.format(ansi, &[Param::Number(color as i32)]) {
+            Err(FormatError::CapabilityNotFound(_)) => {}
+            result => return result,
+        }
+
+        // swap bit 0 (red) and bit 2 (blue)
+        let swapped = (color & !5) | ((color & 1) << 2) | ((color & 4) >> 2);
+        match self.format(legacy, &[Param::Number(swapped as i32)]) {
+            Err(FormatError::CapabilityNotFound(_)) => Err(FormatError::CapabilityNotFound(ansi)),
+            result => result,
+        }
+    }
+
+    /// Returns the number of colors (`colors`), 0 if it's absent or cancelled
+    ///
+    /// Direct color entries have `colors#0x1000000`, see `ext::ColorSupport`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert_eq!(TermInfo::from_file("tests/data/xterm-256color").unwrap().colors(), 256);
+    /// assert_eq!(TermInfo::from_file("tests/data/xterm-direct").unwrap().colors(), 0x1000000);
+    /// assert_eq!(TermInfo::from_file("tests/data/vt100").unwrap().colors(), 0);
+    /// ```
+    pub fn colors(&self) -> u32 {
+        self.get_number(NumberCapability::MaxColors).unwrap_or(0) as u32
+    }
+
+    /// Returns the number of color pairs (`pairs`), 0 if it's absent or cancelled
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert_eq!(TermInfo::from_file("tests/data/linux").unwrap().pairs(), 64);
+    /// ```
+    pub fn pairs(&self) -> u32 {
+        self.get_number(NumberCapability::MaxPairs).unwrap_or(0) as u32
+    }
+
+    /// Returns true if the terminal has at least 8 colors and can set the foreground color
+    /// (`setaf` or `setf`), like curses `has_colors`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/linux").unwrap().has_colors());
+    /// assert!(!TermInfo::from_file("tests/data/vt100").unwrap().has_colors());
+    /// ```
+    pub fn has_colors(&self) -> bool {
+        self.colors() >= 8
+            && (self.get_string(StringCapability::SetAnsiForeground).is_some()
+                || self.get_string(StringCapability::SetForeground).is_some())
+    }
+
+    /// Returns true if the terminal can't show colors, see `has_colors`
+    pub fn monochrome(&self) -> bool {
+        !self.has_colors()
+    }
+
+    /// Sets the color policy consulted by the color helpers, `ColorPolicy::Auto` by default
+    ///
+    /// With `ColorPolicy::Never` the sequences setting colors are empty, `reset_colors` only
+    /// resets the attributes. `ColorPolicy::Always` uses the ANSI colors on terminals without
+    /// colors (`has_colors`).
+    ///
+    /// # Arguments
+    /// * `policy` - color policy, e.g. `ColorPolicy::from_env()`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::terminfo::policy::ColorPolicy;
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// info.set_color_policy(ColorPolicy::Never);
+    /// assert_eq!(info.set_foreground_color(1).unwrap(), b"");
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/vt100").unwrap();
+    /// info.set_color_policy(ColorPolicy::Always);
+    /// assert_eq!(info.set_foreground_color(1).unwrap(), b"\x1B[31m");
+    /// ```
+    pub fn set_color_policy(&mut self, policy: ColorPolicy) {
+        self.color_policy = policy;
+    }
+
+    /// Returns the color policy, see `set_color_policy`
+    pub fn color_policy(&self) -> ColorPolicy {
+        self.color_policy
+    }
+
+    /// Reads `COLORTERM` from env for `color_support` and the 24-bit color helpers, by default
+    /// the environment of the process is read on every call
+    ///
+    /// # Arguments
+    /// * `env` - environment variables, e.g. `ProcessEnv`
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::ext::ColorSupport;
+    ///
+    /// let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// info.set_colorterm_from_provider(&HashMap::new());
+    /// assert_eq!(info.color_support(), ColorSupport::Colors(256));
+    /// assert_eq!(info.set_rgb_foreground(1, 2, 3), None);
+    /// ```
+    pub fn set_colorterm_from_provider<E: EnvProvider>(&mut self, env: &E) {
+        self.colorterm_truecolor = Some(announces_truecolor(env));
+    }
+
+    /// Returns true if `COLORTERM` announces 24-bit colors, see `set_colorterm_from_provider`
+    pub(crate) fn colorterm_truecolor(&self) -> bool {
+        self.colorterm_truecolor.unwrap_or_else(|| announces_truecolor(&ProcessEnv))
+    }
+
+    /// Returns true if the color policy turns off colors
+    pub(crate) fn colors_suppressed(&self) -> bool {
+        self.color_policy == ColorPolicy::Never
+    }
+
+    /// Returns true if the color policy forces the ANSI colors on a terminal without colors
+    pub(crate) fn forces_ansi_colors(&self) -> bool {
+        self.color_policy == ColorPolicy::Always && !self.has_colors()
+    }
+
+    /// Returns true if the terminal can redefine its colors (`ccc`), see `define_color`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/linux").unwrap().can_change_colors());
+    /// assert!(!TermInfo::from_file("tests/data/cx-ext").unwrap().can_change_colors());
+    /// ```
+    pub fn can_change_colors(&self) -> bool {
+        self.get_bool(BoolCapability::CanChange) == Some(true)
+    }
+
+    /// Returns the sequence to redefine a color of the palette with `initc`
+    ///
+    /// `initc` takes red, green and blue scaled to 0-1000, the values are rounded up so that
+    /// terminals scaling back with integer division get the same 0-255 value. Terminals with
+    /// `hls` take hue (0-360, blue at 0), lightness and saturation (0-100) instead.
+    ///
+    /// # Arguments
+    /// * `color` - color number as used with `setaf`
+    /// * `r`     - red
+    /// * `g`     - green
+    /// * `b`     - blue
+    ///
+    /// # Errors
+    /// * `FormatError::ColorOutOfRange` - color is not below `max_colors`
+    /// * `FormatError::CapabilityNotFound(InitializeColor)` - `initc` is not defined
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.define_color(1, 255, 128, 0).unwrap(), b"\x1B]4;1;rgb:FF/80/00\x1B\\");
+    /// ```
+    pub fn define_color(&self, color: u16, r: u8, g: u8, b: u8) -> Result<Vec<u8>, FormatError> {
+        if let Some(colors) = self.get_number(NumberCapability::MaxColors) {
+            if color as i32 >= colors {
+                return Err(FormatError::ColorOutOfRange(color));
+            }
+        }
+
+        let (x, y, z) = self.color_params(r, g, b);
+        let params = [Param::Number(color as i32), Param::Number(x), Param::Number(y),
+                      Param::Number(z)];
+        self.format(StringCapability::InitializeColor, &params)
+    }
+
+    /// Returns the parameters of a color for `initc` and `initp`, r, g, b scaled to 0-1000 or
+    /// HLS if the terminal has `hls`
+    fn color_params(&self, r: u8, g: u8, b: u8) -> (i32, i32, i32) {
+        let scale = |value: u8| (value as i32 * 1000 + 254) / 255;
+        let (r, g, b) = (scale(r), scale(g), scale(b));
+        match self.get_bool(BoolCapability::HueLightnessSaturation) {
+            Some(true) => rgb_to_hls(r, g, b),
+            _ => (r, g, b),
+        }
+    }
+
+    /// Returns the sequence to restore the colors changed by `define_color` (`oc`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// assert_eq!(info.reset_palette().unwrap(), b"\x1B]R");
+    /// ```
+    pub fn reset_palette(&self) -> Result<Vec<u8>, FormatError> {
+        self.format(StringCapability::OrigColors, &[])
+    }
+
+    /// Returns the sequence moving the cursor to row and col (zero-based)
+    ///
+    /// Uses `cup`, otherwise the row is set with `vpa` or `home` and moving down, the column
+    /// with `hpa` or `cr` and moving right. Moves use the parameterized (`cud`, `cuf`) or the
+    /// repeated single step capability (`cud1`, `cuf1`), whichever is shorter. One-based
+    /// capabilities (`%i`) are handled by the evaluation.
+    ///
+    /// # Arguments
+    /// * `row` - zero-based row
+    /// * `col` - zero-based column
+    ///
+    /// # Errors
+    /// `FormatError::CapabilityNotFound(CursorAddress)` if no combination of capabilities can
+    /// reach the position
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.goto(0, 0).unwrap(), b"\x1B[1;1H");
+    /// assert_eq!(info.goto(23, 79).unwrap(), b"\x1B[24;80H");
+    /// ```
+    pub fn goto(&self, row: u16, col: u16) -> Result<Vec<u8>, FormatError> {
+        let not_found = || FormatError::CapabilityNotFound(StringCapability::CursorAddress);
+        let cup = [Param::Number(row as i32), Param::Number(col as i32)];
+        match self.format(StringCapability::CursorAddress, &cup) {
+            Err(FormatError::CapabilityNotFound(_)) => {}
+            result => return result,
+        }
+
+        // after home the cursor is in the first column
+        let vpa = self.format(StringCapability::RowAddress, &cup[..1]);
+        let (mut output, first_column) = match vpa {
+            Ok(vpa) => (vpa, false),
+            Err(FormatError::CapabilityNotFound(_)) => {
+                let mut home = self
+                    .format(StringCapability::CursorHome, &[])
+                    .map_err(|_| not_found())?;
+                home.extend(self.move_down(row).ok_or_else(not_found)?);
+                (home, true)
+            }
+            Err(err) => return Err(err),
+        };
+
+        match self.format(StringCapability::ColumnAddress, &cup[1..]) {
+            Ok(hpa) => output.extend(hpa),
+            Err(FormatError::CapabilityNotFound(_)) => {
+                if !first_column {
+                    let cr = self
+                        .get_string_or_default(StringCapability::CarriageReturn)
+                        .ok_or_else(not_found)?;
+                    output.extend(cr.chars().map(|c| c as u8));
+                }
+                output.extend(self.move_right(col).ok_or_else(not_found)?);
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(output)
+    }
+
+    /// Returns the sequence moving the cursor n cells up or Option::None if neither `cuu`
+    /// nor `cuu1` is defined
+    ///
+    /// The shorter of `cuu` and `cuu1` repeated n times is used (`cuu` if both are equally
+    /// long), n = 0 returns an empty sequence.
+    ///
+    /// # Arguments
+    /// * `n` - number of cells
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.move_up(1).unwrap(), b"\x1B[A");
+    /// assert_eq!(info.move_up(3).unwrap(), b"\x1B[3A");
+    /// assert_eq!(info.move_up(0).unwrap(), b"");
+    /// ```
+    pub fn move_up(&self, n: u16) -> Option<Vec<u8>> {
+        self.relative_route(StringCapability::ParmUpCursor, StringCapability::CursorUp, n)
+            .map(|route| route.bytes)
+    }
+
+    /// Returns the sequence moving the cursor n cells down or Option::None if neither `cud`
+    /// nor `cud1` is defined
+    ///
+    /// The shorter of `cud` and `cud1` repeated n times is used (`cud` if both are equally
+    /// long), n = 0 returns an empty sequence.
+    ///
+    /// # Arguments
+    /// * `n` - number of cells
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.move_down(2).unwrap(), b"\n\n");
+    /// assert_eq!(info.move_down(10).unwrap(), b"\x1B[10B");
+    /// ```
+    pub fn move_down(&self, n: u16) -> Option<Vec<u8>> {
+        self.relative_route(StringCapability::ParmDownCursor, StringCapability::CursorDown, n)
+            .map(|route| route.bytes)
+    }
+
+    /// Returns the sequence moving the cursor n cells left or Option::None if neither `cub`
+    /// nor `cub1` is defined
+    ///
+    /// The shorter of `cub` and `cub1` repeated n times is used (`cub` if both are equally
+    /// long), n = 0 returns an empty sequence.
+    ///
+    /// # Arguments
+    /// * `n` - number of cells
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.move_left(3).unwrap(), b"\x08\x08\x08");
+    /// assert_eq!(info.move_left(5).unwrap(), b"\x1B[5D");
+    /// ```
+    pub fn move_left(&self, n: u16) -> Option<Vec<u8>> {
+        self.relative_route(StringCapability::ParmLeftCursor, StringCapability::CursorLeft, n)
+            .map(|route| route.bytes)
+    }
+
+    /// Returns the sequence moving the cursor n cells right or Option::None if neither `cuf`
+    /// nor `cuf1` is defined
+    ///
+    /// The shorter of `cuf` and `cuf1` repeated n times is used (`cuf` if both are equally
+    /// long), n = 0 returns an empty sequence.
+    ///
+    /// # Arguments
+    /// * `n` - number of cells
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.move_right(1).unwrap(), b"\x1B[C");
+    /// assert_eq!(info.move_right(80).unwrap(), b"\x1B[80C");
+    /// ```
+    pub fn move_right(&self, n: u16) -> Option<Vec<u8>> {
+        self.relative_route(StringCapability::ParmRightCursor, StringCapability::CursorRight, n)
+            .map(|route| route.bytes)
+    }
+
+    /// Returns the sequence moving the cursor from one position to another or Option::None if
+    /// the terminal can't address the target
+    ///
+    /// The cheapest combination of `cup`, `home`, `vpa`/`hpa`, `cr` and parameterized or
+    /// repeated single step moves is used. The cost is the number of bytes plus 1 per
+    /// millisecond of mandatory padding (1 byte takes about 1ms at 9600 baud). Relative moves
+    /// are only used from a known position.
+    ///
+    /// A column equal to the width is the position after writing the last column. With `am` the
+    /// cursor wrapped to the next line, with `xenl` too the position is unknown until the next
+    /// output, without `am` the cursor stays in the last column.
+    ///
+    /// # Arguments
+    /// * `from` - current position (row, col) or Option::None if unknown
+    /// * `to`   - target position (row, col)
+    /// * `size` - screen size (lines, columns)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.move_cursor(None, (4, 9), (24, 80)).unwrap(), b"\x1B[5;10H");
+    /// assert_eq!(info.move_cursor(None, (0, 0), (24, 80)).unwrap(), b"\x1B[H");
+    /// assert_eq!(info.move_cursor(Some((4, 9)), (4, 9), (24, 80)).unwrap(), b"");
+    /// assert_eq!(info.move_cursor(Some((4, 9)), (5, 0), (24, 80)).unwrap(), b"\n\r");
+    /// assert_eq!(info.move_cursor(Some((4, 9)), (4, 7), (24, 80)).unwrap(), b"\x08\x08");
+    /// ```
+    pub fn move_cursor(&self,
+                       from: Option<(u16, u16)>,
+                       to: (u16, u16),
+                       size: (u16, u16)) -> Option<Vec<u8>> {
+        let from = from.and_then(|(row, col)| self.wrapped_position(row, col, size));
+        if from == Some(to) {
+            return Some(Vec::new());
+        }
+
+        let (row, col) = to;
+        let from_row = from.map(|(row, _)| row);
+        let from_col = from.map(|(_, col)| col);
+        let cup = [Param::Number(row as i32), Param::Number(col as i32)];
+        let routes = [
+            self.route(StringCapability::CursorAddress, &cup),
+            self.vertical_route(from_row, row)
+                .and_then(|route| Some(route.then(self.horizontal_route(from_col, col)?))),
+            self.route(StringCapability::CursorHome, &[])
+                .and_then(|route| Some(route.then(self.vertical_route(Some(0), row)?)))
+                .and_then(|route| Some(route.then(self.horizontal_route(Some(0), col)?))),
+        ];
+
+        // the first one wins on equal cost, cup is preferred
+        routes.iter()
+              .flatten()
+              .min_by_key(|route| route.cost)
+              .map(|route| route.bytes.clone())
+    }
+
+    /// Returns the position of the cursor after it was placed at col (see `move_cursor`) or
+    /// Option::None if it's unknown
+    fn wrapped_position(&self, row: u16, col: u16, size: (u16, u16)) -> Option<(u16, u16)> {
+        let (lines, cols) = size;
+        if col < cols {
+            return Some((row, col));
+        }
+
+        match (self.auto_margin(), self.has_newline_glitch()) {
+            (false, _) => Some((row, cols.saturating_sub(1))),
+            (true, false) => Some(((row + 1).min(lines.saturating_sub(1)), 0)),
+            (true, true) => None,
+        }
+    }
+
+    /// Returns the cheapest route to row with `vpa` or relative moves from a known row
+    fn vertical_route(&self, from: Option<u16>, row: u16) -> Option<Route> {
+        let relative = from.and_then(|from| match from > row {
+            true => self.relative_route(StringCapability::ParmUpCursor,
+                                        StringCapability::CursorUp,
+                                        from - row),
+            false => self.relative_route(StringCapability::ParmDownCursor,
+                                         StringCapability::CursorDown,
+                                         row - from),
+        });
+        let vpa = self.route(StringCapability::RowAddress, &[Param::Number(row as i32)]);
+        cheapest(relative, vpa)
+    }
+
+    /// Returns the cheapest route to col with `hpa`, `cr` and moving right or relative moves
+    /// from a known column
+    fn horizontal_route(&self, from: Option<u16>, col: u16) -> Option<Route> {
+        let relative = from.and_then(|from| match from > col {
+            true => self.relative_route(StringCapability::ParmLeftCursor,
+                                        StringCapability::CursorLeft,
+                                        from - col),
+            false => self.relative_route(StringCapability::ParmRightCursor,
+                                         StringCapability::CursorRight,
+                                         col - from),
+        });
+        let hpa = self.route(StringCapability::ColumnAddress, &[Param::Number(col as i32)]);
+        let right = || self.relative_route(StringCapability::ParmRightCursor,
+                                           StringCapability::CursorRight,
+                                           col);
+        let cr = self.route(StringCapability::CarriageReturn, &[])
+                     .and_then(|cr| Some(cr.then(right()?)));
+        cheapest(cheapest(relative, hpa), cr)
+    }
+
+    /// Returns the cheaper route moving n cells with the parameterized capability or the
+    /// repeated single step capability or Option::None if neither is usable
+    fn relative_route(&self,
+                      parm: StringCapability,
+                      single: StringCapability,
+                      n: u16) -> Option<Route> {
+        if n == 0 {
+            return Some(Route::default());
+        }
+
+        let parm = self.route(parm, &[Param::Number(n as i32)]);
+        let repeated = self.route(single, &[]).map(|step| Route {
+            bytes: step.bytes.repeat(n as usize),
+            cost: step.cost * n as usize,
+        });
+        cheapest(parm, repeated)
+    }
+
+    /// Returns the evaluated capability without padding delays and its cost, see `move_cursor`
+    fn route(&self, cap: StringCapability, params: &[Param]) -> Option<Route> {
+        let compiled = self.compiled(CapabilityKey::Standard(cap), || self.get_string(cap))?.ok()?;
+        let delay = compiled.measure(params).ok()?.delay_tenths_ms as usize;
+        let output = compiled.eval(params).ok()?.chars().map(|c| c as u8).collect();
+        let bytes = strip_padding(output);
+        Some(Route { cost: bytes.len() + delay / 10, bytes })
+    }
+
+    fn format_key(&self,
+                  key: CapabilityKey,
+                  value: impl FnOnce() -> Option<String>,
+                  not_found: impl FnOnce() -> FormatError,
+                  params: &[Param],
+                  keep_padding: bool) -> Result<Vec<u8>, FormatError> {
+        let output: Vec<u8> = match self.compiled(key, value) {
+            Some(Ok(compiled)) => compiled.eval_bytes(params)?,
+            Some(Err(err)) => return Err(FormatError::Eval(err)),
+            None => return Err(not_found()),
+        };
+        match keep_padding {
+            true => Ok(output),
+            false => Ok(strip_padding(output)),
+        }
+    }
+
+    /// Returns the bell sequence or Option::None if the terminal has neither an audible nor a
+    /// visual bell
+    ///
+    /// The preferred kind is returned if available, the other one otherwise. The visual bell
+    /// usually contains padding delays, write it with `write_capability`.
+    ///
+    /// # Arguments
+    /// * `prefer_visual` - prefer `flash` over `bel`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{BellSequence, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.bell_sequence(false), Some(BellSequence::Audible("\x07".to_string())));
+    /// assert_eq!(info.bell_sequence(true),
+    ///            Some(BellSequence::Visual("\x1B[?5h$<100/>\x1B[?5l".to_string())));
+    /// ```
+    pub fn bell_sequence(&self, prefer_visual: bool) -> Option<BellSequence> {
+        let audible = || self.get_string(StringCapability::Bell).map(BellSequence::Audible);
+        let visual = || self.get_string(StringCapability::FlashScreen).map(BellSequence::Visual);
+        match prefer_visual {
+            true => visual().or_else(audible),
+            false => audible().or_else(visual),
+        }
+    }
+
+    /// Returns the sequence to enter keypad transmit mode (`smkx`) or Option::None
+    ///
+    /// In keypad transmit mode the function and arrow keys send the sequences described by the
+    /// `Key*` capabilities. Applications entering the mode must always leave it with
+    /// `keypad_mode_exit` before they exit, also on errors and signals, otherwise the keys of the
+    /// shell stay in application mode.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.keypad_mode_enter(), Some("\x1B[?1h\x1B=".to_string()));
+    /// ```
+    pub fn keypad_mode_enter(&self) -> Option<String> {
+        self.get_string(StringCapability::KeypadXmit)
+    }
+
+    /// Returns the sequence to leave keypad transmit mode (`rmkx`) or Option::None
+    ///
+    /// Restores the normal key sequences, see `keypad_mode_enter`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.keypad_mode_exit(), Some("\x1B[?1l\x1B>".to_string()));
+    /// ```
+    pub fn keypad_mode_exit(&self) -> Option<String> {
+        self.get_string(StringCapability::KeypadLocal)
+    }
+
+    /// Returns the sequences to enter and to leave full-screen mode
+    ///
+    /// Enter switches to the alternate screen (`smcup`) and, with keypad, to keypad transmit
+    /// mode (`smkx`). The second sequence is `leave_fullscreen`, it can be registered with a
+    /// panic hook before entering. Terminals without `smcup` (e.g. `vt100`) clear the screen
+    /// instead, missing capabilities are left out and `dumb` gets empty sequences.
+    ///
+    /// # Arguments
+    /// * `keypad` - enter and leave keypad transmit mode
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let (enter, leave) = info.enter_fullscreen(false);
+    /// assert_eq!(enter, b"\x1B[?1049h\x1B[22;0;0t");
+    /// assert_eq!(leave, b"\x1B[?12l\x1B[?25h\x1B[?1049l\x1B[23;0;0t");
+    /// ```
+    pub fn enter_fullscreen(&self, keypad: bool) -> (Vec<u8>, Vec<u8>) {
+        let mut enter = self.sequence(StringCapability::EnterAlternativeMode)
+                            .or_else(|| self.sequence(StringCapability::ClearScreen))
+                            .unwrap_or_default();
+        if keypad {
+            enter.extend(self.sequence(StringCapability::KeypadXmit).unwrap_or_default());
+        }
+        (enter, self.leave_fullscreen(keypad))
+    }
+
+    /// Returns the sequence to leave full-screen mode, see `enter_fullscreen`
+    ///
+    /// Shows the cursor (`cnorm`), leaves keypad transmit mode (`rmkx`) with keypad and
+    /// returns to the normal screen (`rmcup`), or clears the screen if there is no `rmcup`.
+    ///
+    /// # Arguments
+    /// * `keypad` - leave keypad transmit mode
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/vt100").unwrap();
+    /// assert_eq!(info.leave_fullscreen(true), b"\x1B[?1l\x1B>\x1B[H\x1B[J");
+    /// ```
+    pub fn leave_fullscreen(&self, keypad: bool) -> Vec<u8> {
+        let mut leave = self.sequence(StringCapability::CursorNormal).unwrap_or_default();
+        if keypad {
+            leave.extend(self.sequence(StringCapability::KeypadLocal).unwrap_or_default());
+        }
+        leave.extend(self.sequence(StringCapability::ExitAlternativeMode)
+                         .or_else(|| self.sequence(StringCapability::ClearScreen))
+                         .unwrap_or_default());
+        leave
+    }
+
+    /// Evaluates a capability without parameters and padding, Option::None if it's absent
+    fn sequence(&self, cap: StringCapability) -> Option<Vec<u8>> {
+        self.format(cap, &[]).ok()
+    }
+
+    /// Returns the sequence to enter the alternate character set (`smacs`) or Option::None
+    ///
+    /// While the alternate character set is active the characters of the `acsc` map
+    /// (`AcsChars`) are drawn as line and box graphics. The map is a list of pairs, the VT100
+    /// name of a graphic (e.g. `q` for a horizontal line, `l` for the upper left corner) followed
+    /// by the character the terminal prints for it. Characters outside of the map are
+    /// undefined in this mode, leave it with `alt_charset_exit` before writing text.
+    ///
+    /// Terminals using VT100 charset designation require `enacs` (`EnableAlternateCharSet`) to
+    /// be sent once before, it designates the graphics set as G1 (`\E)0`) which `smacs` then
+    /// selects. `smacs` of most current terminals designates the set as G0 directly
+    /// (`\E(0`) and doesn't need `enacs`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.get_string(StringCapability::EnableAlternateCharSet), None);
+    /// assert_eq!(info.alt_charset_enter(), Some("\x1B(0".to_string()));
+    /// ```
+    pub fn alt_charset_enter(&self) -> Option<String> {
+        self.get_string(StringCapability::EnterAltCharsetMode)
+    }
+
+    /// Returns the sequence to leave the alternate character set (`rmacs`) or Option::None
+    ///
+    /// Restores the normal character set, see `alt_charset_enter`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.alt_charset_exit(), Some("\x1B(B".to_string()));
+    /// ```
+    pub fn alt_charset_exit(&self) -> Option<String> {
+        self.get_string(StringCapability::ExitAltCharsetMode)
+    }
+
+    /// Returns the sequence to enter protected mode (`prot`) or Option::None
+    ///
+    /// Characters written in protected mode can't be erased by the selective erase functions
+    /// of the terminal, used by form based applications of block mode terminals like the IBM
+    /// 3270. Few current terminals support it.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.protected_mode_enter(), None);
+    /// ```
+    pub fn protected_mode_enter(&self) -> Option<String> {
+        self.get_string(StringCapability::EnterProtectedMode)
+    }
+
+    /// Returns the sequence to leave protected mode or Option::None if the terminal has no
+    /// protected mode
+    ///
+    /// Terminfo has no capability ending protected mode only, it's an attribute turned off with
+    /// all others by `sgr0`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.protected_mode_exit(), None);
+    /// ```
+    pub fn protected_mode_exit(&self) -> Option<String> {
+        self.get_string(StringCapability::EnterProtectedMode)?;
+        self.get_string(StringCapability::ExitAttributeMode)
+    }
+
+    /// Returns the sequence resetting attributes and colors (`sgr0` followed by `op`, or `oc`
+    /// if `op` is missing) or Option::None if the terminal has neither
+    ///
+    /// `sgr0` turns off all attributes (bold, underline, reverse, ...), on some terminals it
+    /// doesn't change the colors. `op` only sets the default color pair and keeps the
+    /// attributes. `oc` restores the colors of all pairs, on terminals without `op` it's the
+    /// only way back to the default colors. The color reset comes last, so the default colors
+    /// win if `sgr0` sets colors. Only `sgr0` is used if colors are turned off by the color
+    /// policy (`set_color_policy`).
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.reset_colors(), Some("\x1B(B\x1B[m\x1B[39;49m".to_string()));
+    /// ```
+    pub fn reset_colors(&self) -> Option<String> {
+        match (self.get_string(StringCapability::ExitAttributeMode), self.default_colors()) {
+            (Some(sgr0), Some(colors)) => Some(sgr0 + &colors),
+            (sgr0, colors) => sgr0.or(colors),
+        }
+    }
+
+    /// Returns `op` or `oc`, Option::None if colors are turned off by the color policy
+    fn default_colors(&self) -> Option<String> {
+        if self.colors_suppressed() {
+            return None;
+        }
+
+        self.get_string(StringCapability::OrigColorPair)
+            .or_else(|| self.get_string(StringCapability::OrigColors))
+    }
+
+    /// Returns the sequence setting exactly the given attributes or Option::None
+    ///
+    /// Same as `sgr_attrs` with the attributes as bools.
+    ///
+    /// # Arguments
+    /// * `bold`      - extra bright
+    /// * `underline` - underlined
+    /// * `blink`     - blinking
+    /// * `reverse`   - reverse video
+    /// * `invisible` - invisible text
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.sgr(true, false, false, false, false), Some("\x1B(B\x1B[0;1m".to_string()));
+    /// assert_eq!(info.sgr(true, true, false, true, false), Some("\x1B(B\x1B[0;1;4;7m".to_string()));
+    /// assert_eq!(info.sgr(false, false, false, false, false), Some("\x1B(B\x1B[0m".to_string()));
+    /// ```
+    pub fn sgr(&self,
+               bold: bool,
+               underline: bool,
+               blink: bool,
+               reverse: bool,
+               invisible: bool) -> Option<String> {
+        let attrs = [(bold, AttrSet::BOLD),
+                     (underline, AttrSet::UNDERLINE),
+                     (blink, AttrSet::BLINK),
+                     (reverse, AttrSet::REVERSE),
+                     (invisible, AttrSet::INVISIBLE)];
+        self.sgr_attrs(attrs.iter()
+                            .filter(|(on, _)| *on)
+                            .fold(AttrSet::EMPTY, |set, (_, attr)| set | *attr))
+    }
+
+    /// Returns the sequence setting exactly the given attributes or Option::None
+    ///
+    /// Evaluates `sgr`, attributes not given are turned off. Without `sgr` the sequence is
+    /// `sgr0` followed by the sequences of the attributes (`smso`, `bold`, `dim`, `smul`,
+    /// `blink`, `rev`, `invis`, `prot`, `smacs`), Option::None is returned if one of them is
+    /// missing. Italic isn't part of `sgr`, `sitm` is appended. The attributes are set as
+    /// given, see `sgr_with_colors` for colored text.
+    ///
+    /// # Arguments
+    /// * `attrs` - attributes to set
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{AttrSet, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// let attrs = AttrSet::BOLD | AttrSet::UNDERLINE;
+    /// assert_eq!(info.sgr_attrs(attrs), Some("\x1B[0;10;4;1m\x0F".to_string()));
+    /// ```
+    pub fn sgr_attrs(&self, attrs: AttrSet) -> Option<String> {
+        let italic = match attrs.contains(AttrSet::ITALIC) {
+            true => self.get_string(StringCapability::EnterItalicsMode)?,
+            false => String::new(),
+        };
+
+        if let Some(sgr) = self.get_string(StringCapability::SetAttributes) {
+            return evaluate(sgr.as_str(), &attrs.sgr_params()).ok().map(|seq| seq + &italic);
+        }
+
+        ATTRIBUTE_MODES.iter()
+                       .filter(|(attr, _)| attrs.contains(*attr))
+                       .try_fold(self.get_string(StringCapability::ExitAttributeMode)?,
+                                 |seq, (_, cap)| Some(seq + &self.get_string(*cap)?))
+                       .map(|seq| seq + &italic)
+    }
+
+    /// Returns the sequence setting the attributes for colored text or Option::None
+    ///
+    /// Attributes the terminal can't combine with colors (`color_incompatible_attrs`) are
+    /// dropped, e.g. underline is shown as a color on the Linux console. Use `sgr_attrs` to set
+    /// them anyway.
+    ///
+    /// # Arguments
+    /// * `attrs` - attributes to set
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{AttrSet, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// let attrs = AttrSet::BOLD | AttrSet::UNDERLINE;
+    /// assert_eq!(info.sgr_with_colors(attrs), Some("\x1B[0;10;1m\x0F".to_string()));
+    /// ```
+    pub fn sgr_with_colors(&self, attrs: AttrSet) -> Option<String> {
+        self.sgr_attrs(attrs - self.color_incompatible_attrs())
+    }
+
+    /// Returns the attributes that can't be combined with colors (`ncv`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{AttrSet, TermInfo};
+    ///
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// assert_eq!(info.color_incompatible_attrs(), AttrSet::UNDERLINE | AttrSet::DIM);
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert!(info.color_incompatible_attrs().is_empty());
+    /// ```
+    pub fn color_incompatible_attrs(&self) -> AttrSet {
+        match self.get_number(NumberCapability::NoColorVideo) {
+            Some(ncv) if ncv > 0 => AttrSet::from_bits(ncv as u16),
+            _ => AttrSet::EMPTY,
+        }
+    }
+
+    /// Returns the sequence to request a cursor position report or Option::None
+    ///
+    /// Most terminals describe the request as `u7` (`User7`) capability. The answer can be
+    /// read with `response::parse_cursor_position_report`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::response::report_cursor_position_query;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     let query = info.report_cursor_position()
+    ///                     .unwrap_or_else(|| report_cursor_position_query().to_string());
+    ///     println!("{:?}", query);
+    /// }
+    /// ```
+    pub fn report_cursor_position(&self) -> Option<String> {
+        self.get_string(StringCapability::User7)
+    }
+
+    /// Returns the sequence which asks the terminal for its primary device attributes (DA1)
+    ///
+    /// The answer can be read with `parse_device_attributes`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert_eq!(TermInfo::device_attributes_query(), "\x1B[c");
+    /// ```
+    pub fn device_attributes_query() -> &'static str {
+        DEVICE_ATTRIBUTES_QUERY
+    }
+
+    /// Returns the sequence which asks the terminal for its secondary device attributes (DA2)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert_eq!(TermInfo::secondary_device_attributes_query(), "\x1B[>c");
+    /// ```
+    pub fn secondary_device_attributes_query() -> &'static str {
+        SECONDARY_DEVICE_ATTRIBUTES_QUERY
+    }
+
+    /// Parse a primary (`ESC [?class;...c`) or secondary (`ESC [>type;version;romc`) device
+    /// attributes response
+    ///
+    /// Returns Option::None if the response is not a valid device attributes response.
+    ///
+    /// # Arguments
+    /// * `response` - bytes read from the terminal
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::response::AttributesKind;
+    ///
+    /// let da1 = TermInfo::parse_device_attributes(b"\x1B[?62;1;6;22c").unwrap();
+    /// assert_eq!(da1.kind, AttributesKind::Primary);
+    /// assert_eq!(da1.class, 62);
+    /// assert_eq!(da1.extensions, vec![1, 6, 22]);
+    ///
+    /// let da2 = TermInfo::parse_device_attributes(b"\x1B[>41;354;0c").unwrap();
+    /// assert_eq!(da2.kind, AttributesKind::Secondary);
+    /// assert_eq!(da2.class, 41);
+    /// assert_eq!(da2.extensions, vec![354, 0]);
+    ///
+    /// assert_eq!(TermInfo::parse_device_attributes(b"\x1B[12;40R"), None);
+    /// ```
+    pub fn parse_device_attributes(response: &[u8]) -> Option<DeviceAttributes> {
+        let params = strip_csi(response, b'c')?;
+        let kind = match params.first() {
+            Some(b'?') => AttributesKind::Primary,
+            Some(b'>') => AttributesKind::Secondary,
+            _ => return None,
+        };
+
+        let mut parts = params[1..].split(|c| *c == b';');
+        let class = parse_u16(parts.next()?)?;
+        if class > u8::MAX as u16 {
+            return None;
+        }
+
+        let mut extensions = Vec::new();
+        for part in parts {
+            extensions.push(parse_u16(part)?);
+        }
+
+        Some(DeviceAttributes {
+            kind,
+            class: class as u8,
+            extensions,
+        })
+    }
+
+    /// Returns the sequence to set the window title or Option::None if the title contains
+    /// control characters
+    ///
+    /// Uses the status line capabilities (`tsl`, `fsl`) if available, otherwise OSC 2.
+    ///
+    /// # Arguments
+    /// * `title` - new window title
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     print!("{}", info.set_window_title("cx-terminfo").unwrap());
+    ///     assert_eq!(info.set_window_title("bell\x07"), None);
+    /// }
+    /// ```
+    pub fn set_window_title(&self, title: &str) -> Option<String> {
+        self.title_sequence(2, title)
+    }
+
+    /// Returns the sequence to set the icon name or Option::None if the name contains control
+    /// characters
+    ///
+    /// Uses the status line capabilities (`tsl`, `fsl`) if available, otherwise OSC 1.
+    ///
+    /// # Arguments
+    /// * `name` - new icon name
+    pub fn set_icon_name(&self, name: &str) -> Option<String> {
+        self.title_sequence(1, name)
+    }
+
+    /// Returns the sequence to set window title and icon name or Option::None if one of them
+    /// contains control characters
+    ///
+    /// If title and icon name are the same a single sequence (OSC 0) is used.
+    ///
+    /// # Arguments
+    /// * `title` - new window title
+    /// * `icon`  - new icon name
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     print!("{}", info.set_title_and_icon("~/src - vim", "vim").unwrap());
+    /// }
+    /// ```
+    pub fn set_title_and_icon(&self, title: &str, icon: &str) -> Option<String> {
+        if title == icon {
+            return self.title_sequence(0, title);
+        }
+
+        Some(self.title_sequence(1, icon)? + &self.title_sequence(2, title)?)
+    }
+
+    /// Returns the title sequence for OSC `kind` (0 = both, 1 = icon name, 2 = window title)
+    fn title_sequence(&self, kind: u8, text: &str) -> Option<String> {
+        if text.chars().any(|c| c.is_control()) {
+            return None;
+        }
+
+        match (self.get_string(StringCapability::ToStatusLine),
+               self.get_string(StringCapability::FromStatusLine)) {
+            (Some(to), Some(from)) => {
+                let to = evaluate(to.as_str(), &[Param::Number(0)]).ok()?;
+                // status line written with OSC (e.g. tmux `\E]0;`), select title or icon name
+                let osc = to.strip_prefix("\x1B]")
+                            .and_then(|rest| rest.strip_suffix(';'))
+                            .filter(|ps| ps.len() == 1 && "012".contains(*ps));
+                match osc {
+                    Some(_) => Some(format!("\x1B]{};{}{}", kind, text, from)),
+                    None => Some(format!("{}{}{}", to, text, from)),
+                }
+            }
+            _ => Some(format!("\x1B]{};{}\x07", kind, text)),
+        }
+    }
+
+    /// Returns true if the terminal has a status line (`hs`)
+    ///
+    /// Terminal emulators describe their window title as status line, e.g. tmux.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/tmux-256color").unwrap().supports_status_line());
+    /// assert!(!TermInfo::from_file("tests/data/xterm-256color").unwrap().supports_status_line());
+    /// ```
+    pub fn supports_status_line(&self) -> bool {
+        self.get_bool(BoolCapability::HasStatusLine) == Some(true)
+    }
+
+    /// Returns the sequence writing text to the status line (`tsl` to column 0, text, `fsl`) or
+    /// Option::None if the terminal has no status line or the text contains control characters
+    ///
+    /// # Arguments
+    /// * `text` - status line text
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.set_status_line("make: ok"), Some("\x1B]0;make: ok\x07".to_string()));
+    /// assert_eq!(info.set_status_line("\x1B[2J"), None);
+    /// ```
+    pub fn set_status_line(&self, text: &str) -> Option<String> {
+        if !self.supports_status_line() || text.chars().any(|c| c.is_control()) {
+            return None;
+        }
+
+        let to = self.get_string(StringCapability::ToStatusLine)?;
+        let from = self.get_string(StringCapability::FromStatusLine)?;
+        Some(evaluate(to.as_str(), &[Param::Number(0)]).ok()? + text + &from)
+    }
+
+    /// Returns the sequence clearing the status line (`dsl`) or Option::None
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    /// assert_eq!(info.clear_status_line(), Some("\x1B]0;\x07".to_string()));
+    /// ```
+    pub fn clear_status_line(&self) -> Option<String> {
+        match self.supports_status_line() {
+            true => self.get_string(StringCapability::DisStatusLine),
+            false => None,
+        }
+    }
+
+    /// Evaluate a string capability and write it to writer, padding delays (`$<n>`) are
+    /// handled by sleeping the current thread
+    ///
+    /// Returns the number of bytes written. Proportional delays (`$<n*>`) are for one line, see
+    /// `write_capability_with`.
+    ///
+    /// # Arguments
+    /// * `cap`      - string capability
+    /// * `params`   - array of parameters
+    /// * `writer`   - terminal output
+    /// * `baudrate` - output speed, padding is skipped below the `PaddingBaudRate` of the terminal
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::param_string::Param;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let params = [Param::Number(10), Param::Number(10)];
+    /// let mut out = Vec::new();
+    /// let written = info.write_capability(StringCapability::CursorAddress, &params, &mut out, None);
+    /// assert_eq!(written.unwrap(), 8);
+    /// assert_eq!(out, b"\x1B[11;11H");
+    /// ```
+    pub fn write_capability(&self,
+                            cap: StringCapability,
+                            params: &[Param],
+                            writer: &mut dyn Write,
+                            baudrate: Option<u32>) -> Result<usize, TermInfoSendError> {
+        self.write_capability_with(cap, params, writer, baudrate, 1, &mut std::thread::sleep)
+    }
+
+    /// Same as `write_capability`, but padding delays are passed to the given callback
+    ///
+    /// A delay is the time given by the terminal (`Padding::duration`) if the baud rate is
+    /// unknown, otherwise the time to send the pad characters filling it at the baud rate
+    /// (`Padding::duration_at`). Delays are skipped if the terminal uses xon/xoff or the baud
+    /// rate is below the `PaddingBaudRate` of the terminal, except for mandatory delays
+    /// (`$<n/>`).
+    ///
+    /// # Arguments
+    /// * `cap`            - string capability
+    /// * `params`         - array of parameters
+    /// * `writer`         - terminal output
+    /// * `baudrate`       - output speed
+    /// * `affected_lines` - number of lines affected, multiplier for proportional delays
+    ///   (`$<n*>`)
+    /// * `delay`          - called for every padding delay, after flushing writer
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// // flash=\E[?5h$<100/>\E[?5l
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let mut out = Vec::new();
+    /// let mut delays = Vec::new();
+    /// info.write_capability_with(StringCapability::FlashScreen, &[], &mut out, Some(9600), 1,
+    ///                            &mut |d| delays.push(d)).unwrap();
+    /// assert_eq!(out, b"\x1B[?5h\x1B[?5l");
+    /// assert_eq!(delays, [Duration::from_micros(99_375)]);
+    /// ```
+    pub fn write_capability_with(&self,
+                                 cap: StringCapability,
+                                 params: &[Param],
+                                 writer: &mut dyn Write,
+                                 baudrate: Option<u32>,
+                                 affected_lines: u32,
+                                 delay: &mut dyn FnMut(Duration)) -> Result<usize, TermInfoSendError> {
+        let output = match self.compiled(CapabilityKey::Standard(cap), || self.get_string(cap)) {
+            Some(compiled) => compiled?.eval_bytes(params)?,
+            None => return Err(TermInfoSendError::CapabilityNotFound(cap)),
+        };
+
+        let xon_xoff = self.get_bool(BoolCapability::XonXoff).unwrap_or(false);
+        let pad_baudrate = self.get_number(NumberCapability::PaddingBaudRate).unwrap_or(0);
+        let use_padding = match baudrate {
+            Some(baud) => pad_baudrate <= 0 || baud >= pad_baudrate as u32,
+            None => true,
+        };
+
+        let mut written = 0;
+        for segment in split_padding(&output) {
+            match segment {
+                Segment::Output(bytes) => {
+                    writer.write_all(bytes)?;
+                    written += bytes.len();
+                }
+                Segment::Delay(padding) => {
+                    if padding.mandatory || (use_padding && !xon_xoff) {
+                        writer.flush()?;
+                        delay(match baudrate {
+                            Some(baud) => padding.duration_at(baud, affected_lines),
+                            None => padding.duration(affected_lines),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// Same as `write_capability`, the baud rate is read from the terminal (feature `termios`,
+    /// unix only)
+    ///
+    /// Padding delays are skipped if writer isn't a terminal.
+    ///
+    /// # Arguments
+    /// * `cap`      - string capability
+    /// * `params`   - array of parameters
+    /// * `writer`   - terminal output
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     let _ = info.write_capability_tty(StringCapability::FlashScreen, &[], &mut std::io::stdout());
+    /// }
+    /// ```
+    #[cfg(all(unix, feature = "termios"))]
+    pub fn write_capability_tty<W>(&self,
+                                   cap: StringCapability,
+                                   params: &[Param],
+                                   writer: &mut W) -> Result<usize, TermInfoSendError>
+        where W: Write + std::os::unix::io::AsRawFd {
+        match speed::output_baud(writer) {
+            Some(baud) => self.write_capability(cap, params, writer, Some(baud)),
+            None => self.write_capability_with(cap, params, writer, None, 1, &mut |_| {}),
+        }
+    }
+
+    /// Returns the binary format of the terminfo file
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::{TermInfo, TerminfoVersion};
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     if info.terminfo_version() == TerminfoVersion::Legacy16Bit {
+    ///         println!("number capabilities are limited to 32767");
+    ///     }
+    /// }
+    /// ```
+    pub fn terminfo_version(&self) -> TerminfoVersion {
+        match self.read_i32 {
+            true => TerminfoVersion::New32Bit,
+            false => TerminfoVersion::Legacy16Bit,
+        }
+    }
+
+    /// Returns the primary name of the terminal, e.g. `xterm-256color`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     println!("{}", info.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> String {
+        let end = (NAMES_OFFSET + self.sec_name_size).min(self.data.len());
+        let names = read_str(&self.data[..end], NAMES_OFFSET).0;
+        names.split('|').next().unwrap_or("").to_string()
+    }
+
+    /// Write all capabilities to writer, one per line in `infocmp -1` format
+    ///
+    /// The first line contains the terminal names, standard capabilities follow in file order,
+    /// extended capabilities sorted by name. Escape sequences are written readable (`\E`,
+    /// `^G`, ...). Nothing is buffered, wrap writer in a `BufWriter` for unbuffered outputs.
+    ///
+    /// # Arguments
+    /// * `writer` - output
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     let mut out = Vec::new();
+    ///     info.print_capabilities(&mut out).unwrap();
+    ///     let listing = String::from_utf8(out).unwrap();
+    ///     assert!(listing.starts_with(&info.name()));
+    /// }
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn print_capabilities(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        self.print_names(writer)?;
+        self.print_standard(writer, |_| true)?;
+        self.print_extended(writer)
+    }
+
+    /// Write all capabilities to writer like `print_capabilities`, grouped by category
+    ///
+    /// Every category with capabilities starts with a comment line (`# cursor motion`),
+    /// extended capabilities follow last under `# extended`. The output can be compiled by tic.
+    ///
+    /// # Arguments
+    /// * `writer` - output
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let mut out = Vec::new();
+    /// info.print_capabilities_grouped(&mut out).unwrap();
+    /// let listing = String::from_utf8(out).unwrap();
+    /// assert!(listing.contains("# bell\n\tbel=^G,\n\tflash="));
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn print_capabilities_grouped(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        self.print_names(writer)?;
+        for category in Category::all() {
+            let present = category.capabilities::<BoolCapability>().any(|cap| self.bool_at(cap as usize))
+                || category.capabilities::<NumberCapability>().any(|cap| self.get(cap).is_some())
+                || category.capabilities::<StringCapability>().any(|cap| self.get(cap).is_some());
+            if present {
+                writeln!(writer, "# {}", category)?;
+                self.print_standard(writer, |other| other == category)?;
+            }
+        }
+
+        if !self.ext_bool.is_empty() || !self.ext_numbers.is_empty() || !self.ext_strings.is_empty() {
+            writeln!(writer, "# extended")?;
+        }
+        self.print_extended(writer)
+    }
+
+    #[cfg(feature = "names")]
+    fn print_names(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        let end = (NAMES_OFFSET + self.sec_name_size).min(self.data.len());
+        writer.write_all(&self.data[NAMES_OFFSET..find_null_term(&self.data[..end], NAMES_OFFSET)])?;
+        writer.write_all(b",\n")
+    }
+
+    /// Write the standard capabilities of the categories selected by filter in file order
+    #[cfg(feature = "names")]
+    fn print_standard(&self,
+                      writer: &mut dyn Write,
+                      filter: impl Fn(Category) -> bool) -> std::io::Result<()> {
+        for (idx, name) in BOOL_NAMES.iter().enumerate() {
+            if self.bool_at(idx) && filter(BOOL_CATEGORIES[idx]) {
+                writeln!(writer, "\t{},", name)?;
+            }
+        }
+
+        for (idx, name) in NUMBER_NAMES.iter().enumerate() {
+            match self.number_at(idx) {
+                Some(n) if filter(NUMBER_CATEGORIES[idx]) => writeln!(writer, "\t{}#{},", name, n)?,
+                _ => {}
+            }
+        }
+
+        for (idx, name) in STRING_NAMES.iter().enumerate() {
+            match self.string_at(idx) {
+                Some(value) if filter(STRING_CATEGORIES[idx]) => {
+                    write!(writer, "\t{}=", name)?;
+                    write_escaped(writer, value)?;
+                    writer.write_all(b",\n")?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the extended capabilities sorted by name
+    #[cfg(feature = "names")]
+    fn print_extended(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        let mut names = self.ext_bool.iter()
+                            .filter(|(_, value)| **value)
+                            .map(|(name, _)| name)
+                            .collect::<Vec<&String>>();
+        names.sort();
+        for name in names {
+            writeln!(writer, "\t{},", name)?;
+        }
+
+        let mut numbers = self.ext_numbers.iter().collect::<Vec<(&String, &i32)>>();
+        numbers.sort();
+        for (name, value) in numbers {
+            writeln!(writer, "\t{}#{},", name, value)?;
+        }
+
+        let mut strings = self.ext_strings.iter().collect::<Vec<(&String, &String)>>();
+        strings.sort();
+        for (name, value) in strings {
+            write!(writer, "\t{}=", name)?;
+            write_escaped(writer, &value.chars().map(|c| c as u8).collect::<Vec<u8>>())?;
+            writer.write_all(b",\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if both entries define the same capabilities with the same values
+    ///
+    /// The binary layout (16 or 32-bit numbers, order of the string table, section sizes) and
+    /// the terminal names are ignored. Absent and cancelled capabilities are equal, as are
+    /// false and absent bools.
+    ///
+    /// # Arguments
+    /// * `other` - terminfo to compare with
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// // names "test", bw, cols#80, cbt=^G
+    /// let legacy = TermInfo::from_bytes(&[0x1A, 0x01, 5, 0, 1, 0, 1, 0, 1, 0, 2, 0,
+    ///                                     b't', b'e', b's', b't', 0, 1, 80, 0, 0, 0, 7, 0]).unwrap();
+    /// // the same in 32-bit format with an unused string in the string table
+    /// let extended = TermInfo::from_bytes(&[0x1E, 0x02, 5, 0, 1, 0, 1, 0, 1, 0, 4, 0,
+    ///                                       b't', b'e', b's', b't', 0, 1, 80, 0, 0, 0, 2, 0,
+    ///                                       b'x', 0, 7, 0]).unwrap();
+    /// assert!(legacy.is_equivalent_to(&extended));
+    ///
+    /// let xterm = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert!(!legacy.is_equivalent_to(&xterm));
+    /// ```
+    pub fn is_equivalent_to(&self, other: &TermInfo) -> bool {
+        (0..BoolCapability::COUNT).all(|idx| self.bool_at(idx) == other.bool_at(idx))
+            && (0..NumberCapability::COUNT).all(|idx| self.number_at(idx) == other.number_at(idx))
+            && (0..StringCapability::COUNT).all(|idx| self.string_at(idx) == other.string_at(idx))
+            && self.ext_bool.iter().filter(|(_, value)| **value).count()
+                == other.ext_bool.iter().filter(|(_, value)| **value).count()
+            && self.ext_bool.iter()
+                   .filter(|(_, value)| **value)
+                   .all(|(name, _)| other.ext_bool.get(name) == Some(&true))
+            && self.ext_numbers == other.ext_numbers
+            && self.ext_strings == other.ext_strings
+    }
+
+    /// Returns the capabilities of profile which are absent, empty if the entry complies
+    ///
+    /// # Arguments
+    /// * `profile` - terminal to compare with
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::{NumberCapability, StringCapability};
+    /// use cxterminfo::profile::{MissingCapability, Profile};
+    ///
+    /// let xterm = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert!(xterm.missing_for_profile(Profile::Xterm).is_empty());
+    ///
+    /// // names "test", bw, cols#80, cbt=^G
+    /// let test = TermInfo::from_bytes(&[0x1A, 0x01, 5, 0, 1, 0, 1, 0, 1, 0, 2, 0,
+    ///                                   b't', b'e', b's', b't', 0, 1, 80, 0, 0, 0, 7, 0]).unwrap();
+    /// let missing = test.missing_for_profile(Profile::Vt100);
+    /// assert!(missing.contains(&MissingCapability::String(StringCapability::CursorAddress)));
+    /// assert!(!missing.contains(&MissingCapability::Number(NumberCapability::Columns)));
+    /// ```
+    pub fn missing_for_profile(&self, profile: Profile) -> Vec<MissingCapability> {
+        let mut missing = Vec::new();
+        for requirements in profile.requirements().iter() {
+            missing.extend(requirements.bools.iter()
+                                       .filter(|cap| self.get_bool(**cap) != Some(true))
+                                       .map(|cap| MissingCapability::Bool(*cap)));
+            missing.extend(requirements.numbers.iter()
+                                       .filter(|cap| self.get_number(**cap).is_none())
+                                       .map(|cap| MissingCapability::Number(*cap)));
+            missing.extend(requirements.strings.iter()
+                                       .filter(|cap| self.get_string(**cap).is_none())
+                                       .map(|cap| MissingCapability::String(*cap)));
+        }
+        missing
+    }
+
+    /// Returns true for dumb or minimal terminals, applications should fall back to plain
+    /// text output
+    ///
+    /// A terminal is dumb if
+    /// * the name starts with "dumb"
+    /// * `Columns` is absent or 0, console entries like `linux` leave it absent (the size comes
+    ///   from the kernel) and are reported dumb
+    /// * it can't move the cursor (no cursor addressing, home or upward motion)
+    /// * it has fewer than 5 string capabilities
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env() {
+    ///     if info.is_dumb() {
+    ///         println!("plain text only");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_dumb(&self) -> bool {
+        let can_move = [StringCapability::CursorAddress,
+                        StringCapability::CursorHome,
+                        StringCapability::CursorUp,
+                        StringCapability::ParmUpCursor]
+            .iter()
+            .any(|cap| self.string_offset(*cap as usize).is_some());
+        let str_count = (0..self.sec_str_offsets_size)
+            .filter(|idx| self.string_offset(*idx).is_some())
+            .count();
+
+        self.name().starts_with("dumb")
+            || self.get_number(NumberCapability::Columns).unwrap_or(0) == 0
+            || !can_move
+            || str_count < 5
+    }
+
+    /// Returns true if the terminal wraps to the next line after the last column (`am`)
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/xterm-256color").unwrap().auto_margin());
+    /// assert!(!TermInfo::from_file("tests/data/cx-ext").unwrap().auto_margin());
+    /// ```
+    pub fn auto_margin(&self) -> bool {
+        self.get_bool(BoolCapability::AutoRightMargin).unwrap_or(false)
+    }
+
+    /// Returns true if the terminal ignores a newline after the last column (`xenl`)
+    ///
+    /// With `am` the cursor stays in the last column after it was written (vt100), the next
+    /// character wraps and a newline at this point doesn't move the cursor again. Line editors
+    /// can't assume the cursor is on the next line. The accessor `eat_newline_glitch` of the
+    /// `methods` feature returns the same as Option.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// assert!(TermInfo::from_file("tests/data/xterm-256color").unwrap().has_newline_glitch());
+    /// assert!(!TermInfo::from_file("tests/data/cx-ext").unwrap().has_newline_glitch());
+    /// ```
+    pub fn has_newline_glitch(&self) -> bool {
+        self.get_bool(BoolCapability::EatNewlineGlitch).unwrap_or(false)
+    }
+
+    /// Create terminfo database, using TERM environment var.
+    pub fn from_env() -> Result<Self, TermInfoError> {
+        if let Ok(term) = std::env::var("TERM") {
+            TermInfo::from_name(term.as_str())
+        } else {
+            Err(TermInfoError::InvalidName)
+        }
+    }
+
+    /// Create terminfo database, using TERM environment var or the first of fallbacks which
+    /// can be loaded
+    ///
+    /// Returns `TermInfoError::InvalidName` if neither `TERM` nor any fallback can be loaded.
+    ///
+    /// # Arguments
+    /// * `fallbacks` - terminal names tried in order if `TERM` is not set or not supported
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env_with_fallback(&["xterm-256color", "xterm", "vt100"]) {
+    ///     println!("{}", info.name());
+    /// }
+    /// ```
+    pub fn from_env_with_fallback(fallbacks: &[&str]) -> Result<Self, TermInfoError> {
+        TermInfo::from_env()
+            .or_else(|_| fallbacks.iter()
+                                  .find_map(|name| TermInfo::from_name(name).ok())
+                                  .ok_or(TermInfoError::InvalidName))
+    }
+
+    /// Create terminfo database like `from_env`, refined by `TERM_PROGRAM` and `COLORTERM`
+    ///
+    /// See `from_provider_with_hints`.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Ok(info) = TermInfo::from_env_with_hints() {
+    ///     println!("{} {:?}", info.name(), info.color_support());
+    /// }
+    /// ```
+    pub fn from_env_with_hints() -> Result<Self, TermInfoError> {
+        TermInfo::from_provider_with_hints(&ProcessEnv)
+    }
+
+    /// Create terminfo database for `TERM`, refined by `TERM_PROGRAM` and `COLORTERM`
+    ///
+    /// Terminals often set `TERM` to a generic entry and name themselves in `TERM_PROGRAM`. If
+    /// `TERM` is `xterm`, `xterm-color` or `xterm-256color`, the entry of a recognized program
+    /// is loaded instead if it's installed:
+    /// * `iTerm.app` - `iterm2`
+    /// * `WezTerm` - `wezterm`
+    /// * `vscode` - `vscode`
+    /// * `Apple_Terminal` - `nsterm-256color`
+    ///
+    /// Capabilities the entry lacks are added with `apply_env_upgrades`: `Tc` if `COLORTERM`
+    /// is `truecolor` or `24bit`, `Smulx` for iTerm.app, WezTerm, ghostty and vscode. `COLORTERM`
+    /// is read from env by the color helpers as well (`set_colorterm_from_provider`). The
+    /// terminfo directories are searched like `from_name` with `TERMINFO` and `HOME` of env.
+    ///
+    /// # Arguments
+    /// * `env` - environment variables
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::{TermInfo, TermInfoError};
+    ///
+    /// let env: HashMap<String, String> = HashMap::new();
+    /// let info = TermInfo::from_provider_with_hints(&env);
+    /// assert!(matches!(info, Err(TermInfoError::InvalidName)));
+    /// ```
+    pub fn from_provider_with_hints<E: EnvProvider>(env: &E) -> Result<Self, TermInfoError> {
+        let term = env.var("TERM").ok_or(TermInfoError::InvalidName)?;
+        let program = env.var("TERM_PROGRAM").unwrap_or_default();
+        let entry = match term.as_str() {
+            "xterm" | "xterm-color" | "xterm-256color" => {
+                PROGRAM_ENTRIES.iter()
+                               .find(|(name, _)| *name == program)
+                               .and_then(|(_, entry)| TermInfo::from_name_in_env(entry, env).ok())
+            }
+            _ => None,
+        };
+
+        let mut info = match entry {
+            Some(info) => info,
+            None => TermInfo::from_name_in_env(&term, env)?,
+        };
+        info.apply_env_upgrades(env);
+        info.set_colorterm_from_provider(env);
+        Ok(info)
+    }
+
+    /// Create terminfo database from a secondary device attributes (DA2) response
+    ///
+    /// Useful if `TERM` is not set or not reliable. The terminal type reported in the response
+    /// is mapped to terminfo names (see `DeviceAttributes::terminal_names`), the first name found in
+    /// the terminfo database is loaded.
+    ///
+    /// Returns Option::None if the response is not a DA2 response, the terminal type is unknown
+    /// or no matching terminfo file exists.
+    ///
+    /// # Arguments
+    /// * `response` - bytes read from the terminal after sending `ESC [>c`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// if let Some(info) = TermInfo::from_xterm_control_sequence(b"\x1B[>84;0;0c") {
+    ///     assert!(info.name().starts_with("tmux") || info.name().starts_with("screen"));
+    /// }
+    ///
+    /// assert!(TermInfo::from_xterm_control_sequence(b"\x1B[?62;1c").is_none());
+    /// ```
+    pub fn from_xterm_control_sequence(response: &[u8]) -> Option<Self> {
+        let attributes = TermInfo::parse_device_attributes(response)?;
+        attributes.terminal_names()
+                  .iter()
+                  .find_map(|name| TermInfo::from_name(name).ok())
+    }
+
+    /// Create terminfo database for the given name
+    pub fn from_name(name: &str) -> Result<Self, TermInfoError> {
+        TermInfo::from_name_in_env(name, &ProcessEnv)
+    }
+
+    /// `from_name` with `TERMINFO` and `HOME` read from env
+    fn from_name_in_env<E: EnvProvider>(name: &str, env: &E) -> Result<Self, TermInfoError> {
+        if name.is_empty() {
+            return Err(TermInfoError::InvalidName);
+        }
+
+        for path in default_paths(name, env) {
+            if path.exists() {
+                return TermInfo::from_data(read_all_bytes_from_file(&path)?);
+            }
+        }
+
+        Err(TermInfoError::InvalidName)
     }
-}
 
-impl TermInfo {
-    /// Returns the string value for the capability or Option::None
+    /// Create terminfo database for the given name, a file in the 32-bit format is preferred
+    ///
+    /// Some systems install the same terminal in both formats in different directories,
+    /// `from_name` returns the first file found. All candidates are checked here and the first
+    /// 32-bit file (magic 01036) is loaded, the first legacy file otherwise. Numbers of legacy
+    /// files are limited to 32767, e.g. `pairs` of direct color terminals.
     ///
     /// # Arguments
-    /// * `cap` - string capability
+    /// * `name` - terminal name
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
-    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::terminfo::{TermInfo, TerminfoVersion};
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_string(StringCapability::Bell));
+    /// if let Ok(info) = TermInfo::from_name_preferring_32bit("xterm-direct") {
+    ///     if info.terminfo_version() == TerminfoVersion::Legacy16Bit {
+    ///         println!("no 32-bit file installed");
+    ///     }
     /// }
     /// ```
-    pub fn get_string(&self, cap: StringCapability) -> Option<String> {
-        let idx = cap as usize;
-        if idx >= self.sec_str_offsets_size {
-            None
-        } else {
-            let tbl_idx = read_i16(&self.data, self.offset_str_offsets() + (idx * 2)) as usize;
-            if tbl_idx == 0 {
-                None
-            } else {
-                Some(read_str(&self.data, self.offset_str_table() + tbl_idx).0.to_string())
+    pub fn from_name_preferring_32bit(name: &str) -> Result<Self, TermInfoError> {
+        if name.is_empty() {
+            return Err(TermInfoError::InvalidName);
+        }
+
+        let mut legacy = None;
+        for path in default_paths(name, &ProcessEnv) {
+            if !path.is_file() {
+                continue;
+            }
+
+            // a candidate which can't be read doesn't hide the following ones
+            let data = match read_all_bytes_from_file(&path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            if data.len() >= 2 && read_i16(&data, 0) == MAGIC_32BIT {
+                return TermInfo::from_data(data);
             }
+            legacy.get_or_insert(data);
+        }
+
+        match legacy {
+            Some(data) => TermInfo::from_data(data),
+            None => Err(TermInfoError::InvalidName),
         }
     }
 
-    /// Returns the number value for the capability or Option::None
+    /// Create terminfo database for the given name, ignoring the case of ASCII letters
+    ///
+    /// The name is tried as given and lowercased, then the default directories are searched
+    /// for a terminal name differing only in case. Useful if `TERM` is set with wrong casing,
+    /// e.g. `XTerm-256color`.
     ///
     /// # Arguments
-    /// * `cap` - number capability
+    /// * `name` - terminal name
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
-    /// use cxterminfo::capabilities::NumberCapability;
+    /// use cxterminfo::terminfo::TermInfo;
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_number(NumberCapability::MaxColors));
+    /// if let Ok(info) = TermInfo::from_name_case_insensitive("XTerm-256Color") {
+    ///     assert_eq!(info.name(), "xterm-256color");
     /// }
+    /// assert!(TermInfo::from_name_case_insensitive("").is_err());
     /// ```
-    pub fn get_number(&self, cap: NumberCapability) -> Option<i32> {
-        let idx = cap as usize;
-        if idx >= self.sec_number_size {
-            None
-        } else {
-            Some(read_int(&self.data, self.offset_number() + (idx * self.int_size), self.read_i32))
+    pub fn from_name_case_insensitive(name: &str) -> Result<Self, TermInfoError> {
+        match TermInfo::from_name(name) {
+            Err(TermInfoError::InvalidName) if !name.is_empty() => {}
+            result => return result,
+        }
+
+        let lowercase = name.to_ascii_lowercase();
+        if lowercase != name {
+            match TermInfo::from_name(&lowercase) {
+                Err(TermInfoError::InvalidName) => {}
+                result => return result,
+            }
+        }
+
+        for dir in TerminfoDir::system_dirs() {
+            if let Some(found) = dir.names().into_iter().find(|other| other.eq_ignore_ascii_case(name)) {
+                return dir.get(&found);
+            }
         }
+
+        Err(TermInfoError::InvalidName)
     }
 
-    /// Returns the bool value for the capability or Option::None
+    /// Returns true if a terminfo file for the given name exists in the default directories.
+    /// The file is neither read nor parsed.
     ///
     /// # Arguments
-    /// * `cap` - bool capability
+    /// * `name` - terminal name
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
-    /// use cxterminfo::capabilities::BoolCapability;
+    /// use cxterminfo::terminfo::TermInfo;
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_bool(BoolCapability::AutoLeftMargin));
-    /// }
+    /// println!("{}", TermInfo::available("xterm-256color"));
+    /// assert!(!TermInfo::available(""));
     /// ```
-    pub fn get_bool(&self, cap: BoolCapability) -> Option<bool> {
-        let idx = cap as usize;
-        if idx >= self.sec_bool_size {
-            None
-        } else {
-            Some(self.data[(self.offset_bool() + idx)] == 1)
-        }
+    pub fn available(name: &str) -> bool {
+        !name.is_empty() && default_paths(name, &ProcessEnv).iter().any(|path| path.exists())
     }
 
-    /// Returns the extended bool value for the given name or Option::None if name not exist
+    /// Returns true if a terminfo file for the given name exists in one of the given
+    /// directories. The file is neither read nor parsed.
     ///
     /// # Arguments
-    /// * `name` - key
+    /// * `name` - terminal name
+    /// * `dirs` - terminfo base directories, e.g. `/usr/share/terminfo`
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
+    /// use std::path::{Path, PathBuf};
+    /// use cxterminfo::terminfo::TermInfo;
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_ext_bool("AT"));
-    /// }
+    /// let dirs = [PathBuf::from("/usr/share/terminfo"), PathBuf::from("/lib/terminfo")];
+    /// println!("{}", TermInfo::available_in_path("xterm", &dirs));
     /// ```
-    pub fn get_ext_bool(&self, name: &str) -> Option<&bool> {
-        self.ext_bool.get(name)
+    pub fn available_in_path(name: &str, dirs: &[PathBuf]) -> bool {
+        !name.is_empty() && search_paths(name, dirs).iter().any(|path| path.exists())
     }
 
-    /// Returns the extended number value for the given name or Option::None if name not exist
-    ///
-    /// # Arguments
-    /// * `name` - key
+    /// Create terminfo database using given filename
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
+    /// use cxterminfo::terminfo::{TermInfo, TermInfoError};
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_ext_number("?"));
+    /// match TermInfo::from_file("/nonexistent/terminfo/x/xterm") {
+    ///     Err(TermInfoError::Io(err)) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+    ///     _ => unreachable!(),
     /// }
     /// ```
-    pub fn get_ext_number(&self, name: &str) -> Option<&i32> {
-        self.ext_numbers.get(name)
+    pub fn from_file(filename: &str) -> Result<Self, TermInfoError> {
+        TermInfo::from_data(read_all_bytes_from_file(Path::new(filename))?)
     }
 
-    /// Returns the extended string value for the given name or Option::None if name not exist
+    /// Create terminfo database from borrowed bytes, e.g. a static slice or a memory-mapped file
+    ///
+    /// The bytes are copied once after the size check.
     ///
     /// # Arguments
-    /// * `name` - key
+    /// * `bytes` - compiled terminfo entry
     ///
     /// # Example
     /// ```
-    /// use cxterminfo::terminfo;
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
     ///
-    /// if let Ok(info) = terminfo::from_env() {
-    ///     println!("{:?}", info.get_ext_number("xm"));
-    /// }
+    /// // names "test", bw, cols#80, cbt=^G
+    /// static ENTRY: [u8; 24] = [0x1A, 0x01, 5, 0, 1, 0, 1, 0, 1, 0, 2, 0,
+    ///                           b't', b'e', b's', b't', 0, 1, 80, 0, 0, 0, 7, 0];
+    ///
+    /// let info = TermInfo::from_bytes(&ENTRY).unwrap();
+    /// assert_eq!(info.name(), "test");
+    /// assert_eq!(info.get_bool(BoolCapability::AutoLeftMargin), Some(true));
+    /// assert_eq!(info.get_number(NumberCapability::Columns), Some(80));
+    /// assert_eq!(info.get_string(StringCapability::BackTab), Some("\x07".to_string()));
+    ///
+    /// assert!(TermInfo::from_bytes(&ENTRY[..8]).is_err());
     /// ```
-    pub fn get_ext_string(&self, name: &str) -> Option<&String> {
-        self.ext_strings.get(name)
-    }
-
-    /// Create terminfo database, using TERM environment var.
-    pub fn from_env() -> Result<Self, TermInfoError> {
-        if let Ok(term) = std::env::var("TERM") {
-            TermInfo::from_name(term.as_str())
-        } else {
-            Err(TermInfoError::InvalidName)
-        }
-    }
-
-    /// Create terminfo database for the given name
-    pub fn from_name(name: &str) -> Result<Self, TermInfoError> {
-        if name.len() == 0 {
-            return Err(TermInfoError::InvalidName);
-        }
-
-        let first_letter = name.chars().nth(0).unwrap_or('X');
-
-        let mut paths: Vec<PathBuf> = Vec::new();
-        // env TERMINFO
-        if let Ok(env_terminfo) = std::env::var("TERMINFO") {
-            paths.push(PathBuf::from(format!("{}/{}/{}", env_terminfo, first_letter, name)));
-        }
-
-        // HOME .terminfo
-        if let Ok(env_home) = std::env::var("HOME") {
-            paths.push(PathBuf::from(format!("{}/{}/{}", env_home, first_letter, name)));
-        }
-
-        // Linux
-        paths.push(PathBuf::from(format!("/etc/terminfo/{}/{}", first_letter, name)));
-        paths.push(PathBuf::from(format!("/lib/terminfo/{}/{}", first_letter, name)));
-        paths.push(PathBuf::from(format!("/usr/share/terminfo/{}/{}", first_letter, name)));
-        paths.push(PathBuf::from(format!("/usr/share/misc/terminfo/{}/{}", first_letter, name)));
-
-        // Mac
-        paths.push(PathBuf::from(format!("/etc/terminfo/{:X}/{}", first_letter as u8, name)));
-        paths.push(PathBuf::from(format!("/lib/terminfo/{:X}/{}", first_letter as u8, name)));
-        paths.push(PathBuf::from(format!("/usr/share/terminfo/{:X}/{}", first_letter as u8, name)));
-        paths.push(PathBuf::from(format!("/usr/share/misc/terminfo/{:X}/{}", first_letter as u8, name)));
-
-        for path in paths {
-            if path.exists() {
-                return TermInfo::from_file(path.to_str().unwrap())
-            }
+    pub fn from_bytes(bytes: &[u8]) -> Result<TermInfo, TermInfoError> {
+        if bytes.len() < TERMINFO_HEADER_SIZE || bytes.len() > max_data_size(bytes) {
+            return Err(TermInfoError::InvalidDataSize);
         }
 
-        Err(TermInfoError::InvalidName)
+        TermInfo::from_data(bytes.to_vec())
     }
 
-    /// Create terminfo database using given filename
-    pub fn from_file(filename: &str) -> Result<Self, TermInfoError> {
-        TermInfo::from_data(read_all_bytes_from_file(filename))
+    /// Create terminfo database from static data without copying it
+    ///
+    /// Intended for entries embedded with `include_bytes!`, the data is borrowed for the lifetime
+    /// of the `TermInfo`.
+    ///
+    /// # Arguments
+    /// * `data` - compiled terminfo entry
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::NumberCapability;
+    ///
+    /// static XTERM: &[u8] = include_bytes!("../tests/data/xterm-256color");
+    ///
+    /// let info = TermInfo::from_slice(XTERM).unwrap();
+    /// assert_eq!(info.name(), "xterm-256color");
+    /// assert_eq!(info.get_number(NumberCapability::MaxColors), Some(256));
+    /// ```
+    pub fn from_slice(data: &'static [u8]) -> Result<TermInfo, TermInfoError> {
+        TermInfo::from_cow(Cow::Borrowed(data))
     }
 
     /// Create terminfo database by parse byte-array directly
     pub fn from_data(data: Vec<u8>) -> Result<TermInfo, TermInfoError> {
-        if data.len() < TERMINFO_HEADER_SIZE || data.len() > TERMINFO_MAX_SIZE {
+        TermInfo::from_cow(Cow::Owned(data))
+    }
+
+    fn from_cow(data: Cow<'static, [u8]>) -> Result<TermInfo, TermInfoError> {
+        if data.len() < TERMINFO_HEADER_SIZE || data.len() > max_data_size(&data) {
             return Err(TermInfoError::InvalidDataSize);
         }
 
@@ -258,6 +3065,9 @@ impl TermInfo {
             ext_bool: HashMap::new(),
             ext_numbers: HashMap::new(),
             ext_strings: HashMap::new(),
+            compiled: Mutex::new(HashMap::new()),
+            color_policy: ColorPolicy::Auto,
+            colorterm_truecolor: None,
         };
 
         // read the magic number.
@@ -289,112 +3099,103 @@ impl TermInfo {
         info.sec_str_offsets_size = read_i16(&info.data, 8) as usize;
         info.sec_str_table_size = read_i16(&info.data, 10) as usize;
 
+        // all sections must fit into data, capability lookups don't check bounds
+        if info.offset_str_table() + info.sec_str_table_size > info.data.len() {
+            return Err(TermInfoError::InvalidData);
+        }
 
         // In addition to the main section of bools, numbers, and strings, there is also
         // an "extended" section.  This section contains additional entries that don't
         // have well-known indices, and are instead named mappings.  As such, we parse
         // all of this data now rather than on each request, as the mapping is fairly complicated.
         // This function relies on the data stored above, so it's the last thing we run.
-        let mut ext_offset = round_up_even(info.offset_str_table() + info.sec_str_table_size);
+        let ext_offset = round_up_even(info.offset_str_table() + info.sec_str_table_size);
 
-        // Check if there is an extended section
-        if ext_offset + EXT_HEADER_SIZE < info.data.len() {
-            if read_i16(&info.data, ext_offset) < 0
-                || read_i16(&info.data, ext_offset + 2) < 0
-                || read_i16(&info.data, ext_offset + 4) < 0
-            {
-                // The extended contained invalid data
-                return Ok(info);
-            }
+        // Check if there is an extended section, invalid extended data is ignored
+        if ext_offset + EXT_HEADER_SIZE <= info.data.len() {
+            let _ = info.read_extended(ext_offset);
+        }
 
-            let ext_bool_count = read_i16(&info.data, ext_offset) as usize;
-            let ext_number_count = read_i16(&info.data, ext_offset + 2) as usize;
-            let ext_str_count = read_i16(&info.data, ext_offset + 4) as usize;
+        Ok(info)
+    }
 
-            // Read extended bool values
-            let mut bool_values = Vec::with_capacity(ext_bool_count);
+    /// Parse the extended section at offset
+    ///
+    /// The section starts with the number of bools, numbers and strings, the number of string
+    /// table entries and the string table size. Bools, numbers and string offsets follow like
+    /// in the standard sections, then the offsets of all names and the string table. Names
+    /// are stored in the string table after the string values.
+    ///
+    /// Returns Option::None if the section is invalid, no extended capability is stored then.
+    fn read_extended(&mut self, offset: usize) -> Option<()> {
+        let data = &self.data;
+        let count = |idx: usize| match read_i16(data, offset + idx * 2) {
+            n if n < 0 => None,
+            n => Some(n as usize),
+        };
+        let bool_count = count(0)?;
+        let number_count = count(1)?;
+        let str_count = count(2)?;
+        let table_size = count(4)?;
+        let name_count = bool_count + number_count + str_count;
 
-            ext_offset += EXT_HEADER_SIZE;
-            for i in 0..ext_bool_count {
-                let pos = ext_offset + read_i16(&info.data, ext_offset + i * 2) as usize;
+        let bools_offset = offset + EXT_HEADER_SIZE;
+        let numbers_offset = round_up_even(bools_offset + bool_count);
+        let str_offsets = numbers_offset + number_count * self.int_size;
+        let name_offsets = str_offsets + str_count * 2;
+        let table_offset = name_offsets + name_count * 2;
+        let table = data.get(table_offset..table_offset + table_size)?;
 
-                if pos == 0 || ext_offset > info.data.len() {
-                    return Ok(info);
+        let mut values = Vec::with_capacity(str_count);
+        let mut names_start = 0;
+        for idx in 0..str_count {
+            match read_i16(data, str_offsets + idx * 2) {
+                n if n < 0 => values.push(None),
+                n => {
+                    let value = table_str(table, n as usize)?;
+                    names_start = names_start.max(n as usize + value.len() + 1);
+                    values.push(Some(value));
                 }
-
-                bool_values.push(info.data[pos] == 1);
             }
+        }
 
-            // Read extended number values
-            let mut number_values = Vec::with_capacity(ext_number_count);
-
-            ext_offset += if ext_bool_count == 0 { 0 } else { (ext_bool_count - 1) * 2 };
-            for i in 0..ext_number_count {
-                let pos = ext_offset + read_i16(&info.data, ext_offset + i * 2) as usize;
-
-                if pos == 0 || ext_offset > info.data.len() {
-                    return Ok(info);
-                }
-
-                &number_values.push(read_int(&info.data, pos, info.read_i32));
+        let mut names = Vec::with_capacity(name_count);
+        for idx in 0..name_count {
+            match read_i16(data, name_offsets + idx * 2) {
+                n if n < 0 => return None,
+                n => names.push(table_str(table, names_start + n as usize)?),
             }
+        }
 
-            // Now we need to parse all of the extended string values.  These aren't necessarily
-            // "in order", meaning the offsets aren't guaranteed to be increasing.  Instead, we parse
-            // the offsets in order, pulling out each string it references and storing them into our
-            // value vector in the order of the offsets.
-            let mut str_values = Vec::with_capacity(ext_str_count);
-
-            ext_offset += if ext_number_count == 0 { 0 } else { (ext_number_count - 1) * 2 };
-
-            let tbl_offset = ext_offset
-                + ext_str_count * 2
-                + (ext_bool_count + ext_number_count + ext_str_count) * 2;
-            let mut last_end: usize = 0;
-            for i in 0..ext_str_count {
-                let pos = tbl_offset + read_i16(&info.data, ext_offset + i * 2) as usize;
+        let mut names = names.into_iter();
+        let mut ext_bool = HashMap::new();
+        for idx in 0..bool_count {
+            ext_bool.insert(names.next()?, data[bools_offset + idx] == 1);
+        }
 
-                if pos == 0 || ext_offset > info.data.len() {
-                    return Ok(info);
+        let mut ext_numbers = HashMap::new();
+        for idx in 0..number_count {
+            let name = names.next()?;
+            match read_int(data, numbers_offset + idx * self.int_size, self.read_i32) {
+                n if n < 0 => {}
+                n => {
+                    ext_numbers.insert(name, n);
                 }
-
-                let (str, null_term_pos) = read_str(&info.data, pos);
-                &str_values.push(str);
-                last_end = last_end.max(null_term_pos)
-            }
-
-            // Read extended names
-            // The names are in order for the bools, then the numbers, and then the strings.
-            let mut names = Vec::with_capacity(ext_bool_count + ext_number_count + ext_str_count);
-            let mut pos = last_end + 1;
-
-            while pos < info.data.len() {
-                let (str, null_term_pos) = read_str(&info.data, pos);
-                &names.push(str);
-                pos = null_term_pos + 1;
-            }
-
-            // Associate names with the bool values
-            for i in 0..ext_bool_count {
-                &info.ext_bool.insert(names[i].to_string(), bool_values[i]);
-            }
-
-            // Associate names with the number values
-            for i in 0..ext_number_count {
-                &info.ext_numbers
-                     .insert(names[i + ext_bool_count - 1].to_string(), number_values[i]);
             }
+        }
 
-            // Associate names with the string values
-            for i in 0..ext_str_count {
-                &info.ext_strings.insert(
-                    names[i + ext_bool_count + ext_number_count].to_string(),
-                    str_values[i].to_string(),
-                );
+        let mut ext_strings = HashMap::new();
+        for value in values {
+            let name = names.next()?;
+            if let Some(value) = value {
+                ext_strings.insert(name, value);
             }
         }
 
-        Ok(info)
+        self.ext_bool = ext_bool;
+        self.ext_numbers = ext_numbers;
+        self.ext_strings = ext_strings;
+        Some(())
     }
 
     /// The offset into data where the bools section begins
@@ -414,6 +3215,212 @@ impl TermInfo {
     fn offset_str_table(&self) -> usize {
         self.offset_str_offsets() + (self.sec_str_offsets_size * 2)
     }
+    /// The bool with index idx, false if it's outside of the bool section
+    fn bool_at(&self, idx: usize) -> bool {
+        idx < self.sec_bool_size && self.data[self.offset_bool() + idx] == 1
+    }
+    /// The number with index idx, Option::None if the number is absent or cancelled
+    fn number_at(&self, idx: usize) -> Option<i32> {
+        if idx >= self.sec_number_size {
+            return None;
+        }
+
+        // negative values mark absent (-1) or cancelled (-2) capabilities
+        match read_int(&self.data, self.offset_number() + (idx * self.int_size), self.read_i32) {
+            n if n < 0 => None,
+            n => Some(n),
+        }
+    }
+    /// The bytes of the string with index idx, Option::None if the string is absent or cancelled
+    pub(crate) fn string_at(&self, idx: usize) -> Option<&[u8]> {
+        self.string_offset(idx)
+            .map(|offset| &self.data[offset..find_null_term(&self.data, offset)])
+    }
+    /// The offset into data of the string with index idx, Option::None if the string is absent,
+    /// cancelled or outside of the string table
+    fn string_offset(&self, idx: usize) -> Option<usize> {
+        if idx >= self.sec_str_offsets_size {
+            return None;
+        }
+
+        match read_i16(&self.data, self.offset_str_offsets() + (idx * 2)) {
+            n if n < 0 || n as usize >= self.sec_str_table_size => None,
+            n => Some(self.offset_str_table() + n as usize),
+        }
+    }
+}
+
+/// Terminfo base directory, e.g. `/usr/share/terminfo`
+///
+/// Entries are stored in subdirectories named by the first letter of the terminal name (Linux)
+/// or its hex code (Mac).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminfoDir(pub PathBuf);
+
+impl TerminfoDir {
+    /// Returns the existing standard directories in lookup order, see `TermInfo::from_name`.
+    /// `HOME` is left out, entries there are looked up directly in its letter subdirectories.
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TerminfoDir;
+    ///
+    /// for dir in TerminfoDir::system_dirs() {
+    ///     println!("{:?}: {} terminals", dir.0, dir.names().len());
+    /// }
+    /// ```
+    pub fn system_dirs() -> Vec<TerminfoDir> {
+        let home = ProcessEnv.var("HOME").map(PathBuf::from);
+        default_dirs(&ProcessEnv).into_iter()
+                      .filter(|dir| Some(dir) != home.as_ref() && dir.is_dir())
+                      .map(TerminfoDir)
+                      .collect()
+    }
+
+    /// Returns the sorted names of all terminals inside the directory, unreadable
+    /// subdirectories are skipped
+    pub fn names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        let subdirs = match std::fs::read_dir(&self.0) {
+            Ok(entries) => entries,
+            Err(_) => return names,
+        };
+
+        for subdir in subdirs.filter_map(|entry| entry.ok()) {
+            if let Ok(entries) = std::fs::read_dir(subdir.path()) {
+                names.extend(entries.filter_map(|entry| entry.ok())
+                                    .filter(|entry| entry.path().is_file())
+                                    .filter_map(|entry| entry.file_name().into_string().ok()));
+            }
+        }
+
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Create terminfo database for the terminal name inside the directory
+    ///
+    /// # Arguments
+    /// * `name` - terminal name
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TerminfoDir;
+    ///
+    /// for dir in TerminfoDir::system_dirs() {
+    ///     if let Ok(info) = dir.get("xterm") {
+    ///         assert_eq!(info.name(), "xterm");
+    ///     }
+    /// }
+    /// ```
+    pub fn get(&self, name: &str) -> Result<TermInfo, TermInfoError> {
+        if !name.is_empty() {
+            for path in search_paths(name, std::slice::from_ref(&self.0)) {
+                if path.exists() {
+                    return TermInfo::from_data(read_all_bytes_from_file(&path)?);
+                }
+            }
+        }
+
+        Err(TermInfoError::InvalidName)
+    }
+
+    /// Returns an iterator parsing all terminals inside the directory, sorted by name
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TerminfoDir;
+    ///
+    /// for dir in TerminfoDir::system_dirs() {
+    ///     let valid = dir.iter().filter(|entry| entry.is_ok()).count();
+    ///     println!("{:?}: {} valid terminals", dir.0, valid);
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Result<(String, TermInfo), TermInfoError>> + '_ {
+        self.names().into_iter().map(move |name| self.get(&name).map(|info| (name, info)))
+    }
+}
+
+/// Standard terminfo base directories, searched after `TERMINFO` and `HOME`
+const SYSTEM_DIRS: [&str; 4] =
+    ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo", "/usr/share/misc/terminfo"];
+
+/// Returns the terminfo base directories in lookup order, `TERMINFO` and `HOME` of env and the
+/// standard directories
+fn default_dirs<E: EnvProvider>(env: &E) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    // env TERMINFO
+    if let Some(env_terminfo) = env.var("TERMINFO") {
+        dirs.push(PathBuf::from(env_terminfo));
+    }
+
+    // HOME
+    if let Some(env_home) = env.var("HOME") {
+        dirs.push(PathBuf::from(env_home));
+    }
+
+    dirs.extend(SYSTEM_DIRS.iter().map(PathBuf::from));
+    dirs
+}
+
+/// Returns the terminfo file paths for name in lookup order of `TermInfo::from_name`
+///
+/// All directories are searched with the first letter as subdirectory (Linux), the standard
+/// directories also with its hex code (Mac).
+fn default_paths<E: EnvProvider>(name: &str, env: &E) -> Vec<PathBuf> {
+    let first_letter = name.chars().next().unwrap_or('X');
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    // Linux
+    for dir in default_dirs(env) {
+        paths.push(dir.join(first_letter.to_string()).join(name));
+    }
+
+    // Mac
+    for dir in SYSTEM_DIRS.iter() {
+        paths.push(Path::new(dir).join(format!("{:X}", first_letter as u8)).join(name));
+    }
+
+    paths
+}
+
+/// Returns the possible terminfo file paths for name inside dirs
+fn search_paths(name: &str, dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let first_letter = name.chars().next().unwrap_or('X');
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    // Linux
+    for dir in dirs {
+        paths.push(dir.join(first_letter.to_string()).join(name));
+    }
+
+    // Mac
+    for dir in dirs {
+        paths.push(dir.join(format!("{:X}", first_letter as u8)).join(name));
+    }
+
+    paths
+}
+
+/// Returns the output without padding delays (`$<n>`)
+fn strip_padding(output: Vec<u8>) -> Vec<u8> {
+    let mut stripped = Vec::with_capacity(output.len());
+    for segment in split_padding(&output) {
+        if let Segment::Output(bytes) = segment {
+            stripped.extend_from_slice(bytes);
+        }
+    }
+    stripped
+}
+
+/// Returns the cheaper route, a on equal cost
+fn cheapest(a: Option<Route>, b: Option<Route>) -> Option<Route> {
+    match (a, b) {
+        (Some(a), Some(b)) if b.cost < a.cost => Some(b),
+        (Some(a), _) => Some(a),
+        (None, b) => b,
+    }
 }
 
 /// Read i16 or i32
@@ -426,50 +3433,64 @@ impl TermInfo {
 ///
 /// # Warning
 /// NOT SAFE
-fn read_int(data: &Vec<u8>, pos: usize, as_32bit: bool) -> i32 {
+fn read_int(data: &[u8], pos: usize, as_32bit: bool) -> i32 {
     match as_32bit {
         true => read_i32(data, pos),
         false => read_i16(data, pos) as i32,
     }
 }
 
-/// Read i32 from data
+/// Read little endian i32 from data
 ///
 /// # Warning
 /// NOT SAFE
-fn read_i32(data: &Vec<u8>, pos: usize) -> i32 {
-    ((data[pos] as i32) << 24)
-        | ((data[pos + 1] as i32) << 16)
-        | ((data[pos + 2] as i32) << 8)
-        | (data[pos + 3] as i32)
+fn read_i32(data: &[u8], pos: usize) -> i32 {
+    ((data[pos + 3] as i32) << 24)
+        | ((data[pos + 2] as i32) << 16)
+        | ((data[pos + 1] as i32) << 8)
+        | (data[pos] as i32)
 }
 
 /// Read i16 from data
 ///
 /// # Warning
 /// NOT SAFE
-fn read_i16(data: &Vec<u8>, pos: usize) -> i16 {
+fn read_i16(data: &[u8], pos: usize) -> i16 {
     ((data[pos + 1] as i16) << 8) | (data[pos] as i16)
 }
 
-/// Read all data from binary file to a vec<u8>
-///
-/// # Warning
-/// NOT SAFE
-fn read_all_bytes_from_file(filename: &str) -> Vec<u8> {
-    let mut f = File::open(&filename).expect("no file found");
-    let metadata = fs::metadata(&filename).expect("unable to read metadata");
-    let mut buffer = vec![0; metadata.len() as usize];
-    f.read(&mut buffer).expect("buffer overflow");
+/// Returns the maximum size of the entry, 32768 bytes for the 32-bit format and 4096 bytes
+/// for the legacy format
+fn max_data_size(data: &[u8]) -> usize {
+    match data.len() >= 2 && read_i16(data, 0) == MAGIC_32BIT {
+        true => TERMINFO_MAX_SIZE_32BIT,
+        false => TERMINFO_MAX_SIZE,
+    }
+}
+
+/// Read all data from binary file to a vec<u8>, files above the maximum terminfo size of
+/// their format are not read
+fn read_all_bytes_from_file(filename: &Path) -> Result<Vec<u8>, TermInfoError> {
+    let mut f = File::open(filename)?;
+    let len = f.metadata()?.len() as usize;
+    if len > TERMINFO_MAX_SIZE_32BIT {
+        return Err(TermInfoError::InvalidDataSize);
+    }
 
-    buffer
+    let mut buffer = vec![0; len];
+    f.read_exact(&mut buffer)?;
+    if len > max_data_size(&buffer) {
+        return Err(TermInfoError::InvalidDataSize);
+    }
+
+    Ok(buffer)
 }
 
 /// Read string from data
 ///
 /// # Warning
 /// NOT SAFE
-fn read_str(data: &Vec<u8>, pos: usize) -> (String, usize) {
+fn read_str(data: &[u8], pos: usize) -> (String, usize) {
     let null_term = find_null_term(data, pos);
     (data[pos..null_term].iter()
                          .map(|c| *c as char)
@@ -477,15 +3498,102 @@ fn read_str(data: &Vec<u8>, pos: usize) -> (String, usize) {
      null_term)
 }
 
+/// Returns true if name matches the glob pattern, `*` is any sequence and `?` any character
+#[cfg(feature = "names")]
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let name = name.chars().collect::<Vec<char>>();
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` and the name position it's matched up to
+    let mut star = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Converts red, green and blue (0-1000) to Tektronix hue (0-360, blue at 0), lightness and
+/// saturation (0-100) like ncurses
+fn rgb_to_hls(r: i32, g: i32, b: i32) -> (i32, i32, i32) {
+    let (min, max) = (r.min(g).min(b), r.max(g).max(b));
+    let lightness = (min + max) / 20;
+    if min == max {
+        return (0, lightness, 0);
+    }
+
+    let saturation = match lightness < 50 {
+        true => (max - min) * 100 / (max + min),
+        false => (max - min) * 100 / (2000 - max - min),
+    };
+    let hue = match max {
+        _ if r == max => 120 + (g - b) * 60 / (max - min),
+        _ if g == max => 240 + (b - r) * 60 / (max - min),
+        _ => 360 + (r - g) * 60 / (max - min),
+    };
+
+    (hue % 360, lightness, saturation)
+}
+
+/// Read a positive number from an environment variable
+fn env_number(var: &str) -> Option<i32> {
+    std::env::var(var).ok()
+                      .and_then(|value| value.trim().parse::<i32>().ok())
+                      .filter(|n| *n > 0)
+}
+
+/// Read a null terminated string at pos of a string table, Option::None if it's out of bounds
+/// or not terminated
+fn table_str(table: &[u8], pos: usize) -> Option<String> {
+    let bytes = table.get(pos..)?;
+    let end = bytes.iter().position(|c| *c == b'\0')?;
+    Some(bytes[..end].iter().map(|c| *c as char).collect())
+}
+
 /// Find the next '\0' char in data
-fn find_null_term(data: &Vec<u8>, pos: usize) -> usize {
+fn find_null_term(data: &[u8], pos: usize) -> usize {
     let mut term_pos = pos as i32;
-    while term_pos < data.len() as i32 && data[term_pos as usize] != '\0' as u8 {
+    while term_pos < data.len() as i32 && data[term_pos as usize] != b'\0' {
         term_pos += 1;
     }
     term_pos as usize
 }
 
+/// Write bytes of a string capability with escapes like infocmp (`\E`, `^G`, `\200`, ...)
+fn write_escaped(writer: &mut dyn Write, value: &[u8]) -> std::io::Result<()> {
+    for c in value {
+        match *c {
+            0x1B => writer.write_all(b"\\E")?,
+            b'\n' => writer.write_all(b"\\n")?,
+            b'\r' => writer.write_all(b"\\r")?,
+            b'\\' | b',' | b'^' => write!(writer, "\\{}", *c as char)?,
+            c if c < 0x20 => write!(writer, "^{}", (c + b'@') as char)?,
+            0x7F => writer.write_all(b"^?")?,
+            c if c >= 0x80 => write!(writer, "\\{:03o}", c)?,
+            c => writer.write_all(&[c])?,
+        }
+    }
+    Ok(())
+}
+
 /// Simple int rounding to get even numbers
 fn round_up_even(n: usize) -> usize {
     match n % 2 {
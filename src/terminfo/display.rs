@@ -0,0 +1,102 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Lazy expansion of string capabilities with `Display`
+
+use std::cell::RefCell;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+use crate::capabilities::StringCapability;
+use crate::param_string::Param;
+
+use super::{FormatError, TermInfo};
+
+/// String capability evaluated when it's formatted, see `TermInfo::display`
+///
+/// Padding delays are skipped like by `TermInfo::format`. Output written before an error is
+/// kept, the error is returned as `fmt::Error` and can be read with `error`.
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::{FormatError, TermInfo};
+/// use cxterminfo::capabilities::StringCapability;
+/// use cxterminfo::param_string::Param;
+///
+/// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+/// let status = info.display(StringCapability::ToStatusLine, &[]);
+/// assert!(std::fmt::write(&mut String::new(), format_args!("{}", status)).is_err());
+/// assert!(matches!(status.error(), Some(FormatError::CapabilityNotFound(_))));
+/// ```
+#[derive(Debug)]
+pub struct CapDisplay<'a> {
+    info: &'a TermInfo,
+    cap: StringCapability,
+    params: &'a [Param<'a>],
+    error: RefCell<Option<FormatError>>,
+}
+
+impl<'a> CapDisplay<'a> {
+    pub(crate) fn new(info: &'a TermInfo, cap: StringCapability, params: &'a [Param<'a>]) -> Self {
+        CapDisplay { info, cap, params, error: RefCell::new(None) }
+    }
+
+    /// Returns the error of the last formatting or Option::None if it succeeded
+    pub fn error(&self) -> Option<FormatError> {
+        self.error.borrow().clone()
+    }
+
+    /// Write the capability to writer, the same bytes as `TermInfo::format`
+    ///
+    /// Errors of the capability are returned as `io::ErrorKind::InvalidData` and can be read
+    /// with `error`.
+    ///
+    /// # Arguments
+    /// * `writer` - terminal output
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::capabilities::StringCapability;
+    /// use cxterminfo::param_string::Param;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let mut out = Vec::new();
+    /// info.display(StringCapability::CursorAddress, &[Param::Number(4), Param::Number(9)])
+    ///     .write_to(&mut out)
+    ///     .unwrap();
+    /// assert_eq!(out, b"\x1B[5;10H");
+    /// ```
+    pub fn write_to(&self, writer: &mut dyn io::Write) -> io::Result<()> {
+        match self.info.write_bytes_into(self.cap, self.params, writer) {
+            Ok(Ok(())) => {
+                self.error.replace(None);
+                Ok(())
+            }
+            // errors of the writer aren't errors of the capability
+            Ok(Err(err)) => Err(err),
+            Err(err) => {
+                self.error.replace(Some(err.clone()));
+                Err(io::Error::new(io::ErrorKind::InvalidData, err))
+            }
+        }
+    }
+}
+
+impl<'a> Display for CapDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.info.write_into(self.cap, self.params, f) {
+            Ok(result) => {
+                self.error.replace(None);
+                result
+            }
+            Err(err) => {
+                self.error.replace(Some(err));
+                Err(fmt::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Color pairs handed out like curses `init_pair`
+
+use std::collections::HashMap;
+
+use crate::capabilities::StringCapability;
+use crate::ext::xterm_256_to_rgb;
+use crate::param_string::Param;
+
+use super::{FormatError, TermInfo};
+
+/// Registry of color pairs, foreground and background combinations numbered from 1 to
+/// `max_pairs - 1`
+///
+/// Pair 0 are the default colors and is never handed out. Terminals with `initp` and `scp`
+/// (Tektronix and other terminals only knowing pairs) get their pairs defined with `initp`, the
+/// RGB values are those of the xterm palette, see `ext::xterm_256_to_rgb`. All other terminals
+/// set the colors of a pair with `setaf` and `setab`.
+///
+/// The sequences are empty if colors are turned off by the color policy of the terminal, a
+/// terminal forced to use the ANSI colors has 64 pairs (`TermInfo::set_color_policy`).
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::TermInfo;
+/// use cxterminfo::terminfo::pairs::ColorPairs;
+///
+/// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+/// let mut pairs = ColorPairs::new(&info);
+/// let (pair, init) = pairs.alloc(1, 4).unwrap();
+/// assert_eq!((pair, init), (1, vec![]));
+/// assert_eq!(pairs.alloc(1, 4).unwrap().0, 1);
+/// assert_eq!(pairs.set(pair).unwrap(), b"\x1B[31m\x1B[44m");
+/// ```
+#[derive(Debug)]
+pub struct ColorPairs<'a> {
+    info: &'a TermInfo,
+    limit: u32,
+    /// colors of pair i + 1
+    pairs: Vec<(u16, u16)>,
+    ids: HashMap<(u16, u16), u16>,
+}
+
+impl<'a> ColorPairs<'a> {
+    /// Returns an empty registry for the pairs of a terminal (`pairs`)
+    ///
+    /// # Arguments
+    /// * `info` - terminal
+    pub fn new(info: &'a TermInfo) -> Self {
+        // pair numbers are u16
+        let limit = match info.forces_ansi_colors() {
+            true => 64,
+            false => info.pairs().min(0x10000),
+        };
+        ColorPairs { info, limit, pairs: Vec::new(), ids: HashMap::new() }
+    }
+
+    /// Returns true if pairs are defined with `initp` and selected with `scp`, false if the
+    /// colors are set with `setaf` and `setab`
+    pub fn uses_pair_capabilities(&self) -> bool {
+        !self.info.forces_ansi_colors()
+            && self.info.get_string(StringCapability::InitializePair).is_some()
+            && self.info.get_string(StringCapability::SetColorPair).is_some()
+    }
+
+    /// Returns the number of pairs handed out
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns true if no pair is handed out
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns the foreground and background color of a pair or Option::None if it isn't
+    /// handed out
+    ///
+    /// # Arguments
+    /// * `pair` - pair number
+    pub fn colors(&self, pair: u16) -> Option<(u16, u16)> {
+        match pair {
+            0 => None,
+            _ => self.pairs.get(pair as usize - 1).copied(),
+        }
+    }
+
+    /// Returns the pair for foreground and background and the sequence defining it
+    ///
+    /// A pair with the same colors is reused. The sequence is `initp` for a new pair on
+    /// terminals using pair capabilities and empty otherwise.
+    ///
+    /// # Arguments
+    /// * `fg` - foreground color number
+    /// * `bg` - background color number
+    ///
+    /// # Errors
+    /// * `FormatError::ColorOutOfRange` - a color is not below `max_colors`
+    /// * `FormatError::PairsExhausted` - all pairs are in use
+    pub fn alloc(&mut self, fg: u16, bg: u16) -> Result<(u16, Vec<u8>), FormatError> {
+        if let Some(pair) = self.ids.get(&(fg, bg)) {
+            return Ok((*pair, Vec::new()));
+        }
+
+        let colors = match self.info.forces_ansi_colors() {
+            true => 8,
+            false => self.info.colors(),
+        };
+        if let Some(color) = [fg, bg].iter().find(|color| **color as u32 >= colors) {
+            return Err(FormatError::ColorOutOfRange(*color));
+        }
+
+        let pair = self.pairs.len() as u32 + 1;
+        if pair >= self.limit {
+            return Err(FormatError::PairsExhausted(self.limit));
+        }
+
+        let pair = pair as u16;
+        let init = match self.uses_pair_capabilities() && !self.info.colors_suppressed() {
+            true => self.init_pair(pair, fg, bg)?,
+            false => Vec::new(),
+        };
+
+        self.pairs.push((fg, bg));
+        self.ids.insert((fg, bg), pair);
+        Ok((pair, init))
+    }
+
+    /// Returns the sequence selecting a pair, `scp` or `setaf` followed by `setab`
+    ///
+    /// # Arguments
+    /// * `pair` - pair number returned by `alloc`
+    ///
+    /// # Errors
+    /// * `FormatError::ColorOutOfRange` - the pair isn't handed out
+    pub fn set(&self, pair: u16) -> Result<Vec<u8>, FormatError> {
+        let (fg, bg) = self.colors(pair).ok_or(FormatError::ColorOutOfRange(pair))?;
+        if self.info.colors_suppressed() {
+            return Ok(Vec::new());
+        }
+
+        if self.uses_pair_capabilities() {
+            return self.info.format(StringCapability::SetColorPair, &[Param::Number(pair as i32)]);
+        }
+
+        let mut output = self.info.set_foreground_color(fg)?;
+        output.extend(self.info.set_background_color(bg)?);
+        Ok(output)
+    }
+
+    /// Returns the sequence to switch to foreground and background, `alloc` and `set` in one
+    ///
+    /// # Arguments
+    /// * `fg` - foreground color number
+    /// * `bg` - background color number
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::terminfo::pairs::ColorPairs;
+    ///
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// let mut pairs = ColorPairs::new(&info);
+    /// assert_eq!(pairs.color(7, 0).unwrap(), b"\x1B[37m\x1B[40m");
+    /// ```
+    pub fn color(&mut self, fg: u16, bg: u16) -> Result<Vec<u8>, FormatError> {
+        let (pair, mut output) = self.alloc(fg, bg)?;
+        output.extend(self.set(pair)?);
+        Ok(output)
+    }
+
+    fn init_pair(&self, pair: u16, fg: u16, bg: u16) -> Result<Vec<u8>, FormatError> {
+        let rgb = |color: u16| {
+            let (r, g, b) = xterm_256_to_rgb(color.min(255) as u8);
+            self.info.color_params(r, g, b)
+        };
+        let (fg, bg) = (rgb(fg), rgb(bg));
+        let params = [pair as i32, fg.0, fg.1, fg.2, bg.0, bg.1, bg.2].iter()
+                                                                     .map(|n| Param::Number(*n))
+                                                                     .collect::<Vec<Param>>();
+        self.info.format(StringCapability::InitializePair, &params)
+    }
+}
@@ -0,0 +1,94 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Color policy of command-line tools, `NO_COLOR` and `CLICOLOR`
+
+use std::collections::HashMap;
+
+/// Source of environment variables
+///
+/// `ProcessEnv` reads the environment of the process, a `HashMap` serves fixed values, e.g. in
+/// tests.
+pub trait EnvProvider {
+    /// Returns the value of a variable or Option::None if it isn't set or not valid unicode
+    ///
+    /// # Arguments
+    /// * `name` - name of the variable
+    fn var(&self, name: &str) -> Option<String>;
+}
+
+/// Environment of the process
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessEnv;
+
+impl EnvProvider for ProcessEnv {
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+}
+
+impl EnvProvider for HashMap<String, String> {
+    fn var(&self, name: &str) -> Option<String> {
+        self.get(name).cloned()
+    }
+}
+
+/// Whether colors are used, see `TermInfo::set_color_policy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorPolicy {
+    /// Colors of the terminal
+    #[default]
+    Auto,
+    /// No colors, the color sequences are empty
+    Never,
+    /// Colors even if the terminal has none, ANSI colors are assumed
+    Always,
+}
+
+impl ColorPolicy {
+    /// Returns the policy set by the environment of the process, see `from_provider`
+    pub fn from_env() -> ColorPolicy {
+        ColorPolicy::from_provider(&ProcessEnv)
+    }
+
+    /// Returns the policy set by environment variables
+    ///
+    /// In order of precedence:
+    /// * `NO_COLOR` set and not empty - `Never` (<https://no-color.org>)
+    /// * `CLICOLOR_FORCE` set, not empty and not `0` - `Always`
+    /// * `CLICOLOR` is `0` - `Never`
+    ///
+    /// Otherwise `Auto`.
+    ///
+    /// # Arguments
+    /// * `env` - environment variables
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use cxterminfo::terminfo::policy::ColorPolicy;
+    ///
+    /// let mut env = HashMap::new();
+    /// env.insert("CLICOLOR_FORCE".to_string(), "1".to_string());
+    /// assert_eq!(ColorPolicy::from_provider(&env), ColorPolicy::Always);
+    ///
+    /// env.insert("NO_COLOR".to_string(), "1".to_string());
+    /// assert_eq!(ColorPolicy::from_provider(&env), ColorPolicy::Never);
+    /// ```
+    pub fn from_provider<E: EnvProvider>(env: &E) -> ColorPolicy {
+        let set = |name: &str| env.var(name).filter(|value| !value.is_empty());
+
+        if set("NO_COLOR").is_some() {
+            ColorPolicy::Never
+        } else if set("CLICOLOR_FORCE").filter(|value| value != "0").is_some() {
+            ColorPolicy::Always
+        } else if set("CLICOLOR").filter(|value| value == "0").is_some() {
+            ColorPolicy::Never
+        } else {
+            ColorPolicy::Auto
+        }
+    }
+}
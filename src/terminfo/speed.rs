@@ -0,0 +1,105 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Output speed of a terminal from termios (feature `termios`, unix only)
+
+use std::os::raw::c_int;
+use std::os::unix::io::AsRawFd;
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+type SpeedT = std::os::raw::c_ulong;
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+type SpeedT = std::os::raw::c_uint;
+
+/// Storage for `struct termios`, larger than the structure on all supported platforms
+#[repr(C, align(8))]
+struct Termios([u8; 256]);
+
+extern "C" {
+    fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+    fn cfgetospeed(termios: *const Termios) -> SpeedT;
+}
+
+/// Linux `B*` constants and their baud rate
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const SPEEDS: [(u32, u32); 30] = [
+    (0o1, 50), (0o2, 75), (0o3, 110), (0o4, 134), (0o5, 150), (0o6, 200), (0o7, 300),
+    (0o10, 600), (0o11, 1200), (0o12, 1800), (0o13, 2400), (0o14, 4800), (0o15, 9600),
+    (0o16, 19200), (0o17, 38400), (0o10001, 57600), (0o10002, 115200), (0o10003, 230400),
+    (0o10004, 460800), (0o10005, 500000), (0o10006, 576000), (0o10007, 921600),
+    (0o10010, 1000000), (0o10011, 1152000), (0o10012, 1500000), (0o10013, 2000000),
+    (0o10014, 2500000), (0o10015, 3000000), (0o10016, 3500000), (0o10017, 4000000),
+];
+
+/// Returns the baud rate for a `B*` speed constant or Option::None for `B0` (hang up) and
+/// unknown values
+///
+/// Linux encodes speeds as bit patterns (`B9600` is `0o15`), BSD and macOS use the baud rate
+/// itself.
+///
+/// # Arguments
+/// * `speed` - value returned by `cfgetospeed`
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::speed::baud_from_speed;
+///
+/// assert_eq!(baud_from_speed(0), None);
+/// # #[cfg(target_os = "linux")]
+/// # {
+/// assert_eq!(baud_from_speed(0o15), Some(9600));
+/// assert_eq!(baud_from_speed(0o17), Some(38400));
+/// assert_eq!(baud_from_speed(0o10002), Some(115200));
+/// assert_eq!(baud_from_speed(9600), None);
+/// # }
+/// ```
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn baud_from_speed(speed: u32) -> Option<u32> {
+    SPEEDS.iter().find(|(constant, _)| *constant == speed).map(|(_, baud)| *baud)
+}
+
+/// Returns the baud rate for a `B*` speed constant or Option::None for `B0` (hang up) and
+/// unknown values
+///
+/// Linux encodes speeds as bit patterns (`B9600` is `0o15`), BSD and macOS use the baud rate
+/// itself.
+///
+/// # Arguments
+/// * `speed` - value returned by `cfgetospeed`
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn baud_from_speed(speed: u32) -> Option<u32> {
+    match speed {
+        0 => None,
+        baud => Some(baud),
+    }
+}
+
+/// Returns the output baud rate of a terminal or Option::None if fd isn't a terminal
+///
+/// # Arguments
+/// * `fd` - file descriptor or anything with one, e.g. `std::io::stdout()`
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::speed::output_baud;
+///
+/// let file = std::fs::File::open("Cargo.toml").unwrap();
+/// assert_eq!(output_baud(&file), None);
+///
+/// // None if stdout is redirected
+/// println!("{:?}", output_baud(&std::io::stdout()));
+/// ```
+pub fn output_baud<F: AsRawFd + ?Sized>(fd: &F) -> Option<u32> {
+    let mut termios = Termios([0; 256]);
+    let speed = unsafe {
+        if tcgetattr(fd.as_raw_fd(), &mut termios) != 0 {
+            return None;
+        }
+        cfgetospeed(&termios)
+    };
+
+    baud_from_speed(speed as u32)
+}
@@ -0,0 +1,259 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Text styles, video attributes and colors set with one sequence
+
+use crate::capabilities::StringCapability;
+use crate::ext::UnderlineStyle;
+
+use super::{AttrSet, Color, FormatError, TermInfo, ATTRIBUTE_MODES};
+
+/// Video attributes and colors of text, see `TermInfo::apply_style`
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::{AttrSet, Color};
+/// use cxterminfo::terminfo::style::Style;
+///
+/// let style = Style::new().bold().underline().fg(1).bg(Color::Default);
+/// assert_eq!(style.attrs(), AttrSet::BOLD | AttrSet::UNDERLINE);
+/// assert_eq!(style.foreground(), Some(Color::Index(1)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    attrs: AttrSet,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    underline: Option<UnderlineStyle>,
+    underline_rgb: Option<(u8, u8, u8)>,
+}
+
+impl Style {
+    /// Returns a style without attributes and colors
+    pub fn new() -> Self {
+        Style::default()
+    }
+
+    /// Returns the style with standout mode, usually reverse or bold
+    pub fn standout(self) -> Self {
+        self.with(AttrSet::STANDOUT)
+    }
+
+    /// Returns the style with extra bright text
+    pub fn bold(self) -> Self {
+        self.with(AttrSet::BOLD)
+    }
+
+    /// Returns the style with half bright text
+    pub fn dim(self) -> Self {
+        self.with(AttrSet::DIM)
+    }
+
+    /// Returns the style with italic text
+    pub fn italic(self) -> Self {
+        self.with(AttrSet::ITALIC)
+    }
+
+    /// Returns the style with underlined text
+    pub fn underline(self) -> Self {
+        self.with(AttrSet::UNDERLINE)
+    }
+
+    /// Returns the style with blinking text
+    pub fn blink(self) -> Self {
+        self.with(AttrSet::BLINK)
+    }
+
+    /// Returns the style with reverse video
+    pub fn reverse(self) -> Self {
+        self.with(AttrSet::REVERSE)
+    }
+
+    /// Returns the style with invisible text
+    pub fn invisible(self) -> Self {
+        self.with(AttrSet::INVISIBLE)
+    }
+
+    /// Returns the style with a foreground color
+    ///
+    /// # Arguments
+    /// * `color` - ANSI color number or `Color::Default`
+    pub fn fg<C: Into<Color>>(self, color: C) -> Self {
+        Style { fg: Some(color.into()), ..self }
+    }
+
+    /// Returns the style with a background color
+    ///
+    /// # Arguments
+    /// * `color` - ANSI color number or `Color::Default`
+    pub fn bg<C: Into<Color>>(self, color: C) -> Self {
+        Style { bg: Some(color.into()), ..self }
+    }
+
+    /// Returns the style with an underline style, `UnderlineStyle::None` removes the underline
+    ///
+    /// # Arguments
+    /// * `style` - underline style, see `TermInfo::set_underline`
+    pub fn underline_style(self, style: UnderlineStyle) -> Self {
+        match style {
+            UnderlineStyle::None => {
+                Style { attrs: self.attrs - AttrSet::UNDERLINE, underline: None, ..self }
+            }
+            _ => Style { underline: Some(style), ..self.with(AttrSet::UNDERLINE) },
+        }
+    }
+
+    /// Returns the style with an underline color, see `TermInfo::set_underline_color`
+    ///
+    /// # Arguments
+    /// * `r` - red
+    /// * `g` - green
+    /// * `b` - blue
+    pub fn underline_color(self, r: u8, g: u8, b: u8) -> Self {
+        Style { underline_rgb: Some((r, g, b)), ..self }
+    }
+
+    /// Returns the video attributes
+    pub fn attrs(&self) -> AttrSet {
+        self.attrs
+    }
+
+    /// Returns the foreground color or Option::None if the style keeps it
+    pub fn foreground(&self) -> Option<Color> {
+        self.fg
+    }
+
+    /// Returns the background color or Option::None if the style keeps it
+    pub fn background(&self) -> Option<Color> {
+        self.bg
+    }
+
+    fn with(self, attr: AttrSet) -> Self {
+        Style { attrs: self.attrs | attr, ..self }
+    }
+}
+
+impl TermInfo {
+    /// Returns the sequence switching to a style
+    ///
+    /// The attributes are set with `sgr` if the terminal has it, otherwise with `sgr0`
+    /// followed by the sequences of the single attributes (`smso`, `bold`, `dim`, `smul`,
+    /// `blink`, `rev`, `invis`), attributes not in the style are turned off. Italic is set
+    /// with `sitm`, then the colors with `set_foreground_color` and `set_background_color`.
+    /// Styles with a color drop the attributes the terminal can't combine with colors
+    /// (`color_incompatible_attrs`). Padding delays are removed.
+    ///
+    /// Underline styles other than single are set with `Smulx` after the attributes, terminals
+    /// without it keep the single underline. The underline color is set with `Setulc` and
+    /// left out on terminals without it.
+    ///
+    /// # Arguments
+    /// * `style` - attributes and colors
+    ///
+    /// # Errors
+    /// * `FormatError::CapabilityNotFound` - the capability of an attribute is missing, or
+    ///   the terminal has neither `sgr` nor `sgr0`
+    /// * errors of `set_foreground_color` and `set_background_color`
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    /// use cxterminfo::terminfo::style::Style;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// let style = Style::new().bold().underline().fg(1);
+    /// assert_eq!(info.apply_style(&style).unwrap(), b"\x1B(B\x1B[0;1;4m\x1B[31m");
+    ///
+    /// // underline conflicts with colors on the Linux console
+    /// let info = TermInfo::from_file("tests/data/linux").unwrap();
+    /// assert_eq!(info.apply_style(&style).unwrap(), b"\x1B[0;10;1m\x0F\x1B[31m");
+    /// ```
+    pub fn apply_style(&self, style: &Style) -> Result<Vec<u8>, FormatError> {
+        let attrs = match style.fg.is_some() || style.bg.is_some() {
+            true => style.attrs - self.color_incompatible_attrs(),
+            false => style.attrs,
+        };
+
+        let mut output = match self.format(StringCapability::SetAttributes, &attrs.sgr_params()) {
+            Err(FormatError::CapabilityNotFound(_)) => {
+                let mut output = self.format(StringCapability::ExitAttributeMode, &[])?;
+                for (_, cap) in ATTRIBUTE_MODES.iter().filter(|(attr, _)| attrs.contains(*attr)) {
+                    output.extend(self.format(*cap, &[])?);
+                }
+                output
+            }
+            result => result?,
+        };
+
+        if attrs.contains(AttrSet::ITALIC) {
+            output.extend(self.format(StringCapability::EnterItalicsMode, &[])?);
+        }
+        if let Some(fg) = style.fg {
+            output.extend(self.set_foreground_color(fg)?);
+        }
+        if let Some(bg) = style.bg {
+            output.extend(self.set_background_color(bg)?);
+        }
+
+        let mut underline = String::new();
+        let styled = style.underline.filter(|style| *style != UnderlineStyle::Single);
+        if let (true, Some(styled)) = (attrs.contains(AttrSet::UNDERLINE), styled) {
+            if self.supports_undercurl() {
+                underline.extend(self.set_underline(styled));
+            }
+        }
+        if let Some((r, g, b)) = style.underline_rgb {
+            underline.extend(self.set_underline_color(r, g, b));
+        }
+        output.extend(underline.chars().map(|c| c as u8));
+        Ok(output)
+    }
+
+    /// Returns the sequence turning off all attributes and restoring the default colors
+    ///
+    /// Uses `sgr0`, or `rmso`, `rmul` and `ritm` if the terminal has no `sgr0`, followed by
+    /// `op` or `oc` like `reset_colors`. The underline color is restored with
+    /// `reset_underline_color` on terminals with `Setulc`. Padding delays are removed.
+    ///
+    /// # Errors
+    /// * `FormatError::CapabilityNotFound(ExitAttributeMode)` - the terminal has none of the
+    ///   capabilities
+    ///
+    /// # Example
+    /// ```
+    /// use cxterminfo::terminfo::TermInfo;
+    ///
+    /// let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    /// assert_eq!(info.reset_style().unwrap(), b"\x1B(B\x1B[m\x1B[39;49m");
+    ///
+    /// let info = TermInfo::from_file("tests/data/vt100").unwrap();
+    /// assert_eq!(info.reset_style().unwrap(), b"\x1B[m\x0F");
+    /// ```
+    pub fn reset_style(&self) -> Result<Vec<u8>, FormatError> {
+        let mut output = match self.format(StringCapability::ExitAttributeMode, &[]) {
+            Err(FormatError::CapabilityNotFound(_)) => {
+                [StringCapability::ExitStandoutMode,
+                 StringCapability::ExitUnderlineMode,
+                 StringCapability::ExitItalicsMode].iter()
+                                                   .filter_map(|cap| self.format(*cap, &[]).ok())
+                                                   .flatten()
+                                                   .collect()
+            }
+            result => result?,
+        };
+
+        if let Some(colors) = self.default_colors() {
+            output.extend(colors.chars().map(|c| c as u8));
+        }
+        if let Some(underline) = self.reset_underline_color() {
+            output.extend(underline.chars().map(|c| c as u8));
+        }
+        match output.is_empty() {
+            true => Err(FormatError::CapabilityNotFound(StringCapability::ExitAttributeMode)),
+            false => Ok(output),
+        }
+    }
+}
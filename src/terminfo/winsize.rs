@@ -0,0 +1,88 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Window size of a terminal from the `TIOCGWINSZ` ioctl (feature `winsize`, unix only)
+
+use std::io;
+use std::os::raw::c_int;
+use std::os::unix::io::AsRawFd;
+
+#[cfg(all(any(target_os = "linux", target_os = "android"), target_env = "musl"))]
+type Request = c_int;
+#[cfg(not(all(any(target_os = "linux", target_os = "android"), target_env = "musl")))]
+type Request = std::os::raw::c_ulong;
+
+/// `TIOCGWINSZ` request, Option::None on platforms with an unknown value
+#[cfg(all(any(target_os = "linux", target_os = "android"),
+          not(any(target_arch = "mips", target_arch = "mips64", target_arch = "powerpc",
+                  target_arch = "powerpc64", target_arch = "sparc64"))))]
+const TIOCGWINSZ: Option<Request> = Some(0x5413);
+#[cfg(any(all(any(target_os = "linux", target_os = "android"),
+              any(target_arch = "mips", target_arch = "mips64", target_arch = "powerpc",
+                  target_arch = "powerpc64", target_arch = "sparc64")),
+          target_os = "macos", target_os = "ios", target_os = "freebsd", target_os = "dragonfly",
+          target_os = "netbsd", target_os = "openbsd"))]
+const TIOCGWINSZ: Option<Request> = Some(0x40087468);
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+const TIOCGWINSZ: Option<Request> = Some(0x5468);
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios",
+              target_os = "freebsd", target_os = "dragonfly", target_os = "netbsd",
+              target_os = "openbsd", target_os = "solaris", target_os = "illumos")))]
+const TIOCGWINSZ: Option<Request> = None;
+
+/// `struct winsize`, rows and columns are read from the entry instead
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct Winsize {
+    ws_row: u16,
+    ws_col: u16,
+    ws_xpixel: u16,
+    ws_ypixel: u16,
+}
+
+extern "C" {
+    fn ioctl(fd: c_int, request: Request, ...) -> c_int;
+}
+
+fn winsize<F: AsRawFd + ?Sized>(fd: &F) -> io::Result<Winsize> {
+    let request = match TIOCGWINSZ {
+        Some(request) => request,
+        None => return Err(io::ErrorKind::Unsupported.into()),
+    };
+
+    let mut size = Winsize::default();
+    // SAFETY: TIOCGWINSZ writes a `struct winsize` to the pointer, `Winsize` has its layout
+    // and lives until the call returns. An invalid descriptor fails with an error.
+    if unsafe { ioctl(fd.as_raw_fd(), request, &mut size as *mut Winsize) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(size)
+}
+
+/// Returns width and height of the terminal on stdout in pixels, the size of the text area
+/// for Sixel and Kitty graphics
+///
+/// Terminals not reporting their pixel size return `(0, 0)`. Character cell dimensions are
+/// `cols` and `lines` of the terminfo entry.
+///
+/// # Errors
+/// The error of the ioctl, e.g. if stdout isn't a terminal, `Unsupported` on platforms without
+/// a known `TIOCGWINSZ`
+///
+/// # Example
+/// ```
+/// use cxterminfo::terminfo::winsize::pixel_dimensions;
+///
+/// match pixel_dimensions() {
+///     Ok((width, height)) => println!("{}x{} pixels", width, height),
+///     Err(err) => println!("no terminal: {}", err),
+/// }
+/// ```
+pub fn pixel_dimensions() -> io::Result<(u16, u16)> {
+    winsize(&io::stdout()).map(|size| (size.ws_xpixel, size.ws_ypixel))
+}
@@ -0,0 +1,111 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Several attributes at once with `sgr` and with the sequences of the single attributes, `ncv`
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::{AttrSet, TermInfo};
+
+use common::{entry, entry_with_numbers};
+
+#[test]
+fn with_sgr() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert_eq!(info.sgr(false, true, true, false, true), Some("\x1B(B\x1B[0;4;5;8m".to_string()));
+
+    // vt100 style sgr, each parameter tested separately
+    let info = entry(&[], &[(StringCapability::SetAttributes,
+                             "\x1B[0%?%p1%p6%|%t;1%;%?%p2%t;4%;%?%p1%p3%|%t;7%;%?%p4%t;5%;m")]);
+    assert_eq!(info.sgr(true, false, false, false, false), Some("\x1B[0;1m".to_string()));
+    assert_eq!(info.sgr(false, false, true, true, false), Some("\x1B[0;7;5m".to_string()));
+    assert_eq!(info.sgr(false, false, false, false, true), Some("\x1B[0m".to_string()));
+}
+
+#[test]
+fn without_sgr() {
+    let info = entry(&[], &[(StringCapability::ExitAttributeMode, "\x1B[m"),
+                            (StringCapability::EnterBoldMode, "\x1B[1m"),
+                            (StringCapability::EnterUnderlineMode, "\x1B[4m"),
+                            (StringCapability::EnterReverseMode, "\x1B[7m")]);
+    assert_eq!(info.sgr(false, false, false, false, false), Some("\x1B[m".to_string()));
+    assert_eq!(info.sgr(true, true, false, true, false),
+               Some("\x1B[m\x1B[1m\x1B[4m\x1B[7m".to_string()));
+    // no blink and no invis
+    assert_eq!(info.sgr(true, false, true, false, false), None);
+    assert_eq!(info.sgr(false, false, false, false, true), None);
+
+    let info = entry(&[], &[(StringCapability::EnterBoldMode, "\x1B[1m")]);
+    assert_eq!(info.sgr(true, false, false, false, false), None);
+}
+
+#[test]
+fn all_attributes() {
+    let info = entry(&[], &[(StringCapability::ExitAttributeMode, "0"),
+                            (StringCapability::EnterStandoutMode, "s"),
+                            (StringCapability::EnterBoldMode, "b"),
+                            (StringCapability::EnterDimMode, "d"),
+                            (StringCapability::EnterUnderlineMode, "u"),
+                            (StringCapability::EnterBlinkMode, "k"),
+                            (StringCapability::EnterReverseMode, "r"),
+                            (StringCapability::EnterSecureMode, "i"),
+                            (StringCapability::EnterProtectedMode, "p"),
+                            (StringCapability::EnterAltCharsetMode, "a"),
+                            (StringCapability::EnterItalicsMode, "I")]);
+    assert_eq!(info.sgr_attrs(AttrSet::from_bits(0xFFFF)), Some("0sbdukripaI".to_string()));
+    assert_eq!(info.sgr_attrs(AttrSet::ITALIC | AttrSet::DIM), Some("0dI".to_string()));
+
+    // sgr parameters 1 to 9 in bit order, italic appended
+    let info = entry(&[], &[(StringCapability::SetAttributes,
+                             "%p1%d%p2%d%p3%d%p4%d%p5%d%p6%d%p7%d%p8%d%p9%d"),
+                            (StringCapability::EnterItalicsMode, "I")]);
+    let attrs = AttrSet::STANDOUT | AttrSet::ALT_CHARSET;
+    assert_eq!(info.sgr_attrs(attrs), Some("100000001".to_string()));
+    assert_eq!(info.sgr_attrs(AttrSet::REVERSE | AttrSet::ITALIC), Some("001000000I".to_string()));
+    assert_eq!(info.sgr_attrs(AttrSet::EMPTY), Some("000000000".to_string()));
+
+    // no sitm
+    let info = TermInfo::from_file("tests/data/linux").unwrap();
+    assert_eq!(info.sgr_attrs(AttrSet::ITALIC), None);
+}
+
+#[test]
+fn no_color_video() {
+    // linux: ncv#18, underline and dim
+    let info = TermInfo::from_file("tests/data/linux").unwrap();
+    let ncv = info.color_incompatible_attrs();
+    assert_eq!(ncv.bits(), 18);
+    assert!(ncv.contains(AttrSet::UNDERLINE) && ncv.contains(AttrSet::DIM));
+    assert!(!ncv.contains(AttrSet::BOLD));
+
+    let attrs = AttrSet::UNDERLINE | AttrSet::DIM | AttrSet::REVERSE;
+    assert_eq!(info.sgr_with_colors(attrs), Some("\x1B[0;10;7m\x0F".to_string()));
+    assert_eq!(info.sgr_attrs(attrs), Some("\x1B[0;10;4;7;2m\x0F".to_string()));
+
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert_eq!(info.sgr_with_colors(attrs), info.sgr_attrs(attrs));
+
+    let info = entry_with_numbers(&[], &[(NumberCapability::NoColorVideo, -2)], &[]);
+    assert!(info.color_incompatible_attrs().is_empty());
+    assert_eq!(AttrSet::from_bits(0x7E00), AttrSet::EMPTY);
+}
+
+#[test]
+fn protected_mode() {
+    let info = entry(&[], &[(StringCapability::EnterProtectedMode, "\x1B[1\"q"),
+                            (StringCapability::ExitAttributeMode, "\x1B[m")]);
+    assert_eq!(info.protected_mode_enter(), Some("\x1B[1\"q".to_string()));
+    assert_eq!(info.protected_mode_exit(), Some("\x1B[m".to_string()));
+
+    let info = entry(&[], &[(StringCapability::ExitAttributeMode, "\x1B[m")]);
+    assert_eq!(info.protected_mode_enter(), None);
+    assert_eq!(info.protected_mode_exit(), None);
+}
@@ -0,0 +1,48 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Lookup of terminal names with wrong casing in a terminfo directory set by `TERMINFO`
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use std::fs;
+use std::path::PathBuf;
+
+use cxterminfo::terminfo::{TermInfo, TermInfoError};
+
+/// Creates a terminfo directory with xterm-256color stored as `x/xterm-256color` and
+/// `M/MyTerm`
+fn terminfo_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cxterminfo-case-{}", std::process::id()));
+    for (letter, name) in &[("x", "xterm-256color"), ("M", "MyTerm")] {
+        fs::create_dir_all(dir.join(letter)).unwrap();
+        fs::copy("tests/data/xterm-256color", dir.join(letter).join(name)).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn lookup() {
+    let dir = terminfo_dir();
+    std::env::set_var("TERMINFO", &dir);
+
+    // as given and lowercased
+    assert!(TermInfo::from_name_case_insensitive("xterm-256color").is_ok());
+    assert!(TermInfo::from_name("XTerm-256color").is_err());
+    assert!(TermInfo::from_name_case_insensitive("XTerm-256color").is_ok());
+
+    // neither as given nor lowercased, found by searching the directory
+    assert!(TermInfo::from_name("myterm").is_err());
+    assert!(TermInfo::from_name_case_insensitive("myterm").is_ok());
+    assert!(TermInfo::from_name_case_insensitive("MYTERM").is_ok());
+
+    assert!(matches!(TermInfo::from_name_case_insensitive("my-term"), Err(TermInfoError::InvalidName)));
+    assert!(matches!(TermInfo::from_name_case_insensitive(""), Err(TermInfoError::InvalidName)));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,111 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` and the color helpers following the policy
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use std::collections::HashMap;
+
+use cxterminfo::capabilities::StringCapability;
+use cxterminfo::ext::{ColorSupport, DirectMethod};
+use cxterminfo::terminfo::pairs::ColorPairs;
+use cxterminfo::terminfo::policy::ColorPolicy;
+use cxterminfo::terminfo::{Color, FormatError, TermInfo};
+
+fn policy(vars: &[(&str, &str)]) -> ColorPolicy {
+    let env = vars.iter()
+                  .map(|(name, value)| (name.to_string(), value.to_string()))
+                  .collect::<HashMap<String, String>>();
+    ColorPolicy::from_provider(&env)
+}
+
+#[test]
+fn from_provider() {
+    assert_eq!(policy(&[]), ColorPolicy::Auto);
+
+    assert_eq!(policy(&[("NO_COLOR", "1")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("NO_COLOR", "0")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("NO_COLOR", "")]), ColorPolicy::Auto);
+
+    assert_eq!(policy(&[("CLICOLOR", "0")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("CLICOLOR", "1")]), ColorPolicy::Auto);
+    assert_eq!(policy(&[("CLICOLOR", "")]), ColorPolicy::Auto);
+
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "1")]), ColorPolicy::Always);
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "yes")]), ColorPolicy::Always);
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "0")]), ColorPolicy::Auto);
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "")]), ColorPolicy::Auto);
+}
+
+#[test]
+fn precedence() {
+    assert_eq!(policy(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("NO_COLOR", "1"), ("CLICOLOR", "1")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "1"), ("CLICOLOR", "0")]), ColorPolicy::Always);
+    assert_eq!(policy(&[("CLICOLOR_FORCE", "0"), ("CLICOLOR", "0")]), ColorPolicy::Never);
+    assert_eq!(policy(&[("NO_COLOR", ""), ("CLICOLOR_FORCE", "1")]), ColorPolicy::Always);
+}
+
+#[test]
+fn color_support() {
+    let xterm = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    let direct = ColorSupport::Direct { via: DirectMethod::Setaf };
+    assert_eq!(xterm.color_support_with_policy(&ColorPolicy::Auto), direct);
+    assert_eq!(xterm.color_support_with_policy(&ColorPolicy::Always), direct);
+    assert_eq!(xterm.color_support_with_policy(&ColorPolicy::Never), ColorSupport::Mono);
+
+    let linux = TermInfo::from_file("tests/data/linux").unwrap();
+    assert_eq!(linux.color_support_with_policy(&ColorPolicy::Always), ColorSupport::Colors(8));
+}
+
+#[test]
+fn never() {
+    let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    info.set_color_policy(ColorPolicy::Never);
+    assert_eq!(info.color_policy(), ColorPolicy::Never);
+
+    assert_eq!(info.set_foreground_color(1), Ok(vec![]));
+    assert_eq!(info.set_background_color(Color::Default), Ok(vec![]));
+    assert_eq!(info.set_foreground_rgb(250, 10, 5), Some(String::new()));
+    assert_eq!(info.set_background_rgb_or_nearest(250, 10, 5), Ok(vec![]));
+    assert_eq!(info.reset_colors(), Some("\x1B(B\x1B[m".to_string()));
+
+    let mut pairs = ColorPairs::new(&info);
+    assert_eq!(pairs.color(1, 4), Ok(vec![]));
+
+    // attributes stay
+    assert_eq!(info.sgr(true, false, false, false, false), Some("\x1B(B\x1B[0;1m".to_string()));
+
+    let mut info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    info.set_color_policy(ColorPolicy::Never);
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), Some(String::new()));
+    assert_eq!(info.set_underline_color(1, 2, 3), Some(String::new()));
+}
+
+#[test]
+fn always() {
+    let mut info = TermInfo::from_file("tests/data/vt100").unwrap();
+    assert_eq!(info.set_foreground_color(1),
+               Err(FormatError::CapabilityNotFound(StringCapability::SetAnsiForeground)));
+
+    info.set_color_policy(ColorPolicy::Always);
+    assert_eq!(info.set_foreground_color(1).unwrap(), b"\x1B[31m");
+    assert_eq!(info.set_background_color(7).unwrap(), b"\x1B[47m");
+    assert_eq!(info.set_background_color(Color::Default).unwrap(), b"\x1B[49m");
+    assert_eq!(info.set_foreground_color(8), Err(FormatError::ColorOutOfRange(8)));
+    assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5).unwrap(), b"\x1B[31m");
+
+    let mut pairs = ColorPairs::new(&info);
+    assert_eq!(pairs.color(2, 0).unwrap(), b"\x1B[32m\x1B[40m");
+
+    // terminals with colors keep theirs
+    let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    info.set_color_policy(ColorPolicy::Always);
+    assert_eq!(info.set_foreground_color(208).unwrap(), b"\x1B[38;5;208m");
+}
@@ -0,0 +1,138 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Color sequences of terminals with `setaf`/`setab`, the older `setf`/`setb` and default colors
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::{Color, FormatError, TermInfo};
+
+use common::entry_with_numbers;
+
+#[test]
+fn ansi_256() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert_eq!(info.set_foreground_color(0).unwrap(), b"\x1B[30m");
+    assert_eq!(info.set_foreground_color(9).unwrap(), b"\x1B[91m");
+    assert_eq!(info.set_foreground_color(255).unwrap(), b"\x1B[38;5;255m");
+    assert_eq!(info.set_background_color(3).unwrap(), b"\x1B[43m");
+    assert_eq!(info.set_background_color(100).unwrap(), b"\x1B[48;5;100m");
+    assert_eq!(info.set_background_color(256), Err(FormatError::ColorOutOfRange(256)));
+}
+
+#[test]
+fn ansi_16() {
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 16)], &[
+        (StringCapability::SetAnsiForeground, "\x1B[%?%p1%{8}%<%t3%p1%d%e9%p1%{8}%-%d%;m"),
+        (StringCapability::SetAnsiBackground, "\x1B[%?%p1%{8}%<%t4%p1%d%e10%p1%{8}%-%d%;m"),
+    ]);
+    assert_eq!(info.set_foreground_color(1).unwrap(), b"\x1B[31m");
+    assert_eq!(info.set_foreground_color(15).unwrap(), b"\x1B[97m");
+    assert_eq!(info.set_background_color(12).unwrap(), b"\x1B[104m");
+    assert_eq!(info.set_foreground_color(16), Err(FormatError::ColorOutOfRange(16)));
+}
+
+#[test]
+fn legacy_8() {
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)], &[
+        (StringCapability::SetForeground, "\x1B[3%p1%dm"),
+        (StringCapability::SetBackground, "\x1B[4%p1%dm"),
+    ]);
+    // black, red, green, yellow, blue, magenta, cyan, white in setf order
+    let order = [0, 4, 2, 6, 1, 5, 3, 7];
+    for (ansi, legacy) in order.iter().enumerate() {
+        let expected = format!("\x1B[3{}m", legacy);
+        assert_eq!(info.set_foreground_color(ansi as u16).unwrap(), expected.as_bytes());
+        let expected = format!("\x1B[4{}m", legacy);
+        assert_eq!(info.set_background_color(ansi as u16).unwrap(), expected.as_bytes());
+    }
+    assert_eq!(info.set_foreground_color(8), Err(FormatError::ColorOutOfRange(8)));
+}
+
+#[test]
+fn no_colors() {
+    let info = entry_with_numbers(&[], &[], &[]);
+    let not_found = FormatError::CapabilityNotFound(StringCapability::SetAnsiForeground);
+    assert_eq!(info.set_foreground_color(1), Err(not_found));
+    let not_found = FormatError::CapabilityNotFound(StringCapability::SetAnsiBackground);
+    assert_eq!(info.set_background_color(1), Err(not_found));
+}
+
+#[test]
+fn default_color() {
+    // AX, SGR 39 and 49
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert!(info.supports_default_colors());
+    assert_eq!(info.set_foreground_color(Color::Default).unwrap(), b"\x1B[39m");
+    assert_eq!(info.set_background_color(Color::Default).unwrap(), b"\x1B[49m");
+    assert_eq!(info.set_foreground_color(Color::Index(2)), info.set_foreground_color(2));
+
+    // op without AX
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)], &[
+        (StringCapability::SetAnsiForeground, "\x1B[3%p1%dm"),
+        (StringCapability::ExitAttributeMode, "\x1B[m"),
+        (StringCapability::OrigColorPair, "\x1B[39;49m"),
+        (StringCapability::OrigColors, "\x1B]R"),
+    ]);
+    assert!(!info.supports_default_colors());
+    assert_eq!(info.set_foreground_color(Color::Default).unwrap(), b"\x1B[39;49m");
+    assert_eq!(info.set_background_color(Color::Default).unwrap(), b"\x1B[39;49m");
+    assert_eq!(info.reset_colors(), Some("\x1B[m\x1B[39;49m".to_string()));
+
+    // only oc
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)], &[
+        (StringCapability::SetAnsiForeground, "\x1B[3%p1%dm"),
+        (StringCapability::OrigColors, "\x1B]R"),
+    ]);
+    assert_eq!(info.set_foreground_color(Color::Default).unwrap(), b"\x1B]R");
+    assert_eq!(info.reset_colors(), Some("\x1B]R".to_string()));
+
+    // neither
+    let info = entry_with_numbers(&[], &[], &[(StringCapability::ExitAttributeMode, "\x1B[m")]);
+    let not_found = FormatError::CapabilityNotFound(StringCapability::OrigColorPair);
+    assert_eq!(info.set_background_color(Color::Default), Err(not_found));
+    assert_eq!(info.reset_colors(), Some("\x1B[m".to_string()));
+    assert_eq!(entry_with_numbers(&[], &[], &[]).reset_colors(), None);
+}
+
+#[test]
+fn summary() {
+    // name, colors, pairs, has_colors
+    let table = [("vt100", 0, 0, false),
+                 ("linux", 8, 64, true),
+                 ("xterm-256color", 256, 0x10000, true),
+                 ("xterm-direct", 0x1000000, 0x10000, true)];
+    for (name, colors, pairs, has_colors) in &table {
+        let info = TermInfo::from_file(&format!("tests/data/{}", name)).unwrap();
+        assert_eq!(info.colors(), *colors, "{}", name);
+        assert_eq!(info.pairs(), *pairs, "{}", name);
+        assert_eq!(info.has_colors(), *has_colors, "{}", name);
+        assert_eq!(info.monochrome(), !*has_colors, "{}", name);
+    }
+
+    // colors without a way to set them, setf of older terminals
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)], &[]);
+    assert_eq!(info.colors(), 8);
+    assert!(info.monochrome());
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)],
+                                  &[(StringCapability::SetForeground, "\x1B[3%p1%dm")]);
+    assert!(info.has_colors());
+    // fewer than 8 colors
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 2)],
+                                  &[(StringCapability::SetAnsiForeground, "\x1B[3%p1%dm")]);
+    assert!(info.monochrome());
+    // absent and cancelled
+    let info = entry_with_numbers(&[],
+                                  &[(NumberCapability::MaxColors, -2), (NumberCapability::MaxPairs, -1)],
+                                  &[(StringCapability::SetAnsiForeground, "\x1B[3%p1%dm")]);
+    assert_eq!((info.colors(), info.pairs()), (0, 0));
+    assert!(info.monochrome());
+}
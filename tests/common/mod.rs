@@ -0,0 +1,63 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Helpers shared by the integration tests
+
+use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use cxterminfo::terminfo::TermInfo;
+
+/// Compiles a minimal legacy entry named "test" with the given capabilities
+#[allow(dead_code)]
+pub fn entry(bools: &[BoolCapability], strings: &[(StringCapability, &str)]) -> TermInfo {
+    entry_with_numbers(bools, &[], strings)
+}
+
+/// Same as `entry` with number capabilities
+pub fn entry_with_numbers(bools: &[BoolCapability],
+                          numbers: &[(NumberCapability, i16)],
+                          strings: &[(StringCapability, &str)]) -> TermInfo {
+    let bool_count = bools.iter().map(|cap| *cap as usize + 1).max().unwrap_or(0);
+    let num_count = numbers.iter().map(|(cap, _)| *cap as usize + 1).max().unwrap_or(0);
+    let str_count = strings.iter().map(|(cap, _)| *cap as usize + 1).max().unwrap_or(0);
+
+    let mut flags = vec![0u8; bool_count];
+    for cap in bools {
+        flags[*cap as usize] = 1;
+    }
+
+    let mut values = vec![-1i16; num_count];
+    for (cap, value) in numbers {
+        values[*cap as usize] = *value;
+    }
+
+    let mut offsets = vec![-1i16; str_count];
+    let mut table = Vec::new();
+    for (cap, value) in strings {
+        offsets[*cap as usize] = table.len() as i16;
+        table.extend_from_slice(value.as_bytes());
+        table.push(0);
+    }
+
+    let names = b"test\0";
+    let mut data = Vec::new();
+    for n in &[0o432, names.len(), bool_count, num_count, str_count, table.len()] {
+        data.extend_from_slice(&(*n as i16).to_le_bytes());
+    }
+    data.extend_from_slice(names);
+    data.extend_from_slice(&flags);
+    if (names.len() + bool_count) % 2 == 1 {
+        data.push(0);
+    }
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    for offset in offsets {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(&table);
+
+    TermInfo::from_data(data).unwrap()
+}
@@ -0,0 +1,176 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Expands real capability strings and compares them with the output of ncurses `tparm`
+//!
+//! The capability strings are taken from the ncurses terminfo database (6.x), the expected
+//! expansions were captured once with `tparm` for the given parameters.
+
+#![cfg(feature = "param-string")]
+
+extern crate cxterminfo;
+
+use cxterminfo::param_string::{evaluate, Param, ParamString};
+
+struct Case {
+    name: &'static str,
+    cap: &'static str,
+    expansions: &'static [(&'static [i32], &'static str)],
+}
+
+const CUP: &str = "\x1B[%i%p1%d;%p2%dH";
+const CSR: &str = "\x1B[%i%p1%d;%p2%dr";
+const SETAF: &str = "\x1B[%?%p1%{8}%<%t3%p1%d%e%p1%{16}%<%t9%p1%{8}%-%d%e38;5;%p1%d%;m";
+const SETAB: &str = "\x1B[%?%p1%{8}%<%t4%p1%d%e%p1%{16}%<%t10%p1%{8}%-%d%e48;5;%p1%d%;m";
+const INITC: &str = "\x1B]4;%p1%d;rgb:%p2%{255}%*%{1000}%/%2.2X/%p3%{255}%*%{1000}%/%2.2X/\
+                     %p4%{255}%*%{1000}%/%2.2X\x1B\\";
+const SGR: &str = "%?%p9%t\x1B(0%e\x1B(B%;\x1B[0%?%p6%t;1%;%?%p5%t;2%;%?%p2%t;4%;\
+                   %?%p1%p3%|%t;7%;%?%p4%t;5%;%?%p7%t;8%;m";
+const SCREEN_SGR: &str = "\x1B[0%?%p6%t;1%;%?%p1%t;3%;%?%p2%t;4%;%?%p3%t;7%;%?%p4%t;5%;\
+                          %?%p5%t;2%;m%?%p9%t\x0E%e\x0F%;";
+const MS: &str = "\x1B]52;%p1%s;%p2%s\x07";
+
+const CORPUS: &[Case] = &[
+    Case {
+        name: "xterm-256color cup",
+        cap: CUP,
+        expansions: &[(&[0, 0], "\x1B[1;1H"),
+                      (&[4, 9], "\x1B[5;10H"),
+                      (&[23, 79], "\x1B[24;80H"),
+                      (&[99, 199], "\x1B[100;200H")],
+    },
+    Case {
+        name: "xterm-256color csr",
+        cap: CSR,
+        expansions: &[(&[0, 23], "\x1B[1;24r"),
+                      (&[5, 10], "\x1B[6;11r"),
+                      (&[0, 0], "\x1B[1;1r")],
+    },
+    Case {
+        name: "xterm-256color setaf",
+        cap: SETAF,
+        expansions: &[(&[0], "\x1B[30m"),
+                      (&[1], "\x1B[31m"),
+                      (&[7], "\x1B[37m"),
+                      (&[8], "\x1B[90m"),
+                      (&[15], "\x1B[97m"),
+                      (&[16], "\x1B[38;5;16m"),
+                      (&[196], "\x1B[38;5;196m"),
+                      (&[255], "\x1B[38;5;255m")],
+    },
+    Case {
+        name: "xterm-256color setab",
+        cap: SETAB,
+        expansions: &[(&[0], "\x1B[40m"),
+                      (&[7], "\x1B[47m"),
+                      (&[8], "\x1B[100m"),
+                      (&[15], "\x1B[107m"),
+                      (&[16], "\x1B[48;5;16m"),
+                      (&[255], "\x1B[48;5;255m")],
+    },
+    Case {
+        name: "xterm-256color initc",
+        cap: INITC,
+        expansions: &[(&[0, 0, 0, 0], "\x1B]4;0;rgb:00/00/00\x1B\\"),
+                      (&[1, 1000, 0, 0], "\x1B]4;1;rgb:FF/00/00\x1B\\"),
+                      (&[15, 500, 250, 125], "\x1B]4;15;rgb:7F/3F/1F\x1B\\"),
+                      (&[255, 1000, 1000, 1000], "\x1B]4;255;rgb:FF/FF/FF\x1B\\")],
+    },
+    Case {
+        name: "xterm-256color sgr",
+        cap: SGR,
+        expansions: &[(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "\x1B(B\x1B[0m"),
+                      (&[1, 0, 0, 0, 0, 0, 0, 0, 0], "\x1B(B\x1B[0;7m"),
+                      (&[0, 1, 0, 0, 0, 0, 0, 0, 0], "\x1B(B\x1B[0;4m"),
+                      (&[0, 0, 1, 0, 0, 0, 0, 0, 0], "\x1B(B\x1B[0;7m"),
+                      (&[0, 0, 0, 1, 0, 0, 0, 0, 0], "\x1B(B\x1B[0;5m"),
+                      (&[0, 0, 0, 0, 1, 0, 0, 0, 0], "\x1B(B\x1B[0;2m"),
+                      (&[0, 0, 0, 0, 0, 1, 0, 0, 0], "\x1B(B\x1B[0;1m"),
+                      (&[0, 0, 0, 0, 0, 0, 1, 0, 0], "\x1B(B\x1B[0;8m"),
+                      (&[0, 0, 0, 0, 0, 0, 0, 1, 0], "\x1B(B\x1B[0m"),
+                      (&[0, 0, 0, 0, 0, 0, 0, 0, 1], "\x1B(0\x1B[0m"),
+                      (&[1, 1, 1, 1, 1, 1, 1, 1, 1], "\x1B(0\x1B[0;1;2;4;7;5;8m"),
+                      (&[0, 1, 0, 1, 0, 1, 0, 0, 1], "\x1B(0\x1B[0;1;4;5m")],
+    },
+    Case {
+        name: "screen-256color-s sgr",
+        cap: SCREEN_SGR,
+        expansions: &[(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "\x1B[0m\x0F"),
+                      (&[1, 0, 0, 0, 0, 0, 0, 0, 0], "\x1B[0;3m\x0F"),
+                      (&[0, 1, 0, 0, 0, 0, 0, 0, 0], "\x1B[0;4m\x0F"),
+                      (&[0, 0, 1, 0, 0, 0, 0, 0, 0], "\x1B[0;7m\x0F"),
+                      (&[0, 0, 0, 1, 0, 0, 0, 0, 0], "\x1B[0;5m\x0F"),
+                      (&[0, 0, 0, 0, 1, 0, 0, 0, 0], "\x1B[0;2m\x0F"),
+                      (&[0, 0, 0, 0, 0, 1, 0, 0, 0], "\x1B[0;1m\x0F"),
+                      (&[0, 0, 0, 0, 0, 0, 0, 0, 1], "\x1B[0m\x0E"),
+                      (&[1, 1, 1, 1, 1, 1, 1, 1, 1], "\x1B[0;1;3;4;7;5;2m\x0E")],
+    },
+];
+
+fn numbers(values: &[i32]) -> Vec<Param<'static>> {
+    values.iter().map(|v| Param::Number(*v)).collect()
+}
+
+#[test]
+fn corpus_expansions() {
+    for case in CORPUS {
+        for (params, expected) in case.expansions {
+            let result = evaluate(case.cap, &numbers(params));
+            assert_eq!(result.as_ref().map(|s| s.as_str()),
+                       Ok(*expected),
+                       "{} with {:?}",
+                       case.name,
+                       params);
+        }
+    }
+}
+
+#[test]
+fn corpus_compiled_expansions() {
+    for case in CORPUS {
+        let compiled = ParamString::compile(case.cap).unwrap();
+        for (params, expected) in case.expansions {
+            assert_eq!(compiled.eval(&numbers(params)).unwrap(),
+                       *expected,
+                       "{} with {:?}",
+                       case.name,
+                       params);
+        }
+    }
+}
+
+#[test]
+fn corpus_params_used() {
+    for case in CORPUS {
+        let compiled = ParamString::compile(case.cap).unwrap();
+        for (params, _) in case.expansions {
+            assert_eq!(compiled.params_used() as usize, params.len(), "{}", case.name);
+        }
+    }
+
+    assert_eq!(ParamString::compile(MS).unwrap().params_used(), 2);
+}
+
+#[test]
+fn corpus_measure() {
+    for case in CORPUS {
+        let compiled = ParamString::compile(case.cap).unwrap();
+        for (params, expected) in case.expansions {
+            let measure = compiled.measure(&numbers(params)).unwrap();
+            assert_eq!(measure.bytes, expected.len(), "{} with {:?}", case.name, params);
+            assert_eq!(measure.delay_tenths_ms, 0);
+        }
+    }
+}
+
+#[test]
+fn tmux_set_selection() {
+    let params = [Param::from("c"), Param::from("aGVsbG8=".to_string())];
+    assert_eq!(evaluate(MS, &params).unwrap(), "\x1B]52;c;aGVsbG8=\x07");
+
+    let params = [Param::from("p"), Param::from("")];
+    assert_eq!(evaluate(MS, &params).unwrap(), "\x1B]52;p;\x07");
+}
@@ -0,0 +1,95 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Defaults of absent capabilities like curses assumes them
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use cxterminfo::terminfo::TermInfo;
+
+use common::{entry, entry_with_numbers};
+
+#[test]
+fn strings_of_empty_entry() {
+    let info = entry(&[], &[]);
+    assert_eq!(info.get_string_or_default(StringCapability::Bell), Some("\x07".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CarriageReturn), Some("\r".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CursorDown), Some("\n".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::ScrollForward), Some("\n".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CursorLeft), None);
+    assert_eq!(info.get_string_or_default(StringCapability::Tab), None);
+    assert_eq!(info.get_string_or_default(StringCapability::ClearScreen), None);
+    for cap in StringCapability::all() {
+        assert_eq!(info.get_string(cap), None, "{}", cap);
+    }
+}
+
+#[test]
+fn strings_from_obsolete_capabilities() {
+    let info = entry(&[BoolCapability::BackspacesWithBs, BoolCapability::HasHardwareTabs,
+                       BoolCapability::NoCorrectlyWorkingCr, BoolCapability::CrtNoScrolling], &[]);
+    assert_eq!(info.get_string_or_default(StringCapability::CursorLeft), Some("\x08".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::Tab), Some("\t".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CarriageReturn), None);
+    assert_eq!(info.get_string_or_default(StringCapability::ScrollForward), None);
+
+    let info = entry(&[BoolCapability::BackspacesWithBs],
+                     &[(StringCapability::BackspaceIfNotBs, "\x1B[D"),
+                       (StringCapability::LinefeedIfNotLf, "\x1B[B")]);
+    assert_eq!(info.get_string_or_default(StringCapability::CursorLeft), Some("\x1B[D".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CursorDown), Some("\x1B[B".to_string()));
+}
+
+#[test]
+fn present_values_win() {
+    let info = entry(&[BoolCapability::NoCorrectlyWorkingCr],
+                     &[(StringCapability::Bell, "\x1B[?5h"), (StringCapability::CarriageReturn, "\r")]);
+    assert_eq!(info.get_string_or_default(StringCapability::Bell), Some("\x1B[?5h".to_string()));
+    assert_eq!(info.get_string_or_default(StringCapability::CarriageReturn), Some("\r".to_string()));
+
+    let xterm = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    for cap in StringCapability::all().filter(|cap| xterm.get_string(*cap).is_some()) {
+        assert_eq!(xterm.get_string_or_default(cap), xterm.get_string(cap), "{}", cap);
+    }
+}
+
+#[test]
+fn numbers() {
+    let info = entry(&[], &[]);
+    assert_eq!(info.get_number_or_default(NumberCapability::InitTabs), None);
+
+    let info = entry(&[], &[(StringCapability::Tab, "\t")]);
+    assert_eq!(info.get_number_or_default(NumberCapability::InitTabs), Some(8));
+
+    let info = entry(&[BoolCapability::HasHardwareTabs], &[]);
+    assert_eq!(info.get_number_or_default(NumberCapability::InitTabs), Some(8));
+
+    std::env::set_var("COLUMNS", "132");
+    assert_eq!(info.get_number(NumberCapability::Columns), None);
+    assert_eq!(info.get_number_or_default(NumberCapability::Columns), Some(132));
+    std::env::set_var("COLUMNS", "-1");
+    assert_eq!(info.get_number_or_default(NumberCapability::Columns), None);
+}
+
+#[test]
+fn dimensions() {
+    let numbers = [(NumberCapability::Columns, 132), (NumberCapability::Lines, 43)];
+    let info = entry_with_numbers(&[], &numbers, &[]);
+    assert_eq!(info.dimensions(), Some((132, 43)));
+
+    // no defaults from the environment
+    std::env::set_var("LINES", "24");
+    for numbers in &[[(NumberCapability::Columns, 80), (NumberCapability::Lines, 0)],
+                     [(NumberCapability::Columns, 80), (NumberCapability::Lines, -1)],
+                     [(NumberCapability::Columns, -2), (NumberCapability::Lines, 24)]] {
+        assert_eq!(entry_with_numbers(&[], numbers, &[]).dimensions(), None, "{:?}", numbers);
+    }
+}
@@ -0,0 +1,45 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Detection of dumb terminals
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::TermInfo;
+
+use common::entry_with_numbers;
+
+const STRINGS: [(StringCapability, &str); 5] =
+    [(StringCapability::CursorAddress, "\x1B[%i%p1%d;%p2%dH"),
+     (StringCapability::CursorHome, "\x1B[H"),
+     (StringCapability::CursorUp, "\x1B[A"),
+     (StringCapability::ClearScreen, "\x1B[H\x1B[2J"),
+     (StringCapability::Bell, "\x07")];
+
+#[test]
+fn columns() {
+    let info = entry_with_numbers(&[], &[(NumberCapability::Columns, 80)], &STRINGS);
+    assert!(!info.is_dumb());
+    let info = entry_with_numbers(&[], &[(NumberCapability::Columns, 0)], &STRINGS);
+    assert!(info.is_dumb());
+    // absent
+    let info = entry_with_numbers(&[], &[], &STRINGS);
+    assert!(info.is_dumb());
+}
+
+#[test]
+fn database() {
+    assert!(!TermInfo::from_file("tests/data/xterm-256color").unwrap().is_dumb());
+    // the console leaves cols absent
+    let linux = TermInfo::from_file("tests/data/linux").unwrap();
+    assert_eq!(linux.get_number(NumberCapability::Columns), None);
+    assert!(linux.is_dumb());
+}
@@ -0,0 +1,71 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Terminal detection refined by `TERM_PROGRAM` and `COLORTERM` in a terminfo directory set by
+//! `TERMINFO` of a fake environment
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cxterminfo::terminfo::{TermInfo, TermInfoError};
+
+/// Creates a terminfo directory with xterm-256color, tmux-256color and xterm-direct stored as
+/// `w/wezterm`
+fn terminfo_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cxterminfo-hints-{}", std::process::id()));
+    for (letter, name, file) in &[("x", "xterm-256color", "xterm-256color"),
+                                  ("t", "tmux-256color", "tmux-256color"),
+                                  ("w", "wezterm", "xterm-direct")] {
+        fs::create_dir_all(dir.join(letter)).unwrap();
+        fs::copy(format!("tests/data/{}", file), dir.join(letter).join(name)).unwrap();
+    }
+    dir
+}
+
+/// Environment with the given variables and `TERMINFO` set to dir
+fn env_in(dir: &Path, vars: &[(&str, &str)]) -> HashMap<String, String> {
+    let mut env: HashMap<String, String> =
+        vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect();
+    env.insert("TERMINFO".to_string(), dir.to_string_lossy().into_owned());
+    env
+}
+
+#[test]
+fn hints() {
+    let dir = terminfo_dir();
+    let env = |vars: &[(&str, &str)]| env_in(&dir, vars);
+
+    // entry of the program
+    let info = TermInfo::from_provider_with_hints(&env(&[("TERM", "xterm-256color"),
+                                                         ("TERM_PROGRAM", "WezTerm")])).unwrap();
+    assert_eq!(info.colors(), 0x1000000);
+    assert_eq!(info.undercurl(), Some("\x1B[4:3m".to_string()));
+
+    // unknown program, upgrades only
+    let info = TermInfo::from_provider_with_hints(&env(&[("TERM", "xterm-256color"),
+                                                         ("TERM_PROGRAM", "unknown"),
+                                                         ("COLORTERM", "truecolor")])).unwrap();
+    assert_eq!(info.name(), "xterm-256color");
+    assert_eq!(info.get_ext_bool("Tc"), Some(&true));
+    assert_eq!(info.undercurl(), None);
+
+    // TERM isn't generic
+    let info = TermInfo::from_provider_with_hints(&env(&[("TERM", "tmux-256color"),
+                                                         ("TERM_PROGRAM", "WezTerm")])).unwrap();
+    assert_eq!(info.name(), "tmux-256color");
+    assert_eq!(info.get_ext_bool("Tc"), None);
+
+    assert!(matches!(TermInfo::from_provider_with_hints(&env(&[("TERM_PROGRAM", "WezTerm")])),
+                     Err(TermInfoError::InvalidName)));
+    assert!(TermInfo::from_provider_with_hints(&env(&[("TERM", "cx-unknown")])).is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,83 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Capabilities revealed by `COLORTERM` and `TERM_PROGRAM`, probed with a fake environment
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use std::collections::HashMap;
+
+use cxterminfo::ext::{ColorSupport, DirectMethod, EnvUpgrade};
+use cxterminfo::terminfo::TermInfo;
+
+fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+    vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+fn xterm() -> TermInfo {
+    TermInfo::from_file("tests/data/xterm-256color").unwrap()
+}
+
+#[test]
+fn probe() {
+    let info = xterm();
+    assert!(info.probe_env_upgrades(&env(&[])).is_empty());
+    for colorterm in &["truecolor", "24bit"] {
+        let upgrades = info.probe_env_upgrades(&env(&[("COLORTERM", colorterm)]));
+        assert_eq!(upgrades, vec![EnvUpgrade::Truecolor]);
+    }
+    assert!(info.probe_env_upgrades(&env(&[("COLORTERM", "yes")])).is_empty());
+
+    // xterm-256color has sitm
+    let iterm = env(&[("TERM_PROGRAM", "iTerm.app")]);
+    assert_eq!(info.probe_env_upgrades(&iterm), vec![EnvUpgrade::Undercurl]);
+    assert!(info.probe_env_upgrades(&env(&[("TERM_PROGRAM", "Apple_Terminal")])).is_empty());
+    assert!(info.probe_env_upgrades(&env(&[("TERM_PROGRAM", "unknown")])).is_empty());
+
+    // no colors, no italics
+    let vt100 = TermInfo::from_file("tests/data/vt100").unwrap();
+    let all = env(&[("COLORTERM", "truecolor"), ("TERM_PROGRAM", "ghostty")]);
+    assert_eq!(vt100.probe_env_upgrades(&all), vec![EnvUpgrade::Italics, EnvUpgrade::Undercurl]);
+}
+
+#[test]
+fn never_downgrade() {
+    let all = env(&[("COLORTERM", "truecolor"), ("TERM_PROGRAM", "WezTerm")]);
+
+    // Smulx
+    let mut tmux = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    let smulx = tmux.get_ext_string("Smulx").cloned();
+    assert_eq!(tmux.apply_env_upgrades(&all), vec![EnvUpgrade::Truecolor]);
+    assert_eq!(tmux.get_ext_string("Smulx").cloned(), smulx);
+
+    // setrgbf and Smulx, Tc, direct colors
+    let tmux = TermInfo::from_file("tests/data/tmux-setrgb").unwrap();
+    assert!(tmux.probe_env_upgrades(&all).is_empty());
+    let tc = TermInfo::from_file("tests/data/xterm-tc").unwrap();
+    assert!(tc.probe_env_upgrades(&env(&[("COLORTERM", "truecolor")])).is_empty());
+    let direct = TermInfo::from_file("tests/data/xterm-direct").unwrap();
+    assert_eq!(direct.probe_env_upgrades(&all), vec![EnvUpgrade::Undercurl]);
+}
+
+#[test]
+fn apply() {
+    let mut info = xterm();
+    let all = env(&[("COLORTERM", "truecolor"), ("TERM_PROGRAM", "vscode")]);
+    assert_eq!(info.apply_env_upgrades(&all), vec![EnvUpgrade::Truecolor, EnvUpgrade::Undercurl]);
+    assert_eq!(info.get_ext_bool("Tc"), Some(&true));
+    assert_eq!(info.color_support(), ColorSupport::Direct { via: DirectMethod::Truecolor });
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), Some("\x1B[38;2;1;2;3m".to_string()));
+    assert_eq!(info.undercurl(), Some("\x1B[4:3m".to_string()));
+    assert!(info.apply_env_upgrades(&all).is_empty());
+
+    // italics is only reported
+    let mut vt100 = TermInfo::from_file("tests/data/vt100").unwrap();
+    let apple = env(&[("TERM_PROGRAM", "Apple_Terminal")]);
+    assert_eq!(vt100.probe_env_upgrades(&apple), vec![EnvUpgrade::Italics]);
+    assert!(vt100.apply_env_upgrades(&apple).is_empty());
+}
@@ -0,0 +1,162 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! `TermInfo::format` agrees with evaluating the capability
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::StringCapability;
+use cxterminfo::param_string::{evaluate, evaluate_bytes, Param};
+use cxterminfo::terminfo::{FormatError, TermInfo};
+
+fn xterm() -> TermInfo {
+    TermInfo::from_file("tests/data/xterm-256color").unwrap()
+}
+
+#[test]
+fn parameterized() {
+    let info = xterm();
+    let cup = [Param::Number(0), Param::Number(0)];
+    assert_eq!(info.format(StringCapability::CursorAddress, &cup).unwrap(), b"\x1B[1;1H");
+    // cached, same result for other parameters
+    let cup = [Param::Number(23), Param::Number(79)];
+    assert_eq!(info.format(StringCapability::CursorAddress, &cup).unwrap(), b"\x1B[24;80H");
+    assert_eq!(info.param_count(StringCapability::CursorAddress), Some(2));
+
+    for (color, expected) in &[(1, &b"\x1B[31m"[..]), (9, b"\x1B[91m"), (200, b"\x1B[38;5;200m")] {
+        let setaf = info.format(StringCapability::SetAnsiForeground, &[Param::Number(*color)]);
+        assert_eq!(setaf.unwrap(), *expected);
+    }
+
+    let sgr = (0..9).map(|n| Param::Number((n == 1) as i32)).collect::<Vec<Param>>();
+    let term = info.get_string(StringCapability::SetAttributes).unwrap();
+    assert_eq!(info.format(StringCapability::SetAttributes, &sgr).unwrap(),
+               evaluate(&term, &sgr).unwrap().as_bytes());
+}
+
+#[test]
+fn utf8_parameter() {
+    // entry bytes are written as they are, text of %s parameters as UTF-8
+    let term = "\x1B]2;\u{2192} %p1%s\x07";
+    let info = common::entry(&[], &[(StringCapability::ToStatusLine, term)]);
+    let params = [Param::from("caf\u{E9} \u{2192}")];
+    let tsl = info.format(StringCapability::ToStatusLine, &params).unwrap();
+    assert_eq!(tsl, "\x1B]2;\u{2192} caf\u{E9} \u{2192}\x07".as_bytes());
+    assert_eq!(tsl, evaluate_bytes(term.as_bytes(), &params).unwrap());
+    assert_eq!(info.format_with_padding(StringCapability::ToStatusLine, &params).unwrap(), tsl);
+}
+
+#[test]
+fn constant() {
+    let info = xterm();
+    assert_eq!(info.format(StringCapability::ClearScreen, &[]).unwrap(), b"\x1B[H\x1B[2J");
+    assert_eq!(info.format(StringCapability::Bell, &[]).unwrap(), b"\x07");
+}
+
+#[test]
+fn padding() {
+    let info = xterm();
+    assert_eq!(info.format(StringCapability::FlashScreen, &[]).unwrap(), b"\x1B[?5h\x1B[?5l");
+    assert_eq!(info.format_with_padding(StringCapability::FlashScreen, &[]).unwrap(),
+               b"\x1B[?5h$<100/>\x1B[?5l");
+}
+
+#[test]
+fn not_found() {
+    let info = xterm();
+    let err = info.format(StringCapability::ToStatusLine, &[]).unwrap_err();
+    assert_eq!(err, FormatError::CapabilityNotFound(StringCapability::ToStatusLine));
+    assert_eq!(info.format_with_padding(StringCapability::ToStatusLine, &[]).unwrap_err(), err);
+}
+
+#[test]
+fn extended() {
+    // tmux-256color doesn't define Setulc, cx-ext uses the kitty definition
+    let info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    let setulc = info.format_ext("Setulc", &[Param::Number(0xFF8000)]).unwrap();
+    assert_eq!(setulc, b"\x1B[58:2::255:128:0m");
+    assert_eq!(info.ext_param_count("Setulc"), Some(1));
+
+    let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    let ms = info.format_ext("Ms", &[Param::from("c"), Param::from("aGVsbG8=")]).unwrap();
+    assert_eq!(ms, b"\x1B]52;c;aGVsbG8=\x07");
+    // OSC 52 with a UTF-8 parameter, the same bytes as evaluating the capability
+    let params = [Param::from("c"), Param::from("\u{E9}\u{2192}")];
+    let ms = info.format_ext("Ms", &params).unwrap();
+    assert_eq!(ms, "\x1B]52;c;\u{E9}\u{2192}\x07".as_bytes());
+    let term = info.get_ext_string("Ms").unwrap().chars().map(|c| c as u8).collect::<Vec<u8>>();
+    assert_eq!(ms, evaluate_bytes(&term, &params).unwrap());
+    assert_eq!(info.format_ext_with_padding("Ms", &params).unwrap(), ms);
+    assert_eq!(info.format_ext("Smulx", &[Param::Number(3)]).unwrap(), b"\x1B[4:3m");
+    assert_eq!(info.format_ext_with_padding("Smulx", &[Param::Number(0)]).unwrap(), b"\x1B[4:0m");
+
+    let err = info.format_ext("Setulc", &[Param::Number(0)]).unwrap_err();
+    assert_eq!(err, FormatError::ExtCapabilityNotFound("Setulc".to_string()));
+    assert_eq!(err.to_string(), "extended capability Setulc not found");
+}
+
+#[test]
+fn display() {
+    let info = xterm();
+    let cup = [Param::Number(23), Param::Number(79)];
+    let sgr = (0..9).map(|n| Param::Number((n % 3 == 0) as i32)).collect::<Vec<Param>>();
+    let cases: Vec<(StringCapability, &[Param])> = vec![
+        (StringCapability::CursorAddress, &cup),
+        (StringCapability::SetAnsiForeground, &cup[1..]),
+        (StringCapability::SetAnsiBackground, &cup[..1]),
+        (StringCapability::SetAttributes, &sgr),
+        (StringCapability::ClearScreen, &[]),
+        (StringCapability::FlashScreen, &[]),
+        (StringCapability::ParmDeleteLine, &cup[..1]),
+    ];
+
+    for (cap, params) in cases {
+        let expected = info.format(cap, params).unwrap();
+        let display = info.display(cap, params);
+        assert_eq!(display.to_string().as_bytes(), &expected[..], "{}", cap);
+        assert_eq!(display.error(), None);
+
+        let mut out = Vec::new();
+        display.write_to(&mut out).unwrap();
+        assert_eq!(out, expected, "{}", cap);
+    }
+}
+
+#[test]
+fn display_utf8_parameter() {
+    let term = "\x1B]2;\u{2192} %p1%s\x07";
+    let info = common::entry(&[], &[(StringCapability::ToStatusLine, term)]);
+    let params = [Param::from("caf\u{E9} \u{2192}")];
+    let expected = info.format(StringCapability::ToStatusLine, &params).unwrap();
+
+    let mut out = Vec::new();
+    info.display(StringCapability::ToStatusLine, &params).write_to(&mut out).unwrap();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn display_errors() {
+    let info = xterm();
+    let display = info.display(StringCapability::ToStatusLine, &[]);
+    let mut text = String::new();
+    assert!(std::fmt::write(&mut text, format_args!("a{}b", display)).is_err());
+    let expected = FormatError::CapabilityNotFound(StringCapability::ToStatusLine);
+    assert_eq!(display.error(), Some(expected));
+
+    let err = display.write_to(&mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), display.error().unwrap().to_string());
+
+    // errors of the writer aren't errors of the capability
+    let display = info.display(StringCapability::ClearScreen, &[]);
+    let err = display.write_to(&mut &mut [0u8; 2][..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    assert_eq!(display.error(), None);
+}
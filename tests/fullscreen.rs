@@ -0,0 +1,43 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Entering and leaving full-screen mode, with and without the alternate screen
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::terminfo::TermInfo;
+
+use common::entry;
+
+#[test]
+fn alternate_screen() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    let (enter, leave) = info.enter_fullscreen(true);
+    assert_eq!(enter, b"\x1B[?1049h\x1B[22;0;0t\x1B[?1h\x1B=");
+    assert_eq!(leave, b"\x1B[?12l\x1B[?25h\x1B[?1l\x1B>\x1B[?1049l\x1B[23;0;0t");
+    assert_eq!(info.leave_fullscreen(true), leave);
+    assert_eq!(info.leave_fullscreen(false), b"\x1B[?12l\x1B[?25h\x1B[?1049l\x1B[23;0;0t");
+}
+
+#[test]
+fn clear_without_alternate_screen() {
+    // no smcup, rmcup and cnorm, the padding of clear is removed
+    let info = TermInfo::from_file("tests/data/vt100").unwrap();
+    let (enter, leave) = info.enter_fullscreen(true);
+    assert_eq!(enter, b"\x1B[H\x1B[J\x1B[?1h\x1B=");
+    assert_eq!(leave, b"\x1B[?1l\x1B>\x1B[H\x1B[J");
+    assert_eq!(info.enter_fullscreen(false), (b"\x1B[H\x1B[J".to_vec(), b"\x1B[H\x1B[J".to_vec()));
+}
+
+#[test]
+fn dumb() {
+    let info = entry(&[], &[]);
+    assert_eq!(info.enter_fullscreen(true), (vec![], vec![]));
+}
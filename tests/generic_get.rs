@@ -0,0 +1,85 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Lookups through `TermInfo::get` agree with the concrete getters for every capability
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{BoolCapability, Capability, NumberCapability, StringCapability};
+use cxterminfo::terminfo::TermInfo;
+
+use common::entry;
+
+fn xterm() -> TermInfo {
+    TermInfo::from_file("tests/data/xterm-256color").unwrap()
+}
+
+/// Capabilities of `caps` present in both entries with different values
+fn differences<C>(a: &TermInfo, b: &TermInfo, caps: impl Iterator<Item = C>) -> Vec<C>
+    where C: Capability, for<'a> C::Value<'a>: PartialEq {
+    caps.filter(|cap| match (a.get(*cap), b.get(*cap)) {
+        (Some(x), Some(y)) => x != y,
+        _ => false,
+    }).collect()
+}
+
+#[test]
+fn bools() {
+    let info = xterm();
+    for cap in BoolCapability::all() {
+        assert_eq!(info.get(cap), info.get_bool(cap), "{}", cap);
+    }
+    assert_eq!(info.get(BoolCapability::EatNewlineGlitch), Some(true));
+    assert_eq!(info.get(BoolCapability::HardCopy), Some(false));
+}
+
+#[test]
+fn numbers() {
+    let info = xterm();
+    for cap in NumberCapability::all() {
+        assert_eq!(info.get(cap), info.get_number(cap), "{}", cap);
+    }
+    assert_eq!(info.get(NumberCapability::MaxPairs), Some(0x10000));
+    assert_eq!(info.get(NumberCapability::VirtualTerminal), None);
+}
+
+#[test]
+fn strings() {
+    let info = xterm();
+    for cap in StringCapability::all() {
+        let expected = info.get_string(cap).map(|s| s.chars().map(|c| c as u8).collect::<Vec<_>>());
+        assert_eq!(info.get(cap).map(<[u8]>::to_vec), expected, "{}", cap);
+    }
+    assert_eq!(info.get(StringCapability::CursorAddress), Some(&b"\x1B[%i%p1%d;%p2%dH"[..]));
+    assert_eq!(info.get(StringCapability::InsertPadding), None);
+}
+
+#[test]
+fn strings_for_output() {
+    let info = xterm();
+    for cap in StringCapability::all() {
+        assert_eq!(info.get_string_for_output(cap), info.get(cap).map(<[u8]>::to_vec), "{}", cap);
+    }
+
+    // 8-bit CSI, stored as the UTF-8 bytes C2 9B
+    let info = entry(&[], &[(StringCapability::ClearScreen, "\u{9B}H\u{9B}2J")]);
+    let clear = info.get_string_for_output(StringCapability::ClearScreen).unwrap();
+    assert_eq!(clear, b"\xC2\x9BH\xC2\x9B2J");
+    assert_eq!(info.get_string(StringCapability::ClearScreen).unwrap().into_bytes().len(), 11);
+}
+
+#[test]
+fn generic_helper() {
+    let xterm = xterm();
+    let tmux = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    assert!(differences(&xterm, &xterm, StringCapability::all()).is_empty());
+    assert!(differences(&xterm, &tmux, BoolCapability::all()).contains(&BoolCapability::BackColorErase));
+    assert!(differences(&xterm, &tmux, StringCapability::all()).contains(&StringCapability::ClearScreen));
+}
@@ -0,0 +1,100 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Cursor addressing with `cup`, the fallbacks of terminals without it and relative moves
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::StringCapability;
+use cxterminfo::terminfo::{FormatError, TermInfo};
+
+use common::entry;
+
+#[test]
+fn cursor_address() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert_eq!(info.goto(0, 0).unwrap(), b"\x1B[1;1H");
+    assert_eq!(info.goto(4, 9).unwrap(), b"\x1B[5;10H");
+
+    // without %i
+    let info = entry(&[], &[(StringCapability::CursorAddress, "\x1B=%p1%' '%+%c%p2%' '%+%c")]);
+    assert_eq!(info.goto(2, 3).unwrap(), b"\x1B=\"#");
+}
+
+#[test]
+fn home_and_single_steps() {
+    // adm3a style entry without cup
+    let info = entry(&[], &[(StringCapability::CursorHome, "\x1E"),
+                            (StringCapability::CursorDown, "\n"),
+                            (StringCapability::CursorRight, "\x0C")]);
+    assert_eq!(info.goto(0, 0).unwrap(), b"\x1E");
+    assert_eq!(info.goto(2, 3).unwrap(), b"\x1E\n\n\x0C\x0C\x0C");
+
+    // the shorter of parameterized and repeated moves
+    let info = entry(&[], &[(StringCapability::CursorHome, "\x1B[H"),
+                            (StringCapability::CursorDown, "\n"),
+                            (StringCapability::ParmDownCursor, "\x1B[%p1%dB"),
+                            (StringCapability::CursorRight, "\x1B[C"),
+                            (StringCapability::ParmRightCursor, "\x1B[%p1%dC")]);
+    assert_eq!(info.goto(3, 1).unwrap(), b"\x1B[H\n\n\n\x1B[C");
+    assert_eq!(info.goto(10, 40).unwrap(), b"\x1B[H\x1B[10B\x1B[40C");
+}
+
+#[test]
+fn row_and_column_address() {
+    let info = entry(&[], &[(StringCapability::RowAddress, "\x1B[%i%p1%dd"),
+                            (StringCapability::ColumnAddress, "\x1B[%i%p1%dG")]);
+    assert_eq!(info.goto(0, 0).unwrap(), b"\x1B[1d\x1B[1G");
+    assert_eq!(info.goto(4, 9).unwrap(), b"\x1B[5d\x1B[10G");
+
+    // column from the first column after a carriage return
+    let info = entry(&[], &[(StringCapability::RowAddress, "\x1B[%i%p1%dd"),
+                            (StringCapability::ParmRightCursor, "\x1B[%p1%dC")]);
+    assert_eq!(info.goto(1, 20).unwrap(), b"\x1B[2d\r\x1B[20C");
+    assert_eq!(info.goto(1, 0).unwrap(), b"\x1B[2d\r");
+}
+
+#[test]
+fn unreachable() {
+    let not_found = FormatError::CapabilityNotFound(StringCapability::CursorAddress);
+    assert_eq!(entry(&[], &[]).goto(0, 0), Err(not_found.clone()));
+
+    let info = entry(&[], &[(StringCapability::CursorHome, "\x1B[H")]);
+    assert_eq!(info.goto(0, 0).unwrap(), b"\x1B[H");
+    assert_eq!(info.goto(1, 0), Err(not_found.clone()));
+    assert_eq!(info.goto(0, 1), Err(not_found));
+}
+
+#[test]
+fn relative_moves() {
+    // single steps only
+    let info = entry(&[], &[(StringCapability::CursorLeft, "\x08"),
+                            (StringCapability::CursorUp, "\x1BA")]);
+    assert_eq!(info.move_left(3).unwrap(), b"\x08\x08\x08");
+    assert_eq!(info.move_up(2).unwrap(), b"\x1BA\x1BA");
+    assert_eq!(info.move_up(1000).unwrap().len(), 2000);
+    assert_eq!(info.move_right(1), None);
+    assert_eq!(info.move_down(0), Some(Vec::new()));
+
+    // both, the shorter one wins, the parameterized one on a tie
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert_eq!(info.move_down(3).unwrap(), b"\n\n\n");
+    assert_eq!(info.move_down(4).unwrap(), b"\x1B[4B");
+    assert_eq!(info.move_left(3).unwrap(), b"\x08\x08\x08");
+    assert_eq!(info.move_left(4).unwrap(), b"\x1B[4D");
+    assert_eq!(info.move_up(2).unwrap(), b"\x1B[2A");
+    assert_eq!(info.move_right(u16::MAX).unwrap(), b"\x1B[65535C");
+    assert_eq!(info.move_right(0).unwrap(), b"");
+
+    // parameterized only
+    let info = entry(&[], &[(StringCapability::ParmRightCursor, "\x1B[%p1%dC")]);
+    assert_eq!(info.move_right(1).unwrap(), b"\x1B[1C");
+    assert_eq!(info.move_left(1), None);
+}
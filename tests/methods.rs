@@ -0,0 +1,49 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! The generated accessors agree with the getters taking a capability
+
+#![cfg(feature = "methods")]
+
+extern crate cxterminfo;
+
+use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use cxterminfo::terminfo::TermInfo;
+
+fn xterm() -> TermInfo {
+    TermInfo::from_file("tests/data/xterm-256color").unwrap()
+}
+
+#[test]
+fn bools() {
+    let info = xterm();
+    assert_eq!(info.auto_left_margin(), info.get_bool(BoolCapability::AutoLeftMargin));
+    assert_eq!(info.auto_right_margin(), Some(true));
+    assert_eq!(info.back_color_erase(), Some(true));
+    assert_eq!(info.hard_copy(), Some(false));
+    assert_eq!(info.backspaces_with_bs(), info.get_bool(BoolCapability::BackspacesWithBs));
+}
+
+#[test]
+fn numbers() {
+    let info = xterm();
+    assert_eq!(info.columns(), Some(80));
+    assert_eq!(info.lines(), info.get_number(NumberCapability::Lines));
+    assert_eq!(info.max_colors(), Some(256));
+    assert_eq!(info.max_pairs(), Some(0x10000));
+    assert_eq!(info.virtual_terminal(), None);
+}
+
+#[test]
+fn strings() {
+    let info = xterm();
+    assert_eq!(info.cursor_address(), info.get_string(StringCapability::CursorAddress));
+    assert_eq!(info.cursor_address_raw(), Some(&b"\x1B[%i%p1%d;%p2%dH"[..]));
+    assert_eq!(info.bell(), Some("\x07".to_string()));
+    assert_eq!(info.key_f1_raw(), info.get(StringCapability::KeyF1));
+    assert_eq!(info.insert_padding(), None);
+    assert_eq!(info.insert_padding_raw(), None);
+}
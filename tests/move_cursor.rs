@@ -0,0 +1,105 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Cheapest cursor movement between two positions
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+extern crate proptest;
+
+mod common;
+
+use cxterminfo::capabilities::{BoolCapability, StringCapability};
+use cxterminfo::param_string::Param;
+use cxterminfo::terminfo::TermInfo;
+use proptest::prelude::*;
+
+use common::entry;
+
+const SIZE: (u16, u16) = (24, 80);
+
+fn xterm() -> TermInfo {
+    TermInfo::from_file("tests/data/xterm-256color").unwrap()
+}
+
+fn cup(info: &TermInfo, (row, col): (u16, u16)) -> Vec<u8> {
+    let params = [Param::Number(row as i32), Param::Number(col as i32)];
+    info.format(StringCapability::CursorAddress, &params).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn never_longer_than_cup(from in proptest::option::of((0..SIZE.0, 0..=SIZE.1)),
+                             to in (0..SIZE.0, 0..SIZE.1)) {
+        let info = xterm();
+        let moves = info.move_cursor(from, to, SIZE).unwrap();
+        prop_assert!(moves.len() <= cup(&info, to).len());
+    }
+}
+
+#[test]
+fn unknown_position() {
+    let info = xterm();
+    assert_eq!(info.move_cursor(None, (10, 20), SIZE).unwrap(), cup(&info, (10, 20)));
+    assert_eq!(info.move_cursor(None, (0, 0), SIZE).unwrap(), b"\x1B[H");
+    // cup on equal cost
+    assert_eq!(info.move_cursor(None, (0, 1), SIZE).unwrap(), b"\x1B[1;2H");
+    assert_eq!(info.move_cursor(None, (6, 0), SIZE).unwrap(), b"\x1B[7d\r");
+
+    // no cup, vpa and cr
+    let info = entry(&[], &[(StringCapability::RowAddress, "\x1B[%i%p1%dd"),
+                            (StringCapability::CarriageReturn, "\r"),
+                            (StringCapability::CursorRight, "\x1B[C")]);
+    assert_eq!(info.move_cursor(None, (3, 2), SIZE).unwrap(), b"\x1B[4d\r\x1B[C\x1B[C");
+
+    // relative moves need a known position
+    let info = entry(&[], &[(StringCapability::CursorDown, "\n")]);
+    assert_eq!(info.move_cursor(None, (1, 0), SIZE), None);
+    assert_eq!(info.move_cursor(Some((0, 0)), (1, 0), SIZE).unwrap(), b"\n");
+}
+
+#[test]
+fn same_cell() {
+    let info = xterm();
+    assert_eq!(info.move_cursor(Some((0, 0)), (0, 0), SIZE).unwrap(), b"");
+    assert_eq!(info.move_cursor(Some((23, 79)), (23, 79), SIZE).unwrap(), b"");
+    assert_eq!(entry(&[], &[]).move_cursor(Some((5, 5)), (5, 5), SIZE).unwrap(), b"");
+}
+
+#[test]
+fn known_position() {
+    let info = xterm();
+    assert_eq!(info.move_cursor(Some((5, 40)), (5, 0), SIZE).unwrap(), b"\r");
+    assert_eq!(info.move_cursor(Some((5, 40)), (7, 0), SIZE).unwrap(), b"\n\n\r");
+    assert_eq!(info.move_cursor(Some((5, 40)), (5, 1), SIZE).unwrap(), b"\x1B[2G");
+    assert_eq!(info.move_cursor(Some((5, 40)), (5, 2), SIZE).unwrap(), b"\x1B[3G");
+    assert_eq!(info.move_cursor(Some((5, 40)), (5, 38), SIZE).unwrap(), b"\x08\x08");
+    assert_eq!(info.move_cursor(Some((5, 40)), (4, 41), SIZE).unwrap(), b"\x1B[A\x1B[C");
+    assert_eq!(info.move_cursor(Some((5, 40)), (3, 60), SIZE).unwrap(), b"\x1B[4;61H");
+}
+
+#[test]
+fn wrap_at_right_margin() {
+    // xterm has am and xenl, the position after the last column is unknown
+    let info = xterm();
+    let unknown = info.move_cursor(None, (6, 0), SIZE);
+    assert_eq!(info.move_cursor(Some((5, 80)), (6, 0), SIZE), unknown);
+
+    // am wraps to the next line
+    let strings = [(StringCapability::CursorDown, "\n"),
+                   (StringCapability::CursorRight, "\x1B[C"),
+                   (StringCapability::CursorLeft, "\x08"),
+                   (StringCapability::CursorAddress, "\x1B[%i%p1%d;%p2%dH")];
+    let info = entry(&[BoolCapability::AutoRightMargin], &strings);
+    assert_eq!(info.move_cursor(Some((5, 80)), (6, 0), SIZE).unwrap(), b"");
+    assert_eq!(info.move_cursor(Some((5, 80)), (6, 1), SIZE).unwrap(), b"\x1B[C");
+    assert_eq!(info.move_cursor(Some((23, 80)), (23, 0), SIZE).unwrap(), b"");
+
+    // without am the cursor stays in the last column
+    let info = entry(&[], &strings);
+    assert_eq!(info.move_cursor(Some((5, 80)), (5, 78), SIZE).unwrap(), b"\x08");
+}
@@ -0,0 +1,194 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Compares `param_string::evaluate` against ncurses `tparm`
+//!
+//! Run with `cargo test --features ncurses-conformance --test ncurses_conformance`. The
+//! terminals to check are read from `CXTERMINFO_CONFORMANCE_TERMS` (comma separated), the
+//! terminfo database is looked up by ncurses as usual (`TERMINFO` can be used to point to it).
+
+#![cfg(feature = "ncurses-conformance")]
+
+extern crate cxterminfo;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_long};
+
+use cxterminfo::param_string::{evaluate, Evaluator, Param};
+
+#[link(name = "tinfo")]
+extern "C" {
+    static strnames: [*const c_char; 0];
+    fn setupterm(term: *const c_char, fd: c_int, err: *mut c_int) -> c_int;
+    fn tigetstr(name: *const c_char) -> *const c_char;
+    fn tparm(s: *const c_char, ...) -> *const c_char;
+}
+
+const DEFAULT_TERMS: &str = "xterm-256color,xterm,screen,tmux-256color,linux,vt100,vt220,rxvt";
+/// Capabilities not compared
+///
+/// `u6` to `u9` are scanf-style formats of terminal responses and queries (`u6` the cursor
+/// position report `\E[%i%d;%dR`, `u8` the device attributes answer `\E[?%[;0123456789]c`),
+/// they are parsed, not expanded. Strings without `%p` are termcap-style for ncurses, `tparm`
+/// pushes the parameters implicitly and `%i` swaps the first two (`\E[2;1R` for 0 and 1).
+/// `evaluate` only knows terminfo-style strings.
+const SKIPPED: [&str; 4] = ["u6", "u7", "u8", "u9"];
+/// Values used for every parameter
+const GRID: [c_long; 8] = [0, 1, 2, 7, 9, 10, 42, 255];
+
+/// Returns all string capability names known by ncurses
+fn string_names() -> Vec<String> {
+    let mut names = Vec::new();
+    unsafe {
+        let base = strnames.as_ptr();
+        loop {
+            let name = *base.add(names.len());
+            if name.is_null() {
+                break;
+            }
+            names.push(CStr::from_ptr(name).to_string_lossy().into_owned());
+        }
+    }
+    names
+}
+
+/// Parameter sets used to expand a capability
+fn param_grid() -> Vec<[c_long; 9]> {
+    let mut grid = Vec::new();
+
+    // same value for all parameters, shifted by the parameter index
+    for v in GRID.iter() {
+        let mut params = [0; 9];
+        for (i, p) in params.iter_mut().enumerate() {
+            *p = *v + i as c_long;
+        }
+        grid.push(params);
+    }
+
+    // all combinations for the first two parameters
+    for a in GRID.iter() {
+        for b in GRID.iter() {
+            grid.push([*a, *b, 0, 0, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    // all boolean combinations (sgr)
+    for mask in 0..512 {
+        let mut params = [0; 9];
+        for (i, p) in params.iter_mut().enumerate() {
+            *p = ((mask >> i) & 1) as c_long;
+        }
+        grid.push(params);
+    }
+
+    grid
+}
+
+fn tparm_bytes(cap: &CStr, p: &[c_long; 9]) -> Option<Vec<u8>> {
+    unsafe {
+        let res = tparm(cap.as_ptr(), p[0], p[1], p[2], p[3], p[4], p[5], p[6], p[7], p[8]);
+        if res.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(res).to_bytes().to_vec())
+        }
+    }
+}
+
+fn evaluate_bytes(cap: &CStr, p: &[c_long; 9]) -> Result<Vec<u8>, String> {
+    let term = cap.to_bytes().iter().map(|c| *c as char).collect::<String>();
+    let params = p.iter().map(|n| Param::Number(*n as i32)).collect::<Vec<Param>>();
+
+    match std::panic::catch_unwind(|| evaluate(&term, &params)) {
+        Ok(Ok(res)) => Ok(res.chars().map(|c| c as u32 as u8).collect()),
+        Ok(Err(err)) => Err(format!("error: {}", err)),
+        Err(_) => Err("panic".to_string()),
+    }
+}
+
+#[test]
+fn evaluate_matches_tparm() {
+    let terms = std::env::var("CXTERMINFO_CONFORMANCE_TERMS").unwrap_or_else(|_| DEFAULT_TERMS.to_string());
+    let names = string_names();
+    let grid = param_grid();
+    let mut checked = 0;
+    let mut mismatches = 0;
+
+    for term in terms.split(',').map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        let c_term = CString::new(term).unwrap();
+        let mut err: c_int = 0;
+        if unsafe { setupterm(c_term.as_ptr(), 1, &mut err) } != 0 {
+            println!("{}: not installed, skipped", term);
+            continue;
+        }
+
+        for name in names.iter().filter(|name| !SKIPPED.contains(&name.as_str())) {
+            let c_name = CString::new(name.as_str()).unwrap();
+            let cap = unsafe { tigetstr(c_name.as_ptr()) };
+            // absent (0) or cancelled (-1)
+            if cap.is_null() || cap as isize == -1 {
+                continue;
+            }
+
+            let cap = unsafe { CStr::from_ptr(cap) }.to_owned();
+            let bytes = cap.to_bytes();
+            // string parameters are passed as pointers to tparm
+            if !bytes.contains(&b'%') || bytes.windows(2).any(|w| w == b"%s" || w == b"%l") {
+                continue;
+            }
+
+            for params in grid.iter() {
+                let expected = match tparm_bytes(&cap, params) {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+
+                checked += 1;
+                match evaluate_bytes(&cap, params) {
+                    Ok(ref actual) if *actual == expected => {}
+                    actual => {
+                        mismatches += 1;
+                        println!("{}: {} {:?}\n  tparm:    {:?}\n  evaluate: {:?}",
+                                 term, name, params,
+                                 String::from_utf8_lossy(&expected),
+                                 actual.map(|a| String::from_utf8_lossy(&a).into_owned()));
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{} expansions checked, {} mismatches", checked, mismatches);
+    assert_eq!(mismatches, 0);
+}
+
+#[test]
+fn evaluator_matches_tparm() {
+    let c_term = CString::new("xterm").unwrap();
+    let mut err: c_int = 0;
+    if unsafe { setupterm(c_term.as_ptr(), 1, &mut err) } != 0 {
+        println!("xterm: not installed, skipped");
+        return;
+    }
+
+    // static variables persist between calls, dynamic variables don't
+    let steps: [(&str, [c_long; 9]); 6] = [
+        ("%gA%d,%ga%d", [0; 9]),
+        ("%p1%PA%p2%Pa%gA%ga%+%d", [3, 4, 0, 0, 0, 0, 0, 0, 0]),
+        ("%gA%d,%ga%d", [0; 9]),
+        ("%gA%p1%+%PA%gA%d", [5, 0, 0, 0, 0, 0, 0, 0, 0]),
+        ("%?%gA%{8}%=%tset%eunset%;", [0; 9]),
+        ("%{0}%PA%gA%d", [0; 9]),
+    ];
+
+    let mut evaluator = Evaluator::new();
+    for (term, params) in steps.iter() {
+        let expected = tparm_bytes(&CString::new(*term).unwrap(), params).unwrap();
+        let values = params.iter().map(|n| Param::Number(*n as i32)).collect::<Vec<Param>>();
+        let actual = evaluator.evaluate(term, &values).unwrap();
+        assert_eq!(actual.as_bytes(), &expected[..], "{}", term);
+    }
+}
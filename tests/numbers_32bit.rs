@@ -0,0 +1,50 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Numbers of the 32-bit format are read as little endian, the byte order ncurses writes
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use cxterminfo::capabilities::NumberCapability;
+use cxterminfo::terminfo::TermInfo;
+
+/// Compiles an entry named "test" in the 32-bit format (magic 01036) with the given numbers
+fn entry_32bit(numbers: &[(NumberCapability, i32)]) -> TermInfo {
+    let num_count = numbers.iter().map(|(cap, _)| *cap as usize + 1).max().unwrap_or(0);
+    let mut values = vec![-1i32; num_count];
+    for (cap, value) in numbers {
+        values[*cap as usize] = *value;
+    }
+
+    let names = b"test\0";
+    let mut data = Vec::new();
+    for n in &[0o1036, names.len(), 0, num_count, 0, 0] {
+        data.extend_from_slice(&(*n as i16).to_le_bytes());
+    }
+    data.extend_from_slice(names);
+    if names.len() % 2 == 1 {
+        data.push(0);
+    }
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    TermInfo::from_data(data).unwrap()
+}
+
+#[test]
+fn little_endian() {
+    let info = entry_32bit(&[(NumberCapability::MaxColors, 256),
+                             (NumberCapability::MaxPairs, 0x10000),
+                             (NumberCapability::Columns, 0x01020304)]);
+    assert_eq!(info.get_number(NumberCapability::MaxColors), Some(256));
+    assert_eq!(info.get_number(NumberCapability::MaxPairs), Some(0x10000));
+    assert_eq!(info.get_number(NumberCapability::Columns), Some(0x01020304));
+    // absent
+    assert_eq!(info.get_number(NumberCapability::Lines), None);
+}
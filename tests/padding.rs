@@ -0,0 +1,145 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Padding delays of `write_capability_with` received by a fake terminal
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::time::Duration;
+
+use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use cxterminfo::padding::parse_padding;
+use cxterminfo::param_string::Param;
+use cxterminfo::terminfo::TermInfo;
+
+use common::{entry, entry_with_numbers};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(Vec<u8>),
+    Flush,
+    Delay(Duration),
+}
+
+/// Terminal recording writes, flushes and delays in order
+struct FakeTerminal<'a>(&'a RefCell<Vec<Event>>);
+
+impl Write for FakeTerminal<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().push(Event::Write(buf.to_vec()));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.borrow_mut().push(Event::Flush);
+        Ok(())
+    }
+}
+
+fn send(info: &TermInfo, cap: StringCapability, baudrate: Option<u32>, lines: u32) -> Vec<Event> {
+    let events = RefCell::new(Vec::new());
+    let mut terminal = FakeTerminal(&events);
+    let written = info.write_capability_with(cap, &[], &mut terminal, baudrate, lines,
+                                             &mut |d| events.borrow_mut().push(Event::Delay(d)))
+                      .unwrap();
+
+    let events = events.into_inner();
+    let bytes = events.iter()
+                      .map(|event| match event {
+                          Event::Write(bytes) => bytes.len(),
+                          _ => 0,
+                      })
+                      .sum::<usize>();
+    assert_eq!(written, bytes);
+    events
+}
+
+fn write(bytes: &[u8]) -> Event {
+    Event::Write(bytes.to_vec())
+}
+
+/// Entry with `pb#1200`, `il1` padded per line, `flash` with mandatory padding
+fn slow_terminal(bools: &[BoolCapability]) -> TermInfo {
+    entry_with_numbers(bools,
+                       &[(NumberCapability::PaddingBaudRate, 1200)],
+                       &[(StringCapability::InsertLine, "\x1B[L$<2*>"),
+                         (StringCapability::FlashScreen, "\x1B[?5h$<100/>\x1B[?5l"),
+                         (StringCapability::ClearScreen, "\x1B[H\x1B[J$<50>")])
+}
+
+#[test]
+fn delay_depends_on_baud_rate() {
+    let info = entry(&[], &[(StringCapability::ClearScreen, "\x1B[H\x1B[J$<50>")]);
+    let clear = |baud| send(&info, StringCapability::ClearScreen, baud, 1);
+
+    let expected = |delay| vec![write(b"\x1B[H\x1B[J"), Event::Flush, Event::Delay(delay)];
+    assert_eq!(clear(None), expected(Duration::from_millis(50)));
+    // 53 pad characters of 9 bits
+    assert_eq!(clear(Some(9600)), expected(Duration::from_micros(49_687)));
+    // 13 pad characters
+    assert_eq!(clear(Some(2400)), expected(Duration::from_micros(48_750)));
+    // one pad character
+    assert_eq!(clear(Some(300)), expected(Duration::from_millis(30)));
+    // no time for a single character
+    assert_eq!(clear(Some(150)), expected(Duration::from_millis(0)));
+}
+
+#[test]
+fn proportional_delay() {
+    let info = slow_terminal(&[]);
+    let insert = |baud, lines| send(&info, StringCapability::InsertLine, baud, lines);
+
+    let expected = |delay| vec![write(b"\x1B[L"), Event::Flush, Event::Delay(delay)];
+    assert_eq!(insert(None, 1), expected(Duration::from_millis(2)));
+    assert_eq!(insert(None, 24), expected(Duration::from_millis(48)));
+    // 0 lines count as 1
+    assert_eq!(insert(None, 0), expected(Duration::from_millis(2)));
+    // 6 pad characters for 48 ms
+    assert_eq!(insert(Some(1200), 24), expected(Duration::from_millis(45)));
+    assert_eq!(insert(Some(9600), 24), expected(Duration::from_micros(47_812)));
+
+    let (padding, _) = parse_padding(b"$<2*>").unwrap();
+    assert_eq!(padding.pad_chars(9600, 24), 51);
+}
+
+#[test]
+fn skipped_delays() {
+    let info = slow_terminal(&[]);
+    assert_eq!(send(&info, StringCapability::ClearScreen, Some(1200), 1).len(), 3);
+
+    // below pb and with xon/xoff only mandatory delays are kept
+    let xon = slow_terminal(&[BoolCapability::XonXoff]);
+    for (info, baud) in [(&info, Some(600)), (&xon, None), (&xon, Some(9600))].iter() {
+        assert_eq!(send(info, StringCapability::ClearScreen, *baud, 1), [write(b"\x1B[H\x1B[J")]);
+        assert_eq!(send(info, StringCapability::InsertLine, *baud, 24), [write(b"\x1B[L")]);
+    }
+
+    let flash = send(&xon, StringCapability::FlashScreen, Some(600), 1);
+    assert_eq!(flash, [write(b"\x1B[?5h"),
+                       Event::Flush,
+                       Event::Delay(Duration::from_millis(90)),
+                       write(b"\x1B[?5l")]);
+}
+
+#[test]
+fn utf8_parameter() {
+    let info = entry(&[], &[(StringCapability::ToStatusLine, "\x1B]2;\u{2192} %p1%s$<5>\x07")]);
+    let params = [Param::from("caf\u{E9} \u{2192}")];
+    let mut out = Vec::new();
+    let mut delays = Vec::new();
+    let written = info.write_capability_with(StringCapability::ToStatusLine, &params, &mut out,
+                                             None, 1, &mut |d| delays.push(d))
+                      .unwrap();
+    assert_eq!(out, "\x1B]2;\u{2192} caf\u{E9} \u{2192}\x07".as_bytes());
+    assert_eq!(written, out.len());
+    assert_eq!(delays, [Duration::from_millis(5)]);
+}
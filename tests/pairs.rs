@@ -0,0 +1,85 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Color pairs with `initp`/`scp` and with `setaf`/`setab`
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::pairs::ColorPairs;
+use cxterminfo::terminfo::{FormatError, TermInfo};
+
+use common::entry_with_numbers;
+
+/// 8 colors and 3 pairs (default and two more) only set with pair capabilities
+fn pair_terminal() -> TermInfo {
+    let initp = "P%p1%d;%p2%d;%p3%d;%p4%d;%p5%d;%p6%d;%p7%d.";
+    entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8), (NumberCapability::MaxPairs, 3)],
+                       &[(StringCapability::InitializePair, initp),
+                         (StringCapability::SetColorPair, "S%p1%d.")])
+}
+
+#[test]
+fn pair_capabilities() {
+    let info = pair_terminal();
+    let mut pairs = ColorPairs::new(&info);
+    assert!(pairs.uses_pair_capabilities());
+    assert!(pairs.is_empty());
+
+    // xterm colors 1 (205, 0, 0) and 4 (0, 0, 238) scaled to 0-1000
+    assert_eq!(pairs.alloc(1, 4).unwrap(), (1, b"P1;804;0;0;0;0;934.".to_vec()));
+    assert_eq!(pairs.set(1).unwrap(), b"S1.");
+    assert_eq!(pairs.alloc(1, 4).unwrap(), (1, vec![]));
+    assert_eq!(pairs.color(7, 0).unwrap(), b"P2;899;899;899;0;0;0.S2.");
+    assert_eq!(pairs.color(7, 0).unwrap(), b"S2.");
+    assert_eq!(pairs.colors(2), Some((7, 0)));
+    assert_eq!(pairs.len(), 2);
+}
+
+#[test]
+fn exhausted() {
+    let info = pair_terminal();
+    let mut pairs = ColorPairs::new(&info);
+    assert_eq!(pairs.alloc(1, 0).unwrap().0, 1);
+    assert_eq!(pairs.alloc(2, 0).unwrap().0, 2);
+    assert_eq!(pairs.alloc(3, 0), Err(FormatError::PairsExhausted(3)));
+    assert_eq!(FormatError::PairsExhausted(3).to_string(), "all 3 color pairs in use");
+    // used pairs are still handed out
+    assert_eq!(pairs.alloc(2, 0).unwrap().0, 2);
+    assert_eq!(pairs.len(), 2);
+
+    assert_eq!(pairs.alloc(8, 0), Err(FormatError::ColorOutOfRange(8)));
+    assert_eq!(pairs.set(0), Err(FormatError::ColorOutOfRange(0)));
+    assert_eq!(pairs.set(3), Err(FormatError::ColorOutOfRange(3)));
+
+    // no pairs at all
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)],
+                                  &[(StringCapability::SetAnsiForeground, "\x1B[3%p1%dm")]);
+    assert_eq!(ColorPairs::new(&info).alloc(1, 0), Err(FormatError::PairsExhausted(0)));
+}
+
+#[test]
+fn set_colors() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    let mut pairs = ColorPairs::new(&info);
+    assert!(!pairs.uses_pair_capabilities());
+    assert_eq!(pairs.alloc(208, 16).unwrap(), (1, vec![]));
+    assert_eq!(pairs.alloc(7, 0).unwrap(), (2, vec![]));
+    assert_eq!(pairs.set(1).unwrap(), b"\x1B[38;5;208m\x1B[48;5;16m");
+    assert_eq!(pairs.color(7, 0).unwrap(), b"\x1B[37m\x1B[40m");
+    assert_eq!(pairs.alloc(256, 0), Err(FormatError::ColorOutOfRange(256)));
+
+    // setf and setb with remapped colors
+    let numbers = [(NumberCapability::MaxColors, 8), (NumberCapability::MaxPairs, 64)];
+    let info = entry_with_numbers(&[], &numbers,
+                                  &[(StringCapability::SetForeground, "F%p1%d"),
+                                    (StringCapability::SetBackground, "B%p1%d")]);
+    assert_eq!(ColorPairs::new(&info).color(1, 6).unwrap(), b"F4B3");
+}
@@ -0,0 +1,67 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Redefinition of palette colors with `initc` and restoring them with `oc`
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{BoolCapability, NumberCapability, StringCapability};
+use cxterminfo::terminfo::{FormatError, TermInfo};
+
+use common::entry_with_numbers;
+
+#[test]
+fn linux() {
+    let info = TermInfo::from_file("tests/data/linux").unwrap();
+    assert!(info.can_change_colors());
+    assert_eq!(info.define_color(0, 0, 0, 0).unwrap(), b"\x1B]P0000000");
+    assert_eq!(info.define_color(1, 0xAA, 0x00, 0x00).unwrap(), b"\x1B]P1aa0000");
+    assert_eq!(info.define_color(7, 0x12, 0x34, 0x56).unwrap(), b"\x1B]P7123456");
+    assert_eq!(info.define_color(8, 255, 255, 255), Err(FormatError::ColorOutOfRange(8)));
+    assert_eq!(info.reset_palette().unwrap(), b"\x1B]R");
+}
+
+#[test]
+fn xterm() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert!(info.can_change_colors());
+    assert_eq!(info.define_color(4, 0, 0, 255).unwrap(), b"\x1B]4;4;rgb:00/00/FF\x1B\\");
+    assert_eq!(info.define_color(255, 1, 2, 3).unwrap(), b"\x1B]4;255;rgb:01/02/03\x1B\\");
+    assert_eq!(info.reset_palette().unwrap(), b"\x1B]104\x07");
+
+    // terminals scale back with integer division, every value survives
+    for value in 0..=255u8 {
+        let expected = format!("\x1B]4;16;rgb:{0:02X}/{0:02X}/{0:02X}\x1B\\", value);
+        assert_eq!(info.define_color(16, value, value, value).unwrap(), expected.as_bytes());
+    }
+}
+
+#[test]
+fn hls() {
+    let bools = [BoolCapability::CanChange, BoolCapability::HueLightnessSaturation];
+    let initc = "%p1%d:%p2%d,%p3%d,%p4%d;";
+    let info = entry_with_numbers(&bools, &[(NumberCapability::MaxColors, 16)],
+                                  &[(StringCapability::InitializeColor, initc)]);
+    assert_eq!(info.define_color(1, 255, 0, 0).unwrap(), b"1:120,50,100;");
+    assert_eq!(info.define_color(2, 0, 255, 0).unwrap(), b"2:240,50,100;");
+    assert_eq!(info.define_color(3, 0, 0, 255).unwrap(), b"3:0,50,100;");
+    assert_eq!(info.define_color(4, 128, 128, 128).unwrap(), b"4:0,50,0;");
+    assert_eq!(info.define_color(5, 255, 255, 255).unwrap(), b"5:0,100,0;");
+}
+
+#[test]
+fn missing() {
+    let info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)], &[]);
+    assert!(!info.can_change_colors());
+    let not_found = FormatError::CapabilityNotFound(StringCapability::InitializeColor);
+    assert_eq!(info.define_color(1, 0, 0, 0), Err(not_found));
+    let not_found = FormatError::CapabilityNotFound(StringCapability::OrigColors);
+    assert_eq!(info.reset_palette(), Err(not_found));
+}
@@ -0,0 +1,64 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Lookup of a terminal installed in the legacy and the 32-bit format
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use std::fs;
+
+use cxterminfo::terminfo::{TermInfo, TermInfoError, TerminfoVersion};
+
+#[test]
+fn lookup() {
+    // the legacy file is found first in TERMINFO, the 32-bit file is stored in HOME
+    let dir = std::env::temp_dir().join(format!("cxterminfo-32bit-{}", std::process::id()));
+    for (subdir, file, name) in &[("terminfo/c", "cx-ext", "cx-ext"),
+                                  ("home/c", "xterm-256color", "cx-ext"),
+                                  ("terminfo/x", "cx-ext", "xlegacy")] {
+        fs::create_dir_all(dir.join(subdir)).unwrap();
+        fs::copy(format!("tests/data/{}", file), dir.join(subdir).join(name)).unwrap();
+    }
+    std::env::set_var("TERMINFO", dir.join("terminfo"));
+    std::env::set_var("HOME", dir.join("home"));
+
+    let info = TermInfo::from_name("cx-ext").unwrap();
+    assert_eq!(info.terminfo_version(), TerminfoVersion::Legacy16Bit);
+    let info = TermInfo::from_name_preferring_32bit("cx-ext").unwrap();
+    assert_eq!(info.terminfo_version(), TerminfoVersion::New32Bit);
+
+    // only a legacy file
+    let info = TermInfo::from_name_preferring_32bit("xlegacy").unwrap();
+    assert_eq!(info.terminfo_version(), TerminfoVersion::Legacy16Bit);
+
+    // an oversized first candidate is skipped
+    fs::create_dir_all(dir.join("terminfo/b")).unwrap();
+    fs::create_dir_all(dir.join("home/b")).unwrap();
+    fs::write(dir.join("terminfo/b/big"), vec![0x1E; 40000]).unwrap();
+    fs::copy("tests/data/cx-ext", dir.join("home/b/big")).unwrap();
+    let info = TermInfo::from_name_preferring_32bit("big").unwrap();
+    assert_eq!(info.terminfo_version(), TerminfoVersion::Legacy16Bit);
+
+    // 32-bit entries may be larger than the 4096 bytes of legacy entries
+    let mut large = fs::read("tests/data/xterm-256color").unwrap();
+    large.resize(8192, 0);
+    fs::create_dir_all(dir.join("terminfo/l")).unwrap();
+    fs::write(dir.join("terminfo/l/large"), &large).unwrap();
+    let info = TermInfo::from_name_preferring_32bit("large").unwrap();
+    assert_eq!(info.terminfo_version(), TerminfoVersion::New32Bit);
+    assert_eq!(TermInfo::from_name("large").unwrap().name(), "xterm-256color");
+    large[..2].copy_from_slice(&0o432i16.to_le_bytes());
+    assert!(matches!(TermInfo::from_bytes(&large), Err(TermInfoError::InvalidDataSize)));
+
+    for name in &["missing", ""] {
+        let result = TermInfo::from_name_preferring_32bit(name);
+        assert!(matches!(result, Err(TermInfoError::InvalidName)));
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,119 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Property based tests, parsing terminfo data and evaluating parameterized strings must
+//! never panic on arbitrary input
+
+#![cfg(all(feature = "parser", feature = "names"))]
+
+extern crate cxterminfo;
+extern crate proptest;
+
+use cxterminfo::capabilities::StringCapability;
+use cxterminfo::param_string::{evaluate, evaluate_bytes, evaluate_strict, Param, ParamString};
+use cxterminfo::terminfo::{TermInfo, TermInfoError};
+use proptest::prelude::*;
+
+const XTERM: &[u8] = include_bytes!("data/xterm-256color");
+
+/// Use all accessors of a parsed terminfo
+fn exercise(info: &TermInfo) {
+    let _ = info.name();
+    let _ = info.get_string(StringCapability::CursorAddress);
+    let _ = info.get_ext_string("Ms");
+    let _ = info.param_count(StringCapability::SetAttributes);
+    let mut out = Vec::new();
+    info.print_capabilities(&mut out).unwrap();
+}
+
+fn check_from_data(data: Vec<u8>) {
+    match TermInfo::from_data(data) {
+        Ok(info) => exercise(&info),
+        Err(TermInfoError::InvalidDataSize)
+        | Err(TermInfoError::InvalidMagicNum)
+        | Err(TermInfoError::InvalidData) => {}
+        Err(err) => panic!("unexpected error {}", err),
+    }
+}
+
+fn param() -> impl Strategy<Value = Param<'static>> {
+    prop_oneof![any::<bool>().prop_map(Param::Bool),
+                any::<i32>().prop_map(Param::Number),
+                ".{0,8}".prop_map(Param::from)]
+}
+
+/// Strings built from terminfo like pieces, arbitrary strings rarely contain valid `%` codes
+fn param_string() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![Just("%".to_string()),
+                            "%[-+# :.0-9]{0,4}[doxXsc]",
+                            "%p[0-9]",
+                            "%[gP][a-zA-Z]",
+                            "%\\{-?[0-9]{0,11}\\}",
+                            "%'.'?",
+                            "%[ilmAO&|^!~=<>+*/\\-?te;BDrn]",
+                            "\\$<[0-9.*/]{0,5}>?",
+                            "[a-z\\x1B\\[;]{1,3}"];
+    prop::collection::vec(piece, 0..24).prop_map(|pieces| pieces.concat())
+}
+
+proptest! {
+    #[test]
+    fn from_data_arbitrary(data in prop::collection::vec(any::<u8>(), 0..4200)) {
+        check_from_data(data);
+    }
+
+    #[test]
+    fn from_data_valid_header(data in prop::collection::vec(any::<u8>(), 0..4084),
+                              magic in prop_oneof![Just(0x1Au8), Just(0x1Eu8)]) {
+        let mut bytes = vec![magic, if magic == 0x1A { 0x01 } else { 0x02 }];
+        bytes.extend(data);
+        check_from_data(bytes);
+    }
+
+    #[test]
+    fn from_data_mutated(changes in prop::collection::vec((0..XTERM.len(), any::<u8>()), 1..16),
+                         len in 0..=XTERM.len()) {
+        let mut data = XTERM.to_vec();
+        for (pos, value) in changes {
+            data[pos] = value;
+        }
+        data.truncate(len);
+        check_from_data(data);
+    }
+
+    #[test]
+    fn evaluate_arbitrary(term in ".{0,64}", params in prop::collection::vec(param(), 0..10)) {
+        let _ = evaluate(&term, &params);
+        let _ = evaluate_strict(&term, &params);
+    }
+
+    #[test]
+    fn evaluate_param_strings(term in param_string(), params in prop::collection::vec(param(), 0..10)) {
+        let _ = evaluate(&term, &params);
+        let _ = evaluate_strict(&term, &params);
+        if let Ok(compiled) = ParamString::compile(&term) {
+            let _ = compiled.params_used();
+            let _ = compiled.eval(&params);
+            let _ = compiled.measure(&params);
+        }
+    }
+
+    #[test]
+    fn evaluate_bytes_arbitrary(term in prop::collection::vec(any::<u8>(), 0..64),
+                                params in prop::collection::vec(param(), 0..10)) {
+        let _ = evaluate_bytes(&term, &params);
+    }
+
+    #[test]
+    fn evaluate_bytes_agrees(term in param_string(),
+                             numbers in prop::collection::vec(any::<i32>(), 0..10)) {
+        prop_assume!(term.is_ascii());
+        let params = numbers.into_iter().map(Param::Number).collect::<Vec<Param>>();
+        let expected = evaluate(&term, &params)
+            .map(|s| s.chars().map(|c| c as u8).collect::<Vec<u8>>());
+        prop_assert_eq!(evaluate_bytes(term.as_bytes(), &params), expected);
+    }
+}
@@ -0,0 +1,82 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Closest palette colors for RGB values on terminals without 24-bit colors
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use std::collections::HashMap;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::{FormatError, TermInfo};
+
+use common::entry_with_numbers;
+
+/// Entry with the given number of colors, without COLORTERM
+fn with_colors(colors: i16) -> TermInfo {
+    let mut info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, colors)],
+                                      &[(StringCapability::SetAnsiForeground, "\x1B[3%p1%dm"),
+                                        (StringCapability::SetAnsiBackground, "\x1B[4%p1%dm")]);
+    info.set_colorterm_from_provider(&HashMap::new());
+    info
+}
+
+#[test]
+fn xterm_256() {
+    let info = with_colors(256);
+    for (rgb, index) in &[((255, 0, 0), 196), ((0, 0, 0), 16), ((255, 255, 255), 231),
+                          ((128, 128, 128), 244), ((100, 100, 100), 241), ((0, 95, 135), 24),
+                          ((95, 135, 0), 64), ((255, 175, 215), 218), ((18, 18, 18), 233),
+                          ((240, 240, 240), 255), ((30, 144, 255), 33), ((255, 128, 0), 208)] {
+        assert_eq!(info.quantize_color(rgb.0, rgb.1, rgb.2), *index, "{:?}", rgb);
+    }
+}
+
+#[test]
+fn small_palettes() {
+    let info = with_colors(88);
+    assert_eq!(info.quantize_color(0, 0, 0), 16);
+    assert_eq!(info.quantize_color(255, 0, 0), 64);
+    assert_eq!(info.quantize_color(0x8B, 0xCD, 0xFF), 16 + 16 + 2 * 4 + 3);
+    assert_eq!(info.quantize_color(0x2E, 0x2E, 0x2E), 80);
+    assert_eq!(info.quantize_color(0xE0, 0xE0, 0xE0), 87);
+
+    let info = with_colors(16);
+    assert_eq!(info.quantize_color(255, 0, 0), 9);
+    assert_eq!(info.quantize_color(200, 0, 0), 1);
+    assert_eq!(info.quantize_color(128, 128, 128), 8);
+    assert_eq!(info.quantize_color(0x5C, 0x5C, 0xFF), 12);
+
+    let info = with_colors(8);
+    assert_eq!(info.quantize_color(255, 0, 0), 1);
+    assert_eq!(info.quantize_color(255, 255, 255), 7);
+    assert_eq!(info.quantize_color(0, 0, 0x80), 4);
+
+    assert_eq!(entry_with_numbers(&[], &[], &[]).quantize_color(255, 0, 0), 0);
+}
+
+#[test]
+fn or_nearest() {
+    // no 24-bit colors without COLORTERM
+    let info = with_colors(8);
+    assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5).unwrap(), b"\x1B[31m");
+    assert_eq!(info.set_background_rgb_or_nearest(10, 10, 250).unwrap(), b"\x1B[44m");
+
+    let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    info.set_colorterm_from_provider(&HashMap::new());
+    assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5).unwrap(), b"\x1B[38;5;196m");
+
+    let info = TermInfo::from_file("tests/data/xterm-tc").unwrap();
+    assert_eq!(info.set_background_rgb_or_nearest(1, 2, 3).unwrap(), b"\x1B[48;2;1;2;3m");
+
+    let info = entry_with_numbers(&[], &[], &[]);
+    let not_found = FormatError::CapabilityNotFound(StringCapability::SetAnsiForeground);
+    assert_eq!(info.set_foreground_rgb_or_nearest(250, 10, 5), Err(not_found));
+}
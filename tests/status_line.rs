@@ -0,0 +1,47 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Status line of hardware terminals and of emulators using it for the window title
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{BoolCapability, StringCapability};
+
+use common::entry;
+
+const STATUS_LINE: [(StringCapability, &str); 3] = [
+    (StringCapability::ToStatusLine, "\x1B7\x1B[25;%i%p1%dH"),
+    (StringCapability::FromStatusLine, "\x1B8"),
+    (StringCapability::DisStatusLine, "\x1B7\x1B[25;1H\x1B[K\x1B8"),
+];
+
+#[test]
+fn status_line() {
+    let info = entry(&[BoolCapability::HasStatusLine], &STATUS_LINE);
+    assert!(info.supports_status_line());
+    assert_eq!(info.set_status_line("12:00"), Some("\x1B7\x1B[25;1H12:00\x1B8".to_string()));
+    assert_eq!(info.set_status_line(""), Some("\x1B7\x1B[25;1H\x1B8".to_string()));
+    assert_eq!(info.set_status_line("a\nb"), None);
+    assert_eq!(info.clear_status_line(), Some("\x1B7\x1B[25;1H\x1B[K\x1B8".to_string()));
+}
+
+#[test]
+fn without_hs() {
+    // the capabilities alone don't make a status line
+    let info = entry(&[], &STATUS_LINE);
+    assert!(!info.supports_status_line());
+    assert_eq!(info.set_status_line("12:00"), None);
+    assert_eq!(info.clear_status_line(), None);
+
+    let info = entry(&[BoolCapability::HasStatusLine], &[]);
+    assert!(info.supports_status_line());
+    assert_eq!(info.set_status_line("12:00"), None);
+    assert_eq!(info.clear_status_line(), None);
+}
@@ -0,0 +1,88 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Styles set with `sgr` and with the sequences of the single attributes
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::terminfo::style::Style;
+use cxterminfo::terminfo::{Color, FormatError, TermInfo};
+
+use common::{entry, entry_with_numbers};
+
+/// xterm like entry without `sgr`
+fn single_attributes() -> TermInfo {
+    entry(&[], &[(StringCapability::ExitAttributeMode, "\x1B[m"),
+                 (StringCapability::EnterStandoutMode, "\x1B[7m"),
+                 (StringCapability::EnterBoldMode, "\x1B[1m"),
+                 (StringCapability::EnterUnderlineMode, "\x1B[4m"),
+                 (StringCapability::EnterReverseMode, "\x1B[7m"),
+                 (StringCapability::EnterItalicsMode, "\x1B[3m"),
+                 (StringCapability::SetAnsiForeground, "\x1B[3%p1%dm"),
+                 (StringCapability::SetAnsiBackground, "\x1B[4%p1%dm"),
+                 (StringCapability::OrigColorPair, "\x1B[39;49m")])
+}
+
+#[test]
+fn sgr_and_single_attributes() {
+    let xterm = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    let single = single_attributes();
+
+    // style, with sgr, with the single attributes
+    let table = [(Style::new(), "\x1B(B\x1B[0m", "\x1B[m"),
+                 (Style::new().bold(), "\x1B(B\x1B[0;1m", "\x1B[m\x1B[1m"),
+                 (Style::new().underline().bold(), "\x1B(B\x1B[0;1;4m", "\x1B[m\x1B[1m\x1B[4m"),
+                 (Style::new().standout(), "\x1B(B\x1B[0;7m", "\x1B[m\x1B[7m"),
+                 (Style::new().italic().reverse(),
+                  "\x1B(B\x1B[0;7m\x1B[3m",
+                  "\x1B[m\x1B[7m\x1B[3m"),
+                 (Style::new().bold().fg(2).bg(4),
+                  "\x1B(B\x1B[0;1m\x1B[32m\x1B[44m",
+                  "\x1B[m\x1B[1m\x1B[32m\x1B[44m")];
+    for (style, sgr, attributes) in &table {
+        assert_eq!(xterm.apply_style(style).unwrap(), sgr.as_bytes(), "{:?}", style);
+        assert_eq!(single.apply_style(style).unwrap(), attributes.as_bytes(), "{:?}", style);
+    }
+
+    let style = Style::new().blink();
+    assert_eq!(single.apply_style(&style),
+               Err(FormatError::CapabilityNotFound(StringCapability::EnterBlinkMode)));
+}
+
+#[test]
+fn color_incompatible_attributes() {
+    let underline = Style::new().underline();
+    let linux = TermInfo::from_file("tests/data/linux").unwrap();
+    assert_eq!(linux.apply_style(&underline).unwrap(), b"\x1B[0;10;4m\x0F");
+    assert_eq!(linux.apply_style(&underline.fg(Color::Default)).unwrap(),
+               b"\x1B[0;10m\x0F\x1B[39m");
+
+    let info = entry_with_numbers(&[], &[(NumberCapability::NoColorVideo, 2)], &[
+        (StringCapability::ExitAttributeMode, "\x1B[m"),
+        (StringCapability::EnterUnderlineMode, "\x1B[4m"),
+        (StringCapability::SetAnsiForeground, "\x1B[3%p1%dm")]);
+    assert_eq!(info.apply_style(&underline.bold().fg(1)),
+               Err(FormatError::CapabilityNotFound(StringCapability::EnterBoldMode)));
+    assert_eq!(info.apply_style(&underline.fg(1)).unwrap(), b"\x1B[m\x1B[31m");
+}
+
+#[test]
+fn reset() {
+    assert_eq!(single_attributes().reset_style().unwrap(), b"\x1B[m\x1B[39;49m");
+
+    // no sgr0
+    let info = entry(&[], &[(StringCapability::ExitStandoutMode, "\x1B[27m"),
+                            (StringCapability::ExitUnderlineMode, "\x1B[24m")]);
+    assert_eq!(info.reset_style().unwrap(), b"\x1B[27m\x1B[24m");
+
+    assert_eq!(entry(&[], &[]).reset_style(),
+               Err(FormatError::CapabilityNotFound(StringCapability::ExitAttributeMode)));
+}
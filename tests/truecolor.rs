@@ -0,0 +1,95 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Detection of 24-bit colors and the sequences for each method
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+mod common;
+
+use std::collections::HashMap;
+
+use cxterminfo::capabilities::{NumberCapability, StringCapability};
+use cxterminfo::ext::{ColorSupport, DirectMethod};
+use cxterminfo::terminfo::TermInfo;
+
+use common::entry_with_numbers;
+
+fn direct(via: DirectMethod) -> ColorSupport {
+    ColorSupport::Direct { via }
+}
+
+fn colorterm(value: &str) -> HashMap<String, String> {
+    let mut env = HashMap::new();
+    env.insert("COLORTERM".to_string(), value.to_string());
+    env
+}
+
+/// Entry from the test data without COLORTERM
+fn load(name: &str) -> TermInfo {
+    let mut info = TermInfo::from_file(&format!("tests/data/{}", name)).unwrap();
+    info.set_colorterm_from_provider(&HashMap::new());
+    info
+}
+
+#[test]
+fn color_support() {
+    let info = load("xterm-tc");
+    assert_eq!(info.color_support(), direct(DirectMethod::Truecolor));
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), Some("\x1B[38;2;1;2;3m".to_string()));
+    assert_eq!(info.set_rgb_background(1, 2, 3), Some("\x1B[48;2;1;2;3m".to_string()));
+
+    let info = load("xterm-direct");
+    assert_eq!(info.color_support(), direct(DirectMethod::Setaf));
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), Some("\x1B[38:2::1:2:3m".to_string()));
+    // values below 8 are ANSI colors in direct color entries, they are written as SGR
+    assert_eq!(info.set_rgb_foreground(0, 0, 7), Some("\x1B[38:2::0:0:7m".to_string()));
+    assert_eq!(info.set_rgb_background(0, 0, 0), Some("\x1B[48:2::0:0:0m".to_string()));
+    assert_eq!(info.set_rgb_foreground(0, 0, 8), Some("\x1B[38:2::0:0:8m".to_string()));
+
+    let info = load("tmux-setrgb");
+    assert_eq!(info.color_support(), direct(DirectMethod::SetRgb));
+    assert_eq!(info.set_rgb_background(1, 2, 3), Some("\x1B[48;2;1;2;3m".to_string()));
+
+    let info = load("tmux-256color");
+    assert_eq!(info.color_support(), ColorSupport::Colors(256));
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), None);
+
+    let mut info = entry_with_numbers(&[], &[(NumberCapability::MaxColors, 8)],
+                                      &[(StringCapability::SetAnsiForeground, "\x1B[3%p1%dm")]);
+    info.set_colorterm_from_provider(&HashMap::new());
+    assert_eq!(info.color_support(), ColorSupport::Colors(8));
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), None);
+    assert_eq!(info.set_foreground_rgb(1, 2, 3), None);
+
+    let mut mono = entry_with_numbers(&[], &[], &[]);
+    mono.set_colorterm_from_provider(&HashMap::new());
+    assert_eq!(mono.color_support(), ColorSupport::Mono);
+
+    // COLORTERM upgrades entries with colors, the entry's own method wins
+    info.set_colorterm_from_provider(&colorterm("truecolor"));
+    assert_eq!(info.color_support(), direct(DirectMethod::ColorTerm));
+    assert_eq!(info.set_rgb_foreground(1, 2, 3), Some("\x1B[38;2;1;2;3m".to_string()));
+    assert_eq!(info.set_foreground_rgb(1, 2, 3), Some("\x1B[38;2;1;2;3m".to_string()));
+    mono.set_colorterm_from_provider(&colorterm("truecolor"));
+    assert_eq!(mono.color_support(), ColorSupport::Mono);
+    let info = load("xterm-direct");
+    assert_eq!(info.color_support_from_provider(&colorterm("truecolor")),
+               direct(DirectMethod::Setaf));
+}
+
+#[test]
+fn colorterm_values() {
+    let info = load("xterm-256color");
+    assert_eq!(info.color_support_from_provider(&colorterm("truecolor")),
+               direct(DirectMethod::ColorTerm));
+    assert_eq!(info.color_support_from_provider(&colorterm("24bit")),
+               direct(DirectMethod::ColorTerm));
+    assert_eq!(info.color_support_from_provider(&colorterm("yes")), ColorSupport::Colors(256));
+    assert_eq!(info.color_support_from_provider(&HashMap::new()), ColorSupport::Colors(256));
+}
@@ -0,0 +1,98 @@
+//  Copyleft (ↄ) 2021 BxNiom <bxniom@protonmail.com> | https://github.com/bxniom
+//
+//  This work is free. You can redistribute it and/or modify it under the
+//  terms of the Do What The Fuck You Want To Public License, Version 2,
+//  as published by Sam Hocevar. See the COPYING file for more details.
+
+//! Underline styles with `Smulx`, underline colors with `Setulc` and their fallbacks
+
+#![cfg(feature = "parser")]
+
+extern crate cxterminfo;
+
+use cxterminfo::ext::UnderlineStyle;
+use cxterminfo::terminfo::policy::ColorPolicy;
+use cxterminfo::terminfo::style::Style;
+use cxterminfo::terminfo::TermInfo;
+
+#[test]
+fn styled_underline() {
+    let info = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    assert!(info.supports_undercurl());
+    assert!(!info.supports_underline_color());
+
+    let table = [(UnderlineStyle::None, "\x1B[4:0m"),
+                 (UnderlineStyle::Single, "\x1B[4:1m"),
+                 (UnderlineStyle::Double, "\x1B[4:2m"),
+                 (UnderlineStyle::Curly, "\x1B[4:3m"),
+                 (UnderlineStyle::Dotted, "\x1B[4:4m"),
+                 (UnderlineStyle::Dashed, "\x1B[4:5m")];
+    for (style, expected) in table.iter() {
+        assert_eq!(info.set_underline(*style), Some(expected.to_string()), "{:?}", style);
+    }
+
+    assert_eq!(info.set_underline_color(255, 0, 0), None);
+    assert_eq!(info.reset_underline_color(), None);
+}
+
+#[test]
+fn plain_underline() {
+    let info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    assert!(!info.supports_undercurl());
+    assert!(!info.supports_underline_color());
+
+    assert_eq!(info.set_underline(UnderlineStyle::None), Some("\x1B[24m".to_string()));
+    for style in [UnderlineStyle::Single, UnderlineStyle::Curly, UnderlineStyle::Dashed].iter() {
+        assert_eq!(info.set_underline(*style), Some("\x1B[4m".to_string()), "{:?}", style);
+    }
+
+    let info = TermInfo::from_file("tests/data/vt100").unwrap();
+    assert_eq!(info.set_underline(UnderlineStyle::Curly), Some("\x1B[4m$<2>".to_string()));
+}
+
+#[test]
+fn underline_color() {
+    let mut info = TermInfo::from_file("tests/data/cx-ext").unwrap();
+    assert!(info.supports_underline_color());
+    assert_eq!(info.set_underline_color(0x10, 0x20, 0x30),
+               Some("\x1B[58:2::16:32:48m".to_string()));
+    assert_eq!(info.reset_underline_color(), Some("\x1B[59m".to_string()));
+
+    info.set_color_policy(ColorPolicy::Never);
+    assert_eq!(info.set_underline_color(0x10, 0x20, 0x30), Some(String::new()));
+    assert_eq!(info.reset_underline_color(), Some(String::new()));
+
+    // no Setulc
+    let mut info = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    info.set_color_policy(ColorPolicy::Never);
+    assert_eq!(info.set_underline_color(0x10, 0x20, 0x30), None);
+    assert_eq!(info.reset_underline_color(), None);
+}
+
+#[test]
+fn style() {
+    let curly = Style::new().underline_style(UnderlineStyle::Curly).underline_color(255, 0, 0);
+    assert_eq!(curly, Style::new().underline().underline_style(UnderlineStyle::Curly)
+                                  .underline_color(255, 0, 0));
+    assert_eq!(Style::new().bold().underline_style(UnderlineStyle::Dotted)
+                           .underline_style(UnderlineStyle::None),
+               Style::new().bold());
+
+    let tmux = TermInfo::from_file("tests/data/tmux-256color").unwrap();
+    let xterm = TermInfo::from_file("tests/data/xterm-256color").unwrap();
+    let plain = Style::new().underline();
+    assert_eq!(tmux.apply_style(&curly).unwrap(),
+               [tmux.apply_style(&plain).unwrap(), b"\x1B[4:3m".to_vec()].concat());
+    assert_eq!(xterm.apply_style(&curly).unwrap(), xterm.apply_style(&plain).unwrap());
+    assert_eq!(xterm.apply_style(&curly).unwrap(), b"\x1B(B\x1B[0;4m");
+
+    // single underlines don't need Smulx
+    let single = plain.underline_style(UnderlineStyle::Single);
+    assert_eq!(tmux.apply_style(&single).unwrap(), tmux.apply_style(&plain).unwrap());
+
+    // sgr0 and smul, then Smulx and Setulc
+    let info = TermInfo::from_file("tests/data/cx-ul").unwrap();
+    assert_eq!(info.apply_style(&curly.fg(1)).unwrap(),
+               b"\x1B[m\x1B[4m\x1B[31m\x1B[4:3m\x1B[58:2::255:0:0m");
+    assert_eq!(info.reset_style().unwrap(), b"\x1B[m\x1B[39;49m\x1B[59m");
+}